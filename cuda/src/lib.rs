@@ -7,6 +7,17 @@
 )]
 
 use cuda_std::prelude::*;
+// Only needed on the device, on the host the inherent float methods take precedence.
+#[cfg_attr(not(target_os = "cuda"), allow(unused_imports))]
+use cuda_std::GpuFloat;
+use cuda_std::bf16;
+
+// Shared with the host crate, the kernel names in it are only used by the host.
+#[path = "../../src/backend/ops/element.rs"]
+#[allow(dead_code)]
+mod element;
+
+use element::{Abs, Arith, Cast, Integer, Neg};
 
 macro_rules! impl_op {
     ($fn_name:ident, $l_ty:ty, $r_ty:ty, $o_ty:ty, $dim:ty, $op:expr) => {
@@ -67,6 +78,36 @@ impl_op!(eq_f32_3d, f32, f32, bool, generic_array::typenum::U3, |a, b| a == b);
 impl_op!(eq_f32_4d, f32, f32, bool, generic_array::typenum::U4, |a, b| a == b);
 impl_op!(eq_f32_5d, f32, f32, bool, generic_array::typenum::U5, |a, b| a == b);
 impl_op!(eq_f32_6d, f32, f32, bool, generic_array::typenum::U6, |a, b| a == b);
+impl_op!(ne_f32_1d, f32, f32, bool, generic_array::typenum::U1, |a, b| a != b);
+impl_op!(ne_f32_2d, f32, f32, bool, generic_array::typenum::U2, |a, b| a != b);
+impl_op!(ne_f32_3d, f32, f32, bool, generic_array::typenum::U3, |a, b| a != b);
+impl_op!(ne_f32_4d, f32, f32, bool, generic_array::typenum::U4, |a, b| a != b);
+impl_op!(ne_f32_5d, f32, f32, bool, generic_array::typenum::U5, |a, b| a != b);
+impl_op!(ne_f32_6d, f32, f32, bool, generic_array::typenum::U6, |a, b| a != b);
+impl_op!(lt_f32_1d, f32, f32, bool, generic_array::typenum::U1, |a, b| a < b);
+impl_op!(lt_f32_2d, f32, f32, bool, generic_array::typenum::U2, |a, b| a < b);
+impl_op!(lt_f32_3d, f32, f32, bool, generic_array::typenum::U3, |a, b| a < b);
+impl_op!(lt_f32_4d, f32, f32, bool, generic_array::typenum::U4, |a, b| a < b);
+impl_op!(lt_f32_5d, f32, f32, bool, generic_array::typenum::U5, |a, b| a < b);
+impl_op!(lt_f32_6d, f32, f32, bool, generic_array::typenum::U6, |a, b| a < b);
+impl_op!(le_f32_1d, f32, f32, bool, generic_array::typenum::U1, |a, b| a <= b);
+impl_op!(le_f32_2d, f32, f32, bool, generic_array::typenum::U2, |a, b| a <= b);
+impl_op!(le_f32_3d, f32, f32, bool, generic_array::typenum::U3, |a, b| a <= b);
+impl_op!(le_f32_4d, f32, f32, bool, generic_array::typenum::U4, |a, b| a <= b);
+impl_op!(le_f32_5d, f32, f32, bool, generic_array::typenum::U5, |a, b| a <= b);
+impl_op!(le_f32_6d, f32, f32, bool, generic_array::typenum::U6, |a, b| a <= b);
+impl_op!(gt_f32_1d, f32, f32, bool, generic_array::typenum::U1, |a, b| a > b);
+impl_op!(gt_f32_2d, f32, f32, bool, generic_array::typenum::U2, |a, b| a > b);
+impl_op!(gt_f32_3d, f32, f32, bool, generic_array::typenum::U3, |a, b| a > b);
+impl_op!(gt_f32_4d, f32, f32, bool, generic_array::typenum::U4, |a, b| a > b);
+impl_op!(gt_f32_5d, f32, f32, bool, generic_array::typenum::U5, |a, b| a > b);
+impl_op!(gt_f32_6d, f32, f32, bool, generic_array::typenum::U6, |a, b| a > b);
+impl_op!(ge_f32_1d, f32, f32, bool, generic_array::typenum::U1, |a, b| a >= b);
+impl_op!(ge_f32_2d, f32, f32, bool, generic_array::typenum::U2, |a, b| a >= b);
+impl_op!(ge_f32_3d, f32, f32, bool, generic_array::typenum::U3, |a, b| a >= b);
+impl_op!(ge_f32_4d, f32, f32, bool, generic_array::typenum::U4, |a, b| a >= b);
+impl_op!(ge_f32_5d, f32, f32, bool, generic_array::typenum::U5, |a, b| a >= b);
+impl_op!(ge_f32_6d, f32, f32, bool, generic_array::typenum::U6, |a, b| a >= b);
 
 impl_op!(add_f64_1d, f64, f64, f64, generic_array::typenum::U1, |a, b| a + b);
 impl_op!(add_f64_2d, f64, f64, f64, generic_array::typenum::U2, |a, b| a + b);
@@ -98,6 +139,158 @@ impl_op!(eq_f64_3d, f64, f64, bool, generic_array::typenum::U3, |a, b| a == b);
 impl_op!(eq_f64_4d, f64, f64, bool, generic_array::typenum::U4, |a, b| a == b);
 impl_op!(eq_f64_5d, f64, f64, bool, generic_array::typenum::U5, |a, b| a == b);
 impl_op!(eq_f64_6d, f64, f64, bool, generic_array::typenum::U6, |a, b| a == b);
+impl_op!(ne_f64_1d, f64, f64, bool, generic_array::typenum::U1, |a, b| a != b);
+impl_op!(ne_f64_2d, f64, f64, bool, generic_array::typenum::U2, |a, b| a != b);
+impl_op!(ne_f64_3d, f64, f64, bool, generic_array::typenum::U3, |a, b| a != b);
+impl_op!(ne_f64_4d, f64, f64, bool, generic_array::typenum::U4, |a, b| a != b);
+impl_op!(ne_f64_5d, f64, f64, bool, generic_array::typenum::U5, |a, b| a != b);
+impl_op!(ne_f64_6d, f64, f64, bool, generic_array::typenum::U6, |a, b| a != b);
+impl_op!(lt_f64_1d, f64, f64, bool, generic_array::typenum::U1, |a, b| a < b);
+impl_op!(lt_f64_2d, f64, f64, bool, generic_array::typenum::U2, |a, b| a < b);
+impl_op!(lt_f64_3d, f64, f64, bool, generic_array::typenum::U3, |a, b| a < b);
+impl_op!(lt_f64_4d, f64, f64, bool, generic_array::typenum::U4, |a, b| a < b);
+impl_op!(lt_f64_5d, f64, f64, bool, generic_array::typenum::U5, |a, b| a < b);
+impl_op!(lt_f64_6d, f64, f64, bool, generic_array::typenum::U6, |a, b| a < b);
+impl_op!(le_f64_1d, f64, f64, bool, generic_array::typenum::U1, |a, b| a <= b);
+impl_op!(le_f64_2d, f64, f64, bool, generic_array::typenum::U2, |a, b| a <= b);
+impl_op!(le_f64_3d, f64, f64, bool, generic_array::typenum::U3, |a, b| a <= b);
+impl_op!(le_f64_4d, f64, f64, bool, generic_array::typenum::U4, |a, b| a <= b);
+impl_op!(le_f64_5d, f64, f64, bool, generic_array::typenum::U5, |a, b| a <= b);
+impl_op!(le_f64_6d, f64, f64, bool, generic_array::typenum::U6, |a, b| a <= b);
+impl_op!(gt_f64_1d, f64, f64, bool, generic_array::typenum::U1, |a, b| a > b);
+impl_op!(gt_f64_2d, f64, f64, bool, generic_array::typenum::U2, |a, b| a > b);
+impl_op!(gt_f64_3d, f64, f64, bool, generic_array::typenum::U3, |a, b| a > b);
+impl_op!(gt_f64_4d, f64, f64, bool, generic_array::typenum::U4, |a, b| a > b);
+impl_op!(gt_f64_5d, f64, f64, bool, generic_array::typenum::U5, |a, b| a > b);
+impl_op!(gt_f64_6d, f64, f64, bool, generic_array::typenum::U6, |a, b| a > b);
+impl_op!(ge_f64_1d, f64, f64, bool, generic_array::typenum::U1, |a, b| a >= b);
+impl_op!(ge_f64_2d, f64, f64, bool, generic_array::typenum::U2, |a, b| a >= b);
+impl_op!(ge_f64_3d, f64, f64, bool, generic_array::typenum::U3, |a, b| a >= b);
+impl_op!(ge_f64_4d, f64, f64, bool, generic_array::typenum::U4, |a, b| a >= b);
+impl_op!(ge_f64_5d, f64, f64, bool, generic_array::typenum::U5, |a, b| a >= b);
+impl_op!(ge_f64_6d, f64, f64, bool, generic_array::typenum::U6, |a, b| a >= b);
+
+impl_op!(add_f16_1d, f16, f16, f16, generic_array::typenum::U1, |a, b| a + b);
+impl_op!(add_f16_2d, f16, f16, f16, generic_array::typenum::U2, |a, b| a + b);
+impl_op!(add_f16_3d, f16, f16, f16, generic_array::typenum::U3, |a, b| a + b);
+impl_op!(add_f16_4d, f16, f16, f16, generic_array::typenum::U4, |a, b| a + b);
+impl_op!(add_f16_5d, f16, f16, f16, generic_array::typenum::U5, |a, b| a + b);
+impl_op!(add_f16_6d, f16, f16, f16, generic_array::typenum::U6, |a, b| a + b);
+impl_op!(mul_f16_1d, f16, f16, f16, generic_array::typenum::U1, |a, b| a * b);
+impl_op!(mul_f16_2d, f16, f16, f16, generic_array::typenum::U2, |a, b| a * b);
+impl_op!(mul_f16_3d, f16, f16, f16, generic_array::typenum::U3, |a, b| a * b);
+impl_op!(mul_f16_4d, f16, f16, f16, generic_array::typenum::U4, |a, b| a * b);
+impl_op!(mul_f16_5d, f16, f16, f16, generic_array::typenum::U5, |a, b| a * b);
+impl_op!(mul_f16_6d, f16, f16, f16, generic_array::typenum::U6, |a, b| a * b);
+impl_op!(sub_f16_1d, f16, f16, f16, generic_array::typenum::U1, |a, b| a - b);
+impl_op!(sub_f16_2d, f16, f16, f16, generic_array::typenum::U2, |a, b| a - b);
+impl_op!(sub_f16_3d, f16, f16, f16, generic_array::typenum::U3, |a, b| a - b);
+impl_op!(sub_f16_4d, f16, f16, f16, generic_array::typenum::U4, |a, b| a - b);
+impl_op!(sub_f16_5d, f16, f16, f16, generic_array::typenum::U5, |a, b| a - b);
+impl_op!(sub_f16_6d, f16, f16, f16, generic_array::typenum::U6, |a, b| a - b);
+impl_op!(div_f16_1d, f16, f16, f16, generic_array::typenum::U1, |a, b| a / b);
+impl_op!(div_f16_2d, f16, f16, f16, generic_array::typenum::U2, |a, b| a / b);
+impl_op!(div_f16_3d, f16, f16, f16, generic_array::typenum::U3, |a, b| a / b);
+impl_op!(div_f16_4d, f16, f16, f16, generic_array::typenum::U4, |a, b| a / b);
+impl_op!(div_f16_5d, f16, f16, f16, generic_array::typenum::U5, |a, b| a / b);
+impl_op!(div_f16_6d, f16, f16, f16, generic_array::typenum::U6, |a, b| a / b);
+impl_op!(eq_f16_1d, f16, f16, bool, generic_array::typenum::U1, |a, b| a == b);
+impl_op!(eq_f16_2d, f16, f16, bool, generic_array::typenum::U2, |a, b| a == b);
+impl_op!(eq_f16_3d, f16, f16, bool, generic_array::typenum::U3, |a, b| a == b);
+impl_op!(eq_f16_4d, f16, f16, bool, generic_array::typenum::U4, |a, b| a == b);
+impl_op!(eq_f16_5d, f16, f16, bool, generic_array::typenum::U5, |a, b| a == b);
+impl_op!(eq_f16_6d, f16, f16, bool, generic_array::typenum::U6, |a, b| a == b);
+impl_op!(ne_f16_1d, f16, f16, bool, generic_array::typenum::U1, |a, b| a != b);
+impl_op!(ne_f16_2d, f16, f16, bool, generic_array::typenum::U2, |a, b| a != b);
+impl_op!(ne_f16_3d, f16, f16, bool, generic_array::typenum::U3, |a, b| a != b);
+impl_op!(ne_f16_4d, f16, f16, bool, generic_array::typenum::U4, |a, b| a != b);
+impl_op!(ne_f16_5d, f16, f16, bool, generic_array::typenum::U5, |a, b| a != b);
+impl_op!(ne_f16_6d, f16, f16, bool, generic_array::typenum::U6, |a, b| a != b);
+impl_op!(lt_f16_1d, f16, f16, bool, generic_array::typenum::U1, |a, b| a < b);
+impl_op!(lt_f16_2d, f16, f16, bool, generic_array::typenum::U2, |a, b| a < b);
+impl_op!(lt_f16_3d, f16, f16, bool, generic_array::typenum::U3, |a, b| a < b);
+impl_op!(lt_f16_4d, f16, f16, bool, generic_array::typenum::U4, |a, b| a < b);
+impl_op!(lt_f16_5d, f16, f16, bool, generic_array::typenum::U5, |a, b| a < b);
+impl_op!(lt_f16_6d, f16, f16, bool, generic_array::typenum::U6, |a, b| a < b);
+impl_op!(le_f16_1d, f16, f16, bool, generic_array::typenum::U1, |a, b| a <= b);
+impl_op!(le_f16_2d, f16, f16, bool, generic_array::typenum::U2, |a, b| a <= b);
+impl_op!(le_f16_3d, f16, f16, bool, generic_array::typenum::U3, |a, b| a <= b);
+impl_op!(le_f16_4d, f16, f16, bool, generic_array::typenum::U4, |a, b| a <= b);
+impl_op!(le_f16_5d, f16, f16, bool, generic_array::typenum::U5, |a, b| a <= b);
+impl_op!(le_f16_6d, f16, f16, bool, generic_array::typenum::U6, |a, b| a <= b);
+impl_op!(gt_f16_1d, f16, f16, bool, generic_array::typenum::U1, |a, b| a > b);
+impl_op!(gt_f16_2d, f16, f16, bool, generic_array::typenum::U2, |a, b| a > b);
+impl_op!(gt_f16_3d, f16, f16, bool, generic_array::typenum::U3, |a, b| a > b);
+impl_op!(gt_f16_4d, f16, f16, bool, generic_array::typenum::U4, |a, b| a > b);
+impl_op!(gt_f16_5d, f16, f16, bool, generic_array::typenum::U5, |a, b| a > b);
+impl_op!(gt_f16_6d, f16, f16, bool, generic_array::typenum::U6, |a, b| a > b);
+impl_op!(ge_f16_1d, f16, f16, bool, generic_array::typenum::U1, |a, b| a >= b);
+impl_op!(ge_f16_2d, f16, f16, bool, generic_array::typenum::U2, |a, b| a >= b);
+impl_op!(ge_f16_3d, f16, f16, bool, generic_array::typenum::U3, |a, b| a >= b);
+impl_op!(ge_f16_4d, f16, f16, bool, generic_array::typenum::U4, |a, b| a >= b);
+impl_op!(ge_f16_5d, f16, f16, bool, generic_array::typenum::U5, |a, b| a >= b);
+impl_op!(ge_f16_6d, f16, f16, bool, generic_array::typenum::U6, |a, b| a >= b);
+
+impl_op!(add_bf16_1d, bf16, bf16, bf16, generic_array::typenum::U1, |a, b| a + b);
+impl_op!(add_bf16_2d, bf16, bf16, bf16, generic_array::typenum::U2, |a, b| a + b);
+impl_op!(add_bf16_3d, bf16, bf16, bf16, generic_array::typenum::U3, |a, b| a + b);
+impl_op!(add_bf16_4d, bf16, bf16, bf16, generic_array::typenum::U4, |a, b| a + b);
+impl_op!(add_bf16_5d, bf16, bf16, bf16, generic_array::typenum::U5, |a, b| a + b);
+impl_op!(add_bf16_6d, bf16, bf16, bf16, generic_array::typenum::U6, |a, b| a + b);
+impl_op!(mul_bf16_1d, bf16, bf16, bf16, generic_array::typenum::U1, |a, b| a * b);
+impl_op!(mul_bf16_2d, bf16, bf16, bf16, generic_array::typenum::U2, |a, b| a * b);
+impl_op!(mul_bf16_3d, bf16, bf16, bf16, generic_array::typenum::U3, |a, b| a * b);
+impl_op!(mul_bf16_4d, bf16, bf16, bf16, generic_array::typenum::U4, |a, b| a * b);
+impl_op!(mul_bf16_5d, bf16, bf16, bf16, generic_array::typenum::U5, |a, b| a * b);
+impl_op!(mul_bf16_6d, bf16, bf16, bf16, generic_array::typenum::U6, |a, b| a * b);
+impl_op!(sub_bf16_1d, bf16, bf16, bf16, generic_array::typenum::U1, |a, b| a - b);
+impl_op!(sub_bf16_2d, bf16, bf16, bf16, generic_array::typenum::U2, |a, b| a - b);
+impl_op!(sub_bf16_3d, bf16, bf16, bf16, generic_array::typenum::U3, |a, b| a - b);
+impl_op!(sub_bf16_4d, bf16, bf16, bf16, generic_array::typenum::U4, |a, b| a - b);
+impl_op!(sub_bf16_5d, bf16, bf16, bf16, generic_array::typenum::U5, |a, b| a - b);
+impl_op!(sub_bf16_6d, bf16, bf16, bf16, generic_array::typenum::U6, |a, b| a - b);
+impl_op!(div_bf16_1d, bf16, bf16, bf16, generic_array::typenum::U1, |a, b| a / b);
+impl_op!(div_bf16_2d, bf16, bf16, bf16, generic_array::typenum::U2, |a, b| a / b);
+impl_op!(div_bf16_3d, bf16, bf16, bf16, generic_array::typenum::U3, |a, b| a / b);
+impl_op!(div_bf16_4d, bf16, bf16, bf16, generic_array::typenum::U4, |a, b| a / b);
+impl_op!(div_bf16_5d, bf16, bf16, bf16, generic_array::typenum::U5, |a, b| a / b);
+impl_op!(div_bf16_6d, bf16, bf16, bf16, generic_array::typenum::U6, |a, b| a / b);
+impl_op!(eq_bf16_1d, bf16, bf16, bool, generic_array::typenum::U1, |a, b| a == b);
+impl_op!(eq_bf16_2d, bf16, bf16, bool, generic_array::typenum::U2, |a, b| a == b);
+impl_op!(eq_bf16_3d, bf16, bf16, bool, generic_array::typenum::U3, |a, b| a == b);
+impl_op!(eq_bf16_4d, bf16, bf16, bool, generic_array::typenum::U4, |a, b| a == b);
+impl_op!(eq_bf16_5d, bf16, bf16, bool, generic_array::typenum::U5, |a, b| a == b);
+impl_op!(eq_bf16_6d, bf16, bf16, bool, generic_array::typenum::U6, |a, b| a == b);
+impl_op!(ne_bf16_1d, bf16, bf16, bool, generic_array::typenum::U1, |a, b| a != b);
+impl_op!(ne_bf16_2d, bf16, bf16, bool, generic_array::typenum::U2, |a, b| a != b);
+impl_op!(ne_bf16_3d, bf16, bf16, bool, generic_array::typenum::U3, |a, b| a != b);
+impl_op!(ne_bf16_4d, bf16, bf16, bool, generic_array::typenum::U4, |a, b| a != b);
+impl_op!(ne_bf16_5d, bf16, bf16, bool, generic_array::typenum::U5, |a, b| a != b);
+impl_op!(ne_bf16_6d, bf16, bf16, bool, generic_array::typenum::U6, |a, b| a != b);
+impl_op!(lt_bf16_1d, bf16, bf16, bool, generic_array::typenum::U1, |a, b| a < b);
+impl_op!(lt_bf16_2d, bf16, bf16, bool, generic_array::typenum::U2, |a, b| a < b);
+impl_op!(lt_bf16_3d, bf16, bf16, bool, generic_array::typenum::U3, |a, b| a < b);
+impl_op!(lt_bf16_4d, bf16, bf16, bool, generic_array::typenum::U4, |a, b| a < b);
+impl_op!(lt_bf16_5d, bf16, bf16, bool, generic_array::typenum::U5, |a, b| a < b);
+impl_op!(lt_bf16_6d, bf16, bf16, bool, generic_array::typenum::U6, |a, b| a < b);
+impl_op!(le_bf16_1d, bf16, bf16, bool, generic_array::typenum::U1, |a, b| a <= b);
+impl_op!(le_bf16_2d, bf16, bf16, bool, generic_array::typenum::U2, |a, b| a <= b);
+impl_op!(le_bf16_3d, bf16, bf16, bool, generic_array::typenum::U3, |a, b| a <= b);
+impl_op!(le_bf16_4d, bf16, bf16, bool, generic_array::typenum::U4, |a, b| a <= b);
+impl_op!(le_bf16_5d, bf16, bf16, bool, generic_array::typenum::U5, |a, b| a <= b);
+impl_op!(le_bf16_6d, bf16, bf16, bool, generic_array::typenum::U6, |a, b| a <= b);
+impl_op!(gt_bf16_1d, bf16, bf16, bool, generic_array::typenum::U1, |a, b| a > b);
+impl_op!(gt_bf16_2d, bf16, bf16, bool, generic_array::typenum::U2, |a, b| a > b);
+impl_op!(gt_bf16_3d, bf16, bf16, bool, generic_array::typenum::U3, |a, b| a > b);
+impl_op!(gt_bf16_4d, bf16, bf16, bool, generic_array::typenum::U4, |a, b| a > b);
+impl_op!(gt_bf16_5d, bf16, bf16, bool, generic_array::typenum::U5, |a, b| a > b);
+impl_op!(gt_bf16_6d, bf16, bf16, bool, generic_array::typenum::U6, |a, b| a > b);
+impl_op!(ge_bf16_1d, bf16, bf16, bool, generic_array::typenum::U1, |a, b| a >= b);
+impl_op!(ge_bf16_2d, bf16, bf16, bool, generic_array::typenum::U2, |a, b| a >= b);
+impl_op!(ge_bf16_3d, bf16, bf16, bool, generic_array::typenum::U3, |a, b| a >= b);
+impl_op!(ge_bf16_4d, bf16, bf16, bool, generic_array::typenum::U4, |a, b| a >= b);
+impl_op!(ge_bf16_5d, bf16, bf16, bool, generic_array::typenum::U5, |a, b| a >= b);
+impl_op!(ge_bf16_6d, bf16, bf16, bool, generic_array::typenum::U6, |a, b| a >= b);
 
 impl_op!(eq_bool_1d, bool, bool, bool, generic_array::typenum::U1, |a, b| a == b);
 impl_op!(eq_bool_2d, bool, bool, bool, generic_array::typenum::U2, |a, b| a == b);
@@ -105,6 +298,12 @@ impl_op!(eq_bool_3d, bool, bool, bool, generic_array::typenum::U3, |a, b| a == b
 impl_op!(eq_bool_4d, bool, bool, bool, generic_array::typenum::U4, |a, b| a == b);
 impl_op!(eq_bool_5d, bool, bool, bool, generic_array::typenum::U5, |a, b| a == b);
 impl_op!(eq_bool_6d, bool, bool, bool, generic_array::typenum::U6, |a, b| a == b);
+impl_op!(ne_bool_1d, bool, bool, bool, generic_array::typenum::U1, |a, b| a != b);
+impl_op!(ne_bool_2d, bool, bool, bool, generic_array::typenum::U2, |a, b| a != b);
+impl_op!(ne_bool_3d, bool, bool, bool, generic_array::typenum::U3, |a, b| a != b);
+impl_op!(ne_bool_4d, bool, bool, bool, generic_array::typenum::U4, |a, b| a != b);
+impl_op!(ne_bool_5d, bool, bool, bool, generic_array::typenum::U5, |a, b| a != b);
+impl_op!(ne_bool_6d, bool, bool, bool, generic_array::typenum::U6, |a, b| a != b);
 impl_op!(and_bool_1d, bool, bool, bool, generic_array::typenum::U1, |a, b| a & b);
 impl_op!(and_bool_2d, bool, bool, bool, generic_array::typenum::U2, |a, b| a & b);
 impl_op!(and_bool_3d, bool, bool, bool, generic_array::typenum::U3, |a, b| a & b);
@@ -117,6 +316,2211 @@ impl_op!(or_bool_3d, bool, bool, bool, generic_array::typenum::U3, |a, b| a | b)
 impl_op!(or_bool_4d, bool, bool, bool, generic_array::typenum::U4, |a, b| a | b);
 impl_op!(or_bool_5d, bool, bool, bool, generic_array::typenum::U5, |a, b| a | b);
 impl_op!(or_bool_6d, bool, bool, bool, generic_array::typenum::U6, |a, b| a | b);
+impl_op!(xor_bool_1d, bool, bool, bool, generic_array::typenum::U1, |a, b| a ^ b);
+impl_op!(xor_bool_2d, bool, bool, bool, generic_array::typenum::U2, |a, b| a ^ b);
+impl_op!(xor_bool_3d, bool, bool, bool, generic_array::typenum::U3, |a, b| a ^ b);
+impl_op!(xor_bool_4d, bool, bool, bool, generic_array::typenum::U4, |a, b| a ^ b);
+impl_op!(xor_bool_5d, bool, bool, bool, generic_array::typenum::U5, |a, b| a ^ b);
+impl_op!(xor_bool_6d, bool, bool, bool, generic_array::typenum::U6, |a, b| a ^ b);
+impl_op!(lt_bool_1d, bool, bool, bool, generic_array::typenum::U1, |a, b| !a & b);
+impl_op!(lt_bool_2d, bool, bool, bool, generic_array::typenum::U2, |a, b| !a & b);
+impl_op!(lt_bool_3d, bool, bool, bool, generic_array::typenum::U3, |a, b| !a & b);
+impl_op!(lt_bool_4d, bool, bool, bool, generic_array::typenum::U4, |a, b| !a & b);
+impl_op!(lt_bool_5d, bool, bool, bool, generic_array::typenum::U5, |a, b| !a & b);
+impl_op!(lt_bool_6d, bool, bool, bool, generic_array::typenum::U6, |a, b| !a & b);
+impl_op!(le_bool_1d, bool, bool, bool, generic_array::typenum::U1, |a, b| !a | b);
+impl_op!(le_bool_2d, bool, bool, bool, generic_array::typenum::U2, |a, b| !a | b);
+impl_op!(le_bool_3d, bool, bool, bool, generic_array::typenum::U3, |a, b| !a | b);
+impl_op!(le_bool_4d, bool, bool, bool, generic_array::typenum::U4, |a, b| !a | b);
+impl_op!(le_bool_5d, bool, bool, bool, generic_array::typenum::U5, |a, b| !a | b);
+impl_op!(le_bool_6d, bool, bool, bool, generic_array::typenum::U6, |a, b| !a | b);
+impl_op!(gt_bool_1d, bool, bool, bool, generic_array::typenum::U1, |a, b| a & !b);
+impl_op!(gt_bool_2d, bool, bool, bool, generic_array::typenum::U2, |a, b| a & !b);
+impl_op!(gt_bool_3d, bool, bool, bool, generic_array::typenum::U3, |a, b| a & !b);
+impl_op!(gt_bool_4d, bool, bool, bool, generic_array::typenum::U4, |a, b| a & !b);
+impl_op!(gt_bool_5d, bool, bool, bool, generic_array::typenum::U5, |a, b| a & !b);
+impl_op!(gt_bool_6d, bool, bool, bool, generic_array::typenum::U6, |a, b| a & !b);
+impl_op!(ge_bool_1d, bool, bool, bool, generic_array::typenum::U1, |a, b| a | !b);
+impl_op!(ge_bool_2d, bool, bool, bool, generic_array::typenum::U2, |a, b| a | !b);
+impl_op!(ge_bool_3d, bool, bool, bool, generic_array::typenum::U3, |a, b| a | !b);
+impl_op!(ge_bool_4d, bool, bool, bool, generic_array::typenum::U4, |a, b| a | !b);
+impl_op!(ge_bool_5d, bool, bool, bool, generic_array::typenum::U5, |a, b| a | !b);
+impl_op!(ge_bool_6d, bool, bool, bool, generic_array::typenum::U6, |a, b| a | !b);
+
+impl_op!(add_i8_1d, i8, i8, i8, generic_array::typenum::U1, Arith::add);
+impl_op!(add_i8_2d, i8, i8, i8, generic_array::typenum::U2, Arith::add);
+impl_op!(add_i8_3d, i8, i8, i8, generic_array::typenum::U3, Arith::add);
+impl_op!(add_i8_4d, i8, i8, i8, generic_array::typenum::U4, Arith::add);
+impl_op!(add_i8_5d, i8, i8, i8, generic_array::typenum::U5, Arith::add);
+impl_op!(add_i8_6d, i8, i8, i8, generic_array::typenum::U6, Arith::add);
+impl_op!(sub_i8_1d, i8, i8, i8, generic_array::typenum::U1, Arith::sub);
+impl_op!(sub_i8_2d, i8, i8, i8, generic_array::typenum::U2, Arith::sub);
+impl_op!(sub_i8_3d, i8, i8, i8, generic_array::typenum::U3, Arith::sub);
+impl_op!(sub_i8_4d, i8, i8, i8, generic_array::typenum::U4, Arith::sub);
+impl_op!(sub_i8_5d, i8, i8, i8, generic_array::typenum::U5, Arith::sub);
+impl_op!(sub_i8_6d, i8, i8, i8, generic_array::typenum::U6, Arith::sub);
+impl_op!(mul_i8_1d, i8, i8, i8, generic_array::typenum::U1, Arith::mul);
+impl_op!(mul_i8_2d, i8, i8, i8, generic_array::typenum::U2, Arith::mul);
+impl_op!(mul_i8_3d, i8, i8, i8, generic_array::typenum::U3, Arith::mul);
+impl_op!(mul_i8_4d, i8, i8, i8, generic_array::typenum::U4, Arith::mul);
+impl_op!(mul_i8_5d, i8, i8, i8, generic_array::typenum::U5, Arith::mul);
+impl_op!(mul_i8_6d, i8, i8, i8, generic_array::typenum::U6, Arith::mul);
+impl_op!(div_i8_1d, i8, i8, i8, generic_array::typenum::U1, Arith::div);
+impl_op!(div_i8_2d, i8, i8, i8, generic_array::typenum::U2, Arith::div);
+impl_op!(div_i8_3d, i8, i8, i8, generic_array::typenum::U3, Arith::div);
+impl_op!(div_i8_4d, i8, i8, i8, generic_array::typenum::U4, Arith::div);
+impl_op!(div_i8_5d, i8, i8, i8, generic_array::typenum::U5, Arith::div);
+impl_op!(div_i8_6d, i8, i8, i8, generic_array::typenum::U6, Arith::div);
+impl_op!(rem_i8_1d, i8, i8, i8, generic_array::typenum::U1, Integer::rem);
+impl_op!(rem_i8_2d, i8, i8, i8, generic_array::typenum::U2, Integer::rem);
+impl_op!(rem_i8_3d, i8, i8, i8, generic_array::typenum::U3, Integer::rem);
+impl_op!(rem_i8_4d, i8, i8, i8, generic_array::typenum::U4, Integer::rem);
+impl_op!(rem_i8_5d, i8, i8, i8, generic_array::typenum::U5, Integer::rem);
+impl_op!(rem_i8_6d, i8, i8, i8, generic_array::typenum::U6, Integer::rem);
+impl_op!(and_i8_1d, i8, i8, i8, generic_array::typenum::U1, |a, b| a & b);
+impl_op!(and_i8_2d, i8, i8, i8, generic_array::typenum::U2, |a, b| a & b);
+impl_op!(and_i8_3d, i8, i8, i8, generic_array::typenum::U3, |a, b| a & b);
+impl_op!(and_i8_4d, i8, i8, i8, generic_array::typenum::U4, |a, b| a & b);
+impl_op!(and_i8_5d, i8, i8, i8, generic_array::typenum::U5, |a, b| a & b);
+impl_op!(and_i8_6d, i8, i8, i8, generic_array::typenum::U6, |a, b| a & b);
+impl_op!(or_i8_1d, i8, i8, i8, generic_array::typenum::U1, |a, b| a | b);
+impl_op!(or_i8_2d, i8, i8, i8, generic_array::typenum::U2, |a, b| a | b);
+impl_op!(or_i8_3d, i8, i8, i8, generic_array::typenum::U3, |a, b| a | b);
+impl_op!(or_i8_4d, i8, i8, i8, generic_array::typenum::U4, |a, b| a | b);
+impl_op!(or_i8_5d, i8, i8, i8, generic_array::typenum::U5, |a, b| a | b);
+impl_op!(or_i8_6d, i8, i8, i8, generic_array::typenum::U6, |a, b| a | b);
+impl_op!(xor_i8_1d, i8, i8, i8, generic_array::typenum::U1, |a, b| a ^ b);
+impl_op!(xor_i8_2d, i8, i8, i8, generic_array::typenum::U2, |a, b| a ^ b);
+impl_op!(xor_i8_3d, i8, i8, i8, generic_array::typenum::U3, |a, b| a ^ b);
+impl_op!(xor_i8_4d, i8, i8, i8, generic_array::typenum::U4, |a, b| a ^ b);
+impl_op!(xor_i8_5d, i8, i8, i8, generic_array::typenum::U5, |a, b| a ^ b);
+impl_op!(xor_i8_6d, i8, i8, i8, generic_array::typenum::U6, |a, b| a ^ b);
+impl_op!(shl_i8_1d, i8, i8, i8, generic_array::typenum::U1, Integer::shl);
+impl_op!(shl_i8_2d, i8, i8, i8, generic_array::typenum::U2, Integer::shl);
+impl_op!(shl_i8_3d, i8, i8, i8, generic_array::typenum::U3, Integer::shl);
+impl_op!(shl_i8_4d, i8, i8, i8, generic_array::typenum::U4, Integer::shl);
+impl_op!(shl_i8_5d, i8, i8, i8, generic_array::typenum::U5, Integer::shl);
+impl_op!(shl_i8_6d, i8, i8, i8, generic_array::typenum::U6, Integer::shl);
+impl_op!(shr_i8_1d, i8, i8, i8, generic_array::typenum::U1, Integer::shr);
+impl_op!(shr_i8_2d, i8, i8, i8, generic_array::typenum::U2, Integer::shr);
+impl_op!(shr_i8_3d, i8, i8, i8, generic_array::typenum::U3, Integer::shr);
+impl_op!(shr_i8_4d, i8, i8, i8, generic_array::typenum::U4, Integer::shr);
+impl_op!(shr_i8_5d, i8, i8, i8, generic_array::typenum::U5, Integer::shr);
+impl_op!(shr_i8_6d, i8, i8, i8, generic_array::typenum::U6, Integer::shr);
+impl_op!(eq_i8_1d, i8, i8, bool, generic_array::typenum::U1, |a, b| a == b);
+impl_op!(eq_i8_2d, i8, i8, bool, generic_array::typenum::U2, |a, b| a == b);
+impl_op!(eq_i8_3d, i8, i8, bool, generic_array::typenum::U3, |a, b| a == b);
+impl_op!(eq_i8_4d, i8, i8, bool, generic_array::typenum::U4, |a, b| a == b);
+impl_op!(eq_i8_5d, i8, i8, bool, generic_array::typenum::U5, |a, b| a == b);
+impl_op!(eq_i8_6d, i8, i8, bool, generic_array::typenum::U6, |a, b| a == b);
+impl_op!(ne_i8_1d, i8, i8, bool, generic_array::typenum::U1, |a, b| a != b);
+impl_op!(ne_i8_2d, i8, i8, bool, generic_array::typenum::U2, |a, b| a != b);
+impl_op!(ne_i8_3d, i8, i8, bool, generic_array::typenum::U3, |a, b| a != b);
+impl_op!(ne_i8_4d, i8, i8, bool, generic_array::typenum::U4, |a, b| a != b);
+impl_op!(ne_i8_5d, i8, i8, bool, generic_array::typenum::U5, |a, b| a != b);
+impl_op!(ne_i8_6d, i8, i8, bool, generic_array::typenum::U6, |a, b| a != b);
+impl_op!(lt_i8_1d, i8, i8, bool, generic_array::typenum::U1, |a, b| a < b);
+impl_op!(lt_i8_2d, i8, i8, bool, generic_array::typenum::U2, |a, b| a < b);
+impl_op!(lt_i8_3d, i8, i8, bool, generic_array::typenum::U3, |a, b| a < b);
+impl_op!(lt_i8_4d, i8, i8, bool, generic_array::typenum::U4, |a, b| a < b);
+impl_op!(lt_i8_5d, i8, i8, bool, generic_array::typenum::U5, |a, b| a < b);
+impl_op!(lt_i8_6d, i8, i8, bool, generic_array::typenum::U6, |a, b| a < b);
+impl_op!(le_i8_1d, i8, i8, bool, generic_array::typenum::U1, |a, b| a <= b);
+impl_op!(le_i8_2d, i8, i8, bool, generic_array::typenum::U2, |a, b| a <= b);
+impl_op!(le_i8_3d, i8, i8, bool, generic_array::typenum::U3, |a, b| a <= b);
+impl_op!(le_i8_4d, i8, i8, bool, generic_array::typenum::U4, |a, b| a <= b);
+impl_op!(le_i8_5d, i8, i8, bool, generic_array::typenum::U5, |a, b| a <= b);
+impl_op!(le_i8_6d, i8, i8, bool, generic_array::typenum::U6, |a, b| a <= b);
+impl_op!(gt_i8_1d, i8, i8, bool, generic_array::typenum::U1, |a, b| a > b);
+impl_op!(gt_i8_2d, i8, i8, bool, generic_array::typenum::U2, |a, b| a > b);
+impl_op!(gt_i8_3d, i8, i8, bool, generic_array::typenum::U3, |a, b| a > b);
+impl_op!(gt_i8_4d, i8, i8, bool, generic_array::typenum::U4, |a, b| a > b);
+impl_op!(gt_i8_5d, i8, i8, bool, generic_array::typenum::U5, |a, b| a > b);
+impl_op!(gt_i8_6d, i8, i8, bool, generic_array::typenum::U6, |a, b| a > b);
+impl_op!(ge_i8_1d, i8, i8, bool, generic_array::typenum::U1, |a, b| a >= b);
+impl_op!(ge_i8_2d, i8, i8, bool, generic_array::typenum::U2, |a, b| a >= b);
+impl_op!(ge_i8_3d, i8, i8, bool, generic_array::typenum::U3, |a, b| a >= b);
+impl_op!(ge_i8_4d, i8, i8, bool, generic_array::typenum::U4, |a, b| a >= b);
+impl_op!(ge_i8_5d, i8, i8, bool, generic_array::typenum::U5, |a, b| a >= b);
+impl_op!(ge_i8_6d, i8, i8, bool, generic_array::typenum::U6, |a, b| a >= b);
+
+impl_op!(add_i16_1d, i16, i16, i16, generic_array::typenum::U1, Arith::add);
+impl_op!(add_i16_2d, i16, i16, i16, generic_array::typenum::U2, Arith::add);
+impl_op!(add_i16_3d, i16, i16, i16, generic_array::typenum::U3, Arith::add);
+impl_op!(add_i16_4d, i16, i16, i16, generic_array::typenum::U4, Arith::add);
+impl_op!(add_i16_5d, i16, i16, i16, generic_array::typenum::U5, Arith::add);
+impl_op!(add_i16_6d, i16, i16, i16, generic_array::typenum::U6, Arith::add);
+impl_op!(sub_i16_1d, i16, i16, i16, generic_array::typenum::U1, Arith::sub);
+impl_op!(sub_i16_2d, i16, i16, i16, generic_array::typenum::U2, Arith::sub);
+impl_op!(sub_i16_3d, i16, i16, i16, generic_array::typenum::U3, Arith::sub);
+impl_op!(sub_i16_4d, i16, i16, i16, generic_array::typenum::U4, Arith::sub);
+impl_op!(sub_i16_5d, i16, i16, i16, generic_array::typenum::U5, Arith::sub);
+impl_op!(sub_i16_6d, i16, i16, i16, generic_array::typenum::U6, Arith::sub);
+impl_op!(mul_i16_1d, i16, i16, i16, generic_array::typenum::U1, Arith::mul);
+impl_op!(mul_i16_2d, i16, i16, i16, generic_array::typenum::U2, Arith::mul);
+impl_op!(mul_i16_3d, i16, i16, i16, generic_array::typenum::U3, Arith::mul);
+impl_op!(mul_i16_4d, i16, i16, i16, generic_array::typenum::U4, Arith::mul);
+impl_op!(mul_i16_5d, i16, i16, i16, generic_array::typenum::U5, Arith::mul);
+impl_op!(mul_i16_6d, i16, i16, i16, generic_array::typenum::U6, Arith::mul);
+impl_op!(div_i16_1d, i16, i16, i16, generic_array::typenum::U1, Arith::div);
+impl_op!(div_i16_2d, i16, i16, i16, generic_array::typenum::U2, Arith::div);
+impl_op!(div_i16_3d, i16, i16, i16, generic_array::typenum::U3, Arith::div);
+impl_op!(div_i16_4d, i16, i16, i16, generic_array::typenum::U4, Arith::div);
+impl_op!(div_i16_5d, i16, i16, i16, generic_array::typenum::U5, Arith::div);
+impl_op!(div_i16_6d, i16, i16, i16, generic_array::typenum::U6, Arith::div);
+impl_op!(rem_i16_1d, i16, i16, i16, generic_array::typenum::U1, Integer::rem);
+impl_op!(rem_i16_2d, i16, i16, i16, generic_array::typenum::U2, Integer::rem);
+impl_op!(rem_i16_3d, i16, i16, i16, generic_array::typenum::U3, Integer::rem);
+impl_op!(rem_i16_4d, i16, i16, i16, generic_array::typenum::U4, Integer::rem);
+impl_op!(rem_i16_5d, i16, i16, i16, generic_array::typenum::U5, Integer::rem);
+impl_op!(rem_i16_6d, i16, i16, i16, generic_array::typenum::U6, Integer::rem);
+impl_op!(and_i16_1d, i16, i16, i16, generic_array::typenum::U1, |a, b| a & b);
+impl_op!(and_i16_2d, i16, i16, i16, generic_array::typenum::U2, |a, b| a & b);
+impl_op!(and_i16_3d, i16, i16, i16, generic_array::typenum::U3, |a, b| a & b);
+impl_op!(and_i16_4d, i16, i16, i16, generic_array::typenum::U4, |a, b| a & b);
+impl_op!(and_i16_5d, i16, i16, i16, generic_array::typenum::U5, |a, b| a & b);
+impl_op!(and_i16_6d, i16, i16, i16, generic_array::typenum::U6, |a, b| a & b);
+impl_op!(or_i16_1d, i16, i16, i16, generic_array::typenum::U1, |a, b| a | b);
+impl_op!(or_i16_2d, i16, i16, i16, generic_array::typenum::U2, |a, b| a | b);
+impl_op!(or_i16_3d, i16, i16, i16, generic_array::typenum::U3, |a, b| a | b);
+impl_op!(or_i16_4d, i16, i16, i16, generic_array::typenum::U4, |a, b| a | b);
+impl_op!(or_i16_5d, i16, i16, i16, generic_array::typenum::U5, |a, b| a | b);
+impl_op!(or_i16_6d, i16, i16, i16, generic_array::typenum::U6, |a, b| a | b);
+impl_op!(xor_i16_1d, i16, i16, i16, generic_array::typenum::U1, |a, b| a ^ b);
+impl_op!(xor_i16_2d, i16, i16, i16, generic_array::typenum::U2, |a, b| a ^ b);
+impl_op!(xor_i16_3d, i16, i16, i16, generic_array::typenum::U3, |a, b| a ^ b);
+impl_op!(xor_i16_4d, i16, i16, i16, generic_array::typenum::U4, |a, b| a ^ b);
+impl_op!(xor_i16_5d, i16, i16, i16, generic_array::typenum::U5, |a, b| a ^ b);
+impl_op!(xor_i16_6d, i16, i16, i16, generic_array::typenum::U6, |a, b| a ^ b);
+impl_op!(shl_i16_1d, i16, i16, i16, generic_array::typenum::U1, Integer::shl);
+impl_op!(shl_i16_2d, i16, i16, i16, generic_array::typenum::U2, Integer::shl);
+impl_op!(shl_i16_3d, i16, i16, i16, generic_array::typenum::U3, Integer::shl);
+impl_op!(shl_i16_4d, i16, i16, i16, generic_array::typenum::U4, Integer::shl);
+impl_op!(shl_i16_5d, i16, i16, i16, generic_array::typenum::U5, Integer::shl);
+impl_op!(shl_i16_6d, i16, i16, i16, generic_array::typenum::U6, Integer::shl);
+impl_op!(shr_i16_1d, i16, i16, i16, generic_array::typenum::U1, Integer::shr);
+impl_op!(shr_i16_2d, i16, i16, i16, generic_array::typenum::U2, Integer::shr);
+impl_op!(shr_i16_3d, i16, i16, i16, generic_array::typenum::U3, Integer::shr);
+impl_op!(shr_i16_4d, i16, i16, i16, generic_array::typenum::U4, Integer::shr);
+impl_op!(shr_i16_5d, i16, i16, i16, generic_array::typenum::U5, Integer::shr);
+impl_op!(shr_i16_6d, i16, i16, i16, generic_array::typenum::U6, Integer::shr);
+impl_op!(eq_i16_1d, i16, i16, bool, generic_array::typenum::U1, |a, b| a == b);
+impl_op!(eq_i16_2d, i16, i16, bool, generic_array::typenum::U2, |a, b| a == b);
+impl_op!(eq_i16_3d, i16, i16, bool, generic_array::typenum::U3, |a, b| a == b);
+impl_op!(eq_i16_4d, i16, i16, bool, generic_array::typenum::U4, |a, b| a == b);
+impl_op!(eq_i16_5d, i16, i16, bool, generic_array::typenum::U5, |a, b| a == b);
+impl_op!(eq_i16_6d, i16, i16, bool, generic_array::typenum::U6, |a, b| a == b);
+impl_op!(ne_i16_1d, i16, i16, bool, generic_array::typenum::U1, |a, b| a != b);
+impl_op!(ne_i16_2d, i16, i16, bool, generic_array::typenum::U2, |a, b| a != b);
+impl_op!(ne_i16_3d, i16, i16, bool, generic_array::typenum::U3, |a, b| a != b);
+impl_op!(ne_i16_4d, i16, i16, bool, generic_array::typenum::U4, |a, b| a != b);
+impl_op!(ne_i16_5d, i16, i16, bool, generic_array::typenum::U5, |a, b| a != b);
+impl_op!(ne_i16_6d, i16, i16, bool, generic_array::typenum::U6, |a, b| a != b);
+impl_op!(lt_i16_1d, i16, i16, bool, generic_array::typenum::U1, |a, b| a < b);
+impl_op!(lt_i16_2d, i16, i16, bool, generic_array::typenum::U2, |a, b| a < b);
+impl_op!(lt_i16_3d, i16, i16, bool, generic_array::typenum::U3, |a, b| a < b);
+impl_op!(lt_i16_4d, i16, i16, bool, generic_array::typenum::U4, |a, b| a < b);
+impl_op!(lt_i16_5d, i16, i16, bool, generic_array::typenum::U5, |a, b| a < b);
+impl_op!(lt_i16_6d, i16, i16, bool, generic_array::typenum::U6, |a, b| a < b);
+impl_op!(le_i16_1d, i16, i16, bool, generic_array::typenum::U1, |a, b| a <= b);
+impl_op!(le_i16_2d, i16, i16, bool, generic_array::typenum::U2, |a, b| a <= b);
+impl_op!(le_i16_3d, i16, i16, bool, generic_array::typenum::U3, |a, b| a <= b);
+impl_op!(le_i16_4d, i16, i16, bool, generic_array::typenum::U4, |a, b| a <= b);
+impl_op!(le_i16_5d, i16, i16, bool, generic_array::typenum::U5, |a, b| a <= b);
+impl_op!(le_i16_6d, i16, i16, bool, generic_array::typenum::U6, |a, b| a <= b);
+impl_op!(gt_i16_1d, i16, i16, bool, generic_array::typenum::U1, |a, b| a > b);
+impl_op!(gt_i16_2d, i16, i16, bool, generic_array::typenum::U2, |a, b| a > b);
+impl_op!(gt_i16_3d, i16, i16, bool, generic_array::typenum::U3, |a, b| a > b);
+impl_op!(gt_i16_4d, i16, i16, bool, generic_array::typenum::U4, |a, b| a > b);
+impl_op!(gt_i16_5d, i16, i16, bool, generic_array::typenum::U5, |a, b| a > b);
+impl_op!(gt_i16_6d, i16, i16, bool, generic_array::typenum::U6, |a, b| a > b);
+impl_op!(ge_i16_1d, i16, i16, bool, generic_array::typenum::U1, |a, b| a >= b);
+impl_op!(ge_i16_2d, i16, i16, bool, generic_array::typenum::U2, |a, b| a >= b);
+impl_op!(ge_i16_3d, i16, i16, bool, generic_array::typenum::U3, |a, b| a >= b);
+impl_op!(ge_i16_4d, i16, i16, bool, generic_array::typenum::U4, |a, b| a >= b);
+impl_op!(ge_i16_5d, i16, i16, bool, generic_array::typenum::U5, |a, b| a >= b);
+impl_op!(ge_i16_6d, i16, i16, bool, generic_array::typenum::U6, |a, b| a >= b);
+
+impl_op!(add_i32_1d, i32, i32, i32, generic_array::typenum::U1, Arith::add);
+impl_op!(add_i32_2d, i32, i32, i32, generic_array::typenum::U2, Arith::add);
+impl_op!(add_i32_3d, i32, i32, i32, generic_array::typenum::U3, Arith::add);
+impl_op!(add_i32_4d, i32, i32, i32, generic_array::typenum::U4, Arith::add);
+impl_op!(add_i32_5d, i32, i32, i32, generic_array::typenum::U5, Arith::add);
+impl_op!(add_i32_6d, i32, i32, i32, generic_array::typenum::U6, Arith::add);
+impl_op!(sub_i32_1d, i32, i32, i32, generic_array::typenum::U1, Arith::sub);
+impl_op!(sub_i32_2d, i32, i32, i32, generic_array::typenum::U2, Arith::sub);
+impl_op!(sub_i32_3d, i32, i32, i32, generic_array::typenum::U3, Arith::sub);
+impl_op!(sub_i32_4d, i32, i32, i32, generic_array::typenum::U4, Arith::sub);
+impl_op!(sub_i32_5d, i32, i32, i32, generic_array::typenum::U5, Arith::sub);
+impl_op!(sub_i32_6d, i32, i32, i32, generic_array::typenum::U6, Arith::sub);
+impl_op!(mul_i32_1d, i32, i32, i32, generic_array::typenum::U1, Arith::mul);
+impl_op!(mul_i32_2d, i32, i32, i32, generic_array::typenum::U2, Arith::mul);
+impl_op!(mul_i32_3d, i32, i32, i32, generic_array::typenum::U3, Arith::mul);
+impl_op!(mul_i32_4d, i32, i32, i32, generic_array::typenum::U4, Arith::mul);
+impl_op!(mul_i32_5d, i32, i32, i32, generic_array::typenum::U5, Arith::mul);
+impl_op!(mul_i32_6d, i32, i32, i32, generic_array::typenum::U6, Arith::mul);
+impl_op!(div_i32_1d, i32, i32, i32, generic_array::typenum::U1, Arith::div);
+impl_op!(div_i32_2d, i32, i32, i32, generic_array::typenum::U2, Arith::div);
+impl_op!(div_i32_3d, i32, i32, i32, generic_array::typenum::U3, Arith::div);
+impl_op!(div_i32_4d, i32, i32, i32, generic_array::typenum::U4, Arith::div);
+impl_op!(div_i32_5d, i32, i32, i32, generic_array::typenum::U5, Arith::div);
+impl_op!(div_i32_6d, i32, i32, i32, generic_array::typenum::U6, Arith::div);
+impl_op!(rem_i32_1d, i32, i32, i32, generic_array::typenum::U1, Integer::rem);
+impl_op!(rem_i32_2d, i32, i32, i32, generic_array::typenum::U2, Integer::rem);
+impl_op!(rem_i32_3d, i32, i32, i32, generic_array::typenum::U3, Integer::rem);
+impl_op!(rem_i32_4d, i32, i32, i32, generic_array::typenum::U4, Integer::rem);
+impl_op!(rem_i32_5d, i32, i32, i32, generic_array::typenum::U5, Integer::rem);
+impl_op!(rem_i32_6d, i32, i32, i32, generic_array::typenum::U6, Integer::rem);
+impl_op!(and_i32_1d, i32, i32, i32, generic_array::typenum::U1, |a, b| a & b);
+impl_op!(and_i32_2d, i32, i32, i32, generic_array::typenum::U2, |a, b| a & b);
+impl_op!(and_i32_3d, i32, i32, i32, generic_array::typenum::U3, |a, b| a & b);
+impl_op!(and_i32_4d, i32, i32, i32, generic_array::typenum::U4, |a, b| a & b);
+impl_op!(and_i32_5d, i32, i32, i32, generic_array::typenum::U5, |a, b| a & b);
+impl_op!(and_i32_6d, i32, i32, i32, generic_array::typenum::U6, |a, b| a & b);
+impl_op!(or_i32_1d, i32, i32, i32, generic_array::typenum::U1, |a, b| a | b);
+impl_op!(or_i32_2d, i32, i32, i32, generic_array::typenum::U2, |a, b| a | b);
+impl_op!(or_i32_3d, i32, i32, i32, generic_array::typenum::U3, |a, b| a | b);
+impl_op!(or_i32_4d, i32, i32, i32, generic_array::typenum::U4, |a, b| a | b);
+impl_op!(or_i32_5d, i32, i32, i32, generic_array::typenum::U5, |a, b| a | b);
+impl_op!(or_i32_6d, i32, i32, i32, generic_array::typenum::U6, |a, b| a | b);
+impl_op!(xor_i32_1d, i32, i32, i32, generic_array::typenum::U1, |a, b| a ^ b);
+impl_op!(xor_i32_2d, i32, i32, i32, generic_array::typenum::U2, |a, b| a ^ b);
+impl_op!(xor_i32_3d, i32, i32, i32, generic_array::typenum::U3, |a, b| a ^ b);
+impl_op!(xor_i32_4d, i32, i32, i32, generic_array::typenum::U4, |a, b| a ^ b);
+impl_op!(xor_i32_5d, i32, i32, i32, generic_array::typenum::U5, |a, b| a ^ b);
+impl_op!(xor_i32_6d, i32, i32, i32, generic_array::typenum::U6, |a, b| a ^ b);
+impl_op!(shl_i32_1d, i32, i32, i32, generic_array::typenum::U1, Integer::shl);
+impl_op!(shl_i32_2d, i32, i32, i32, generic_array::typenum::U2, Integer::shl);
+impl_op!(shl_i32_3d, i32, i32, i32, generic_array::typenum::U3, Integer::shl);
+impl_op!(shl_i32_4d, i32, i32, i32, generic_array::typenum::U4, Integer::shl);
+impl_op!(shl_i32_5d, i32, i32, i32, generic_array::typenum::U5, Integer::shl);
+impl_op!(shl_i32_6d, i32, i32, i32, generic_array::typenum::U6, Integer::shl);
+impl_op!(shr_i32_1d, i32, i32, i32, generic_array::typenum::U1, Integer::shr);
+impl_op!(shr_i32_2d, i32, i32, i32, generic_array::typenum::U2, Integer::shr);
+impl_op!(shr_i32_3d, i32, i32, i32, generic_array::typenum::U3, Integer::shr);
+impl_op!(shr_i32_4d, i32, i32, i32, generic_array::typenum::U4, Integer::shr);
+impl_op!(shr_i32_5d, i32, i32, i32, generic_array::typenum::U5, Integer::shr);
+impl_op!(shr_i32_6d, i32, i32, i32, generic_array::typenum::U6, Integer::shr);
+impl_op!(eq_i32_1d, i32, i32, bool, generic_array::typenum::U1, |a, b| a == b);
+impl_op!(eq_i32_2d, i32, i32, bool, generic_array::typenum::U2, |a, b| a == b);
+impl_op!(eq_i32_3d, i32, i32, bool, generic_array::typenum::U3, |a, b| a == b);
+impl_op!(eq_i32_4d, i32, i32, bool, generic_array::typenum::U4, |a, b| a == b);
+impl_op!(eq_i32_5d, i32, i32, bool, generic_array::typenum::U5, |a, b| a == b);
+impl_op!(eq_i32_6d, i32, i32, bool, generic_array::typenum::U6, |a, b| a == b);
+impl_op!(ne_i32_1d, i32, i32, bool, generic_array::typenum::U1, |a, b| a != b);
+impl_op!(ne_i32_2d, i32, i32, bool, generic_array::typenum::U2, |a, b| a != b);
+impl_op!(ne_i32_3d, i32, i32, bool, generic_array::typenum::U3, |a, b| a != b);
+impl_op!(ne_i32_4d, i32, i32, bool, generic_array::typenum::U4, |a, b| a != b);
+impl_op!(ne_i32_5d, i32, i32, bool, generic_array::typenum::U5, |a, b| a != b);
+impl_op!(ne_i32_6d, i32, i32, bool, generic_array::typenum::U6, |a, b| a != b);
+impl_op!(lt_i32_1d, i32, i32, bool, generic_array::typenum::U1, |a, b| a < b);
+impl_op!(lt_i32_2d, i32, i32, bool, generic_array::typenum::U2, |a, b| a < b);
+impl_op!(lt_i32_3d, i32, i32, bool, generic_array::typenum::U3, |a, b| a < b);
+impl_op!(lt_i32_4d, i32, i32, bool, generic_array::typenum::U4, |a, b| a < b);
+impl_op!(lt_i32_5d, i32, i32, bool, generic_array::typenum::U5, |a, b| a < b);
+impl_op!(lt_i32_6d, i32, i32, bool, generic_array::typenum::U6, |a, b| a < b);
+impl_op!(le_i32_1d, i32, i32, bool, generic_array::typenum::U1, |a, b| a <= b);
+impl_op!(le_i32_2d, i32, i32, bool, generic_array::typenum::U2, |a, b| a <= b);
+impl_op!(le_i32_3d, i32, i32, bool, generic_array::typenum::U3, |a, b| a <= b);
+impl_op!(le_i32_4d, i32, i32, bool, generic_array::typenum::U4, |a, b| a <= b);
+impl_op!(le_i32_5d, i32, i32, bool, generic_array::typenum::U5, |a, b| a <= b);
+impl_op!(le_i32_6d, i32, i32, bool, generic_array::typenum::U6, |a, b| a <= b);
+impl_op!(gt_i32_1d, i32, i32, bool, generic_array::typenum::U1, |a, b| a > b);
+impl_op!(gt_i32_2d, i32, i32, bool, generic_array::typenum::U2, |a, b| a > b);
+impl_op!(gt_i32_3d, i32, i32, bool, generic_array::typenum::U3, |a, b| a > b);
+impl_op!(gt_i32_4d, i32, i32, bool, generic_array::typenum::U4, |a, b| a > b);
+impl_op!(gt_i32_5d, i32, i32, bool, generic_array::typenum::U5, |a, b| a > b);
+impl_op!(gt_i32_6d, i32, i32, bool, generic_array::typenum::U6, |a, b| a > b);
+impl_op!(ge_i32_1d, i32, i32, bool, generic_array::typenum::U1, |a, b| a >= b);
+impl_op!(ge_i32_2d, i32, i32, bool, generic_array::typenum::U2, |a, b| a >= b);
+impl_op!(ge_i32_3d, i32, i32, bool, generic_array::typenum::U3, |a, b| a >= b);
+impl_op!(ge_i32_4d, i32, i32, bool, generic_array::typenum::U4, |a, b| a >= b);
+impl_op!(ge_i32_5d, i32, i32, bool, generic_array::typenum::U5, |a, b| a >= b);
+impl_op!(ge_i32_6d, i32, i32, bool, generic_array::typenum::U6, |a, b| a >= b);
+
+impl_op!(add_i64_1d, i64, i64, i64, generic_array::typenum::U1, Arith::add);
+impl_op!(add_i64_2d, i64, i64, i64, generic_array::typenum::U2, Arith::add);
+impl_op!(add_i64_3d, i64, i64, i64, generic_array::typenum::U3, Arith::add);
+impl_op!(add_i64_4d, i64, i64, i64, generic_array::typenum::U4, Arith::add);
+impl_op!(add_i64_5d, i64, i64, i64, generic_array::typenum::U5, Arith::add);
+impl_op!(add_i64_6d, i64, i64, i64, generic_array::typenum::U6, Arith::add);
+impl_op!(sub_i64_1d, i64, i64, i64, generic_array::typenum::U1, Arith::sub);
+impl_op!(sub_i64_2d, i64, i64, i64, generic_array::typenum::U2, Arith::sub);
+impl_op!(sub_i64_3d, i64, i64, i64, generic_array::typenum::U3, Arith::sub);
+impl_op!(sub_i64_4d, i64, i64, i64, generic_array::typenum::U4, Arith::sub);
+impl_op!(sub_i64_5d, i64, i64, i64, generic_array::typenum::U5, Arith::sub);
+impl_op!(sub_i64_6d, i64, i64, i64, generic_array::typenum::U6, Arith::sub);
+impl_op!(mul_i64_1d, i64, i64, i64, generic_array::typenum::U1, Arith::mul);
+impl_op!(mul_i64_2d, i64, i64, i64, generic_array::typenum::U2, Arith::mul);
+impl_op!(mul_i64_3d, i64, i64, i64, generic_array::typenum::U3, Arith::mul);
+impl_op!(mul_i64_4d, i64, i64, i64, generic_array::typenum::U4, Arith::mul);
+impl_op!(mul_i64_5d, i64, i64, i64, generic_array::typenum::U5, Arith::mul);
+impl_op!(mul_i64_6d, i64, i64, i64, generic_array::typenum::U6, Arith::mul);
+impl_op!(div_i64_1d, i64, i64, i64, generic_array::typenum::U1, Arith::div);
+impl_op!(div_i64_2d, i64, i64, i64, generic_array::typenum::U2, Arith::div);
+impl_op!(div_i64_3d, i64, i64, i64, generic_array::typenum::U3, Arith::div);
+impl_op!(div_i64_4d, i64, i64, i64, generic_array::typenum::U4, Arith::div);
+impl_op!(div_i64_5d, i64, i64, i64, generic_array::typenum::U5, Arith::div);
+impl_op!(div_i64_6d, i64, i64, i64, generic_array::typenum::U6, Arith::div);
+impl_op!(rem_i64_1d, i64, i64, i64, generic_array::typenum::U1, Integer::rem);
+impl_op!(rem_i64_2d, i64, i64, i64, generic_array::typenum::U2, Integer::rem);
+impl_op!(rem_i64_3d, i64, i64, i64, generic_array::typenum::U3, Integer::rem);
+impl_op!(rem_i64_4d, i64, i64, i64, generic_array::typenum::U4, Integer::rem);
+impl_op!(rem_i64_5d, i64, i64, i64, generic_array::typenum::U5, Integer::rem);
+impl_op!(rem_i64_6d, i64, i64, i64, generic_array::typenum::U6, Integer::rem);
+impl_op!(and_i64_1d, i64, i64, i64, generic_array::typenum::U1, |a, b| a & b);
+impl_op!(and_i64_2d, i64, i64, i64, generic_array::typenum::U2, |a, b| a & b);
+impl_op!(and_i64_3d, i64, i64, i64, generic_array::typenum::U3, |a, b| a & b);
+impl_op!(and_i64_4d, i64, i64, i64, generic_array::typenum::U4, |a, b| a & b);
+impl_op!(and_i64_5d, i64, i64, i64, generic_array::typenum::U5, |a, b| a & b);
+impl_op!(and_i64_6d, i64, i64, i64, generic_array::typenum::U6, |a, b| a & b);
+impl_op!(or_i64_1d, i64, i64, i64, generic_array::typenum::U1, |a, b| a | b);
+impl_op!(or_i64_2d, i64, i64, i64, generic_array::typenum::U2, |a, b| a | b);
+impl_op!(or_i64_3d, i64, i64, i64, generic_array::typenum::U3, |a, b| a | b);
+impl_op!(or_i64_4d, i64, i64, i64, generic_array::typenum::U4, |a, b| a | b);
+impl_op!(or_i64_5d, i64, i64, i64, generic_array::typenum::U5, |a, b| a | b);
+impl_op!(or_i64_6d, i64, i64, i64, generic_array::typenum::U6, |a, b| a | b);
+impl_op!(xor_i64_1d, i64, i64, i64, generic_array::typenum::U1, |a, b| a ^ b);
+impl_op!(xor_i64_2d, i64, i64, i64, generic_array::typenum::U2, |a, b| a ^ b);
+impl_op!(xor_i64_3d, i64, i64, i64, generic_array::typenum::U3, |a, b| a ^ b);
+impl_op!(xor_i64_4d, i64, i64, i64, generic_array::typenum::U4, |a, b| a ^ b);
+impl_op!(xor_i64_5d, i64, i64, i64, generic_array::typenum::U5, |a, b| a ^ b);
+impl_op!(xor_i64_6d, i64, i64, i64, generic_array::typenum::U6, |a, b| a ^ b);
+impl_op!(shl_i64_1d, i64, i64, i64, generic_array::typenum::U1, Integer::shl);
+impl_op!(shl_i64_2d, i64, i64, i64, generic_array::typenum::U2, Integer::shl);
+impl_op!(shl_i64_3d, i64, i64, i64, generic_array::typenum::U3, Integer::shl);
+impl_op!(shl_i64_4d, i64, i64, i64, generic_array::typenum::U4, Integer::shl);
+impl_op!(shl_i64_5d, i64, i64, i64, generic_array::typenum::U5, Integer::shl);
+impl_op!(shl_i64_6d, i64, i64, i64, generic_array::typenum::U6, Integer::shl);
+impl_op!(shr_i64_1d, i64, i64, i64, generic_array::typenum::U1, Integer::shr);
+impl_op!(shr_i64_2d, i64, i64, i64, generic_array::typenum::U2, Integer::shr);
+impl_op!(shr_i64_3d, i64, i64, i64, generic_array::typenum::U3, Integer::shr);
+impl_op!(shr_i64_4d, i64, i64, i64, generic_array::typenum::U4, Integer::shr);
+impl_op!(shr_i64_5d, i64, i64, i64, generic_array::typenum::U5, Integer::shr);
+impl_op!(shr_i64_6d, i64, i64, i64, generic_array::typenum::U6, Integer::shr);
+impl_op!(eq_i64_1d, i64, i64, bool, generic_array::typenum::U1, |a, b| a == b);
+impl_op!(eq_i64_2d, i64, i64, bool, generic_array::typenum::U2, |a, b| a == b);
+impl_op!(eq_i64_3d, i64, i64, bool, generic_array::typenum::U3, |a, b| a == b);
+impl_op!(eq_i64_4d, i64, i64, bool, generic_array::typenum::U4, |a, b| a == b);
+impl_op!(eq_i64_5d, i64, i64, bool, generic_array::typenum::U5, |a, b| a == b);
+impl_op!(eq_i64_6d, i64, i64, bool, generic_array::typenum::U6, |a, b| a == b);
+impl_op!(ne_i64_1d, i64, i64, bool, generic_array::typenum::U1, |a, b| a != b);
+impl_op!(ne_i64_2d, i64, i64, bool, generic_array::typenum::U2, |a, b| a != b);
+impl_op!(ne_i64_3d, i64, i64, bool, generic_array::typenum::U3, |a, b| a != b);
+impl_op!(ne_i64_4d, i64, i64, bool, generic_array::typenum::U4, |a, b| a != b);
+impl_op!(ne_i64_5d, i64, i64, bool, generic_array::typenum::U5, |a, b| a != b);
+impl_op!(ne_i64_6d, i64, i64, bool, generic_array::typenum::U6, |a, b| a != b);
+impl_op!(lt_i64_1d, i64, i64, bool, generic_array::typenum::U1, |a, b| a < b);
+impl_op!(lt_i64_2d, i64, i64, bool, generic_array::typenum::U2, |a, b| a < b);
+impl_op!(lt_i64_3d, i64, i64, bool, generic_array::typenum::U3, |a, b| a < b);
+impl_op!(lt_i64_4d, i64, i64, bool, generic_array::typenum::U4, |a, b| a < b);
+impl_op!(lt_i64_5d, i64, i64, bool, generic_array::typenum::U5, |a, b| a < b);
+impl_op!(lt_i64_6d, i64, i64, bool, generic_array::typenum::U6, |a, b| a < b);
+impl_op!(le_i64_1d, i64, i64, bool, generic_array::typenum::U1, |a, b| a <= b);
+impl_op!(le_i64_2d, i64, i64, bool, generic_array::typenum::U2, |a, b| a <= b);
+impl_op!(le_i64_3d, i64, i64, bool, generic_array::typenum::U3, |a, b| a <= b);
+impl_op!(le_i64_4d, i64, i64, bool, generic_array::typenum::U4, |a, b| a <= b);
+impl_op!(le_i64_5d, i64, i64, bool, generic_array::typenum::U5, |a, b| a <= b);
+impl_op!(le_i64_6d, i64, i64, bool, generic_array::typenum::U6, |a, b| a <= b);
+impl_op!(gt_i64_1d, i64, i64, bool, generic_array::typenum::U1, |a, b| a > b);
+impl_op!(gt_i64_2d, i64, i64, bool, generic_array::typenum::U2, |a, b| a > b);
+impl_op!(gt_i64_3d, i64, i64, bool, generic_array::typenum::U3, |a, b| a > b);
+impl_op!(gt_i64_4d, i64, i64, bool, generic_array::typenum::U4, |a, b| a > b);
+impl_op!(gt_i64_5d, i64, i64, bool, generic_array::typenum::U5, |a, b| a > b);
+impl_op!(gt_i64_6d, i64, i64, bool, generic_array::typenum::U6, |a, b| a > b);
+impl_op!(ge_i64_1d, i64, i64, bool, generic_array::typenum::U1, |a, b| a >= b);
+impl_op!(ge_i64_2d, i64, i64, bool, generic_array::typenum::U2, |a, b| a >= b);
+impl_op!(ge_i64_3d, i64, i64, bool, generic_array::typenum::U3, |a, b| a >= b);
+impl_op!(ge_i64_4d, i64, i64, bool, generic_array::typenum::U4, |a, b| a >= b);
+impl_op!(ge_i64_5d, i64, i64, bool, generic_array::typenum::U5, |a, b| a >= b);
+impl_op!(ge_i64_6d, i64, i64, bool, generic_array::typenum::U6, |a, b| a >= b);
+
+impl_op!(add_u8_1d, u8, u8, u8, generic_array::typenum::U1, Arith::add);
+impl_op!(add_u8_2d, u8, u8, u8, generic_array::typenum::U2, Arith::add);
+impl_op!(add_u8_3d, u8, u8, u8, generic_array::typenum::U3, Arith::add);
+impl_op!(add_u8_4d, u8, u8, u8, generic_array::typenum::U4, Arith::add);
+impl_op!(add_u8_5d, u8, u8, u8, generic_array::typenum::U5, Arith::add);
+impl_op!(add_u8_6d, u8, u8, u8, generic_array::typenum::U6, Arith::add);
+impl_op!(sub_u8_1d, u8, u8, u8, generic_array::typenum::U1, Arith::sub);
+impl_op!(sub_u8_2d, u8, u8, u8, generic_array::typenum::U2, Arith::sub);
+impl_op!(sub_u8_3d, u8, u8, u8, generic_array::typenum::U3, Arith::sub);
+impl_op!(sub_u8_4d, u8, u8, u8, generic_array::typenum::U4, Arith::sub);
+impl_op!(sub_u8_5d, u8, u8, u8, generic_array::typenum::U5, Arith::sub);
+impl_op!(sub_u8_6d, u8, u8, u8, generic_array::typenum::U6, Arith::sub);
+impl_op!(mul_u8_1d, u8, u8, u8, generic_array::typenum::U1, Arith::mul);
+impl_op!(mul_u8_2d, u8, u8, u8, generic_array::typenum::U2, Arith::mul);
+impl_op!(mul_u8_3d, u8, u8, u8, generic_array::typenum::U3, Arith::mul);
+impl_op!(mul_u8_4d, u8, u8, u8, generic_array::typenum::U4, Arith::mul);
+impl_op!(mul_u8_5d, u8, u8, u8, generic_array::typenum::U5, Arith::mul);
+impl_op!(mul_u8_6d, u8, u8, u8, generic_array::typenum::U6, Arith::mul);
+impl_op!(div_u8_1d, u8, u8, u8, generic_array::typenum::U1, Arith::div);
+impl_op!(div_u8_2d, u8, u8, u8, generic_array::typenum::U2, Arith::div);
+impl_op!(div_u8_3d, u8, u8, u8, generic_array::typenum::U3, Arith::div);
+impl_op!(div_u8_4d, u8, u8, u8, generic_array::typenum::U4, Arith::div);
+impl_op!(div_u8_5d, u8, u8, u8, generic_array::typenum::U5, Arith::div);
+impl_op!(div_u8_6d, u8, u8, u8, generic_array::typenum::U6, Arith::div);
+impl_op!(rem_u8_1d, u8, u8, u8, generic_array::typenum::U1, Integer::rem);
+impl_op!(rem_u8_2d, u8, u8, u8, generic_array::typenum::U2, Integer::rem);
+impl_op!(rem_u8_3d, u8, u8, u8, generic_array::typenum::U3, Integer::rem);
+impl_op!(rem_u8_4d, u8, u8, u8, generic_array::typenum::U4, Integer::rem);
+impl_op!(rem_u8_5d, u8, u8, u8, generic_array::typenum::U5, Integer::rem);
+impl_op!(rem_u8_6d, u8, u8, u8, generic_array::typenum::U6, Integer::rem);
+impl_op!(and_u8_1d, u8, u8, u8, generic_array::typenum::U1, |a, b| a & b);
+impl_op!(and_u8_2d, u8, u8, u8, generic_array::typenum::U2, |a, b| a & b);
+impl_op!(and_u8_3d, u8, u8, u8, generic_array::typenum::U3, |a, b| a & b);
+impl_op!(and_u8_4d, u8, u8, u8, generic_array::typenum::U4, |a, b| a & b);
+impl_op!(and_u8_5d, u8, u8, u8, generic_array::typenum::U5, |a, b| a & b);
+impl_op!(and_u8_6d, u8, u8, u8, generic_array::typenum::U6, |a, b| a & b);
+impl_op!(or_u8_1d, u8, u8, u8, generic_array::typenum::U1, |a, b| a | b);
+impl_op!(or_u8_2d, u8, u8, u8, generic_array::typenum::U2, |a, b| a | b);
+impl_op!(or_u8_3d, u8, u8, u8, generic_array::typenum::U3, |a, b| a | b);
+impl_op!(or_u8_4d, u8, u8, u8, generic_array::typenum::U4, |a, b| a | b);
+impl_op!(or_u8_5d, u8, u8, u8, generic_array::typenum::U5, |a, b| a | b);
+impl_op!(or_u8_6d, u8, u8, u8, generic_array::typenum::U6, |a, b| a | b);
+impl_op!(xor_u8_1d, u8, u8, u8, generic_array::typenum::U1, |a, b| a ^ b);
+impl_op!(xor_u8_2d, u8, u8, u8, generic_array::typenum::U2, |a, b| a ^ b);
+impl_op!(xor_u8_3d, u8, u8, u8, generic_array::typenum::U3, |a, b| a ^ b);
+impl_op!(xor_u8_4d, u8, u8, u8, generic_array::typenum::U4, |a, b| a ^ b);
+impl_op!(xor_u8_5d, u8, u8, u8, generic_array::typenum::U5, |a, b| a ^ b);
+impl_op!(xor_u8_6d, u8, u8, u8, generic_array::typenum::U6, |a, b| a ^ b);
+impl_op!(shl_u8_1d, u8, u8, u8, generic_array::typenum::U1, Integer::shl);
+impl_op!(shl_u8_2d, u8, u8, u8, generic_array::typenum::U2, Integer::shl);
+impl_op!(shl_u8_3d, u8, u8, u8, generic_array::typenum::U3, Integer::shl);
+impl_op!(shl_u8_4d, u8, u8, u8, generic_array::typenum::U4, Integer::shl);
+impl_op!(shl_u8_5d, u8, u8, u8, generic_array::typenum::U5, Integer::shl);
+impl_op!(shl_u8_6d, u8, u8, u8, generic_array::typenum::U6, Integer::shl);
+impl_op!(shr_u8_1d, u8, u8, u8, generic_array::typenum::U1, Integer::shr);
+impl_op!(shr_u8_2d, u8, u8, u8, generic_array::typenum::U2, Integer::shr);
+impl_op!(shr_u8_3d, u8, u8, u8, generic_array::typenum::U3, Integer::shr);
+impl_op!(shr_u8_4d, u8, u8, u8, generic_array::typenum::U4, Integer::shr);
+impl_op!(shr_u8_5d, u8, u8, u8, generic_array::typenum::U5, Integer::shr);
+impl_op!(shr_u8_6d, u8, u8, u8, generic_array::typenum::U6, Integer::shr);
+impl_op!(eq_u8_1d, u8, u8, bool, generic_array::typenum::U1, |a, b| a == b);
+impl_op!(eq_u8_2d, u8, u8, bool, generic_array::typenum::U2, |a, b| a == b);
+impl_op!(eq_u8_3d, u8, u8, bool, generic_array::typenum::U3, |a, b| a == b);
+impl_op!(eq_u8_4d, u8, u8, bool, generic_array::typenum::U4, |a, b| a == b);
+impl_op!(eq_u8_5d, u8, u8, bool, generic_array::typenum::U5, |a, b| a == b);
+impl_op!(eq_u8_6d, u8, u8, bool, generic_array::typenum::U6, |a, b| a == b);
+impl_op!(ne_u8_1d, u8, u8, bool, generic_array::typenum::U1, |a, b| a != b);
+impl_op!(ne_u8_2d, u8, u8, bool, generic_array::typenum::U2, |a, b| a != b);
+impl_op!(ne_u8_3d, u8, u8, bool, generic_array::typenum::U3, |a, b| a != b);
+impl_op!(ne_u8_4d, u8, u8, bool, generic_array::typenum::U4, |a, b| a != b);
+impl_op!(ne_u8_5d, u8, u8, bool, generic_array::typenum::U5, |a, b| a != b);
+impl_op!(ne_u8_6d, u8, u8, bool, generic_array::typenum::U6, |a, b| a != b);
+impl_op!(lt_u8_1d, u8, u8, bool, generic_array::typenum::U1, |a, b| a < b);
+impl_op!(lt_u8_2d, u8, u8, bool, generic_array::typenum::U2, |a, b| a < b);
+impl_op!(lt_u8_3d, u8, u8, bool, generic_array::typenum::U3, |a, b| a < b);
+impl_op!(lt_u8_4d, u8, u8, bool, generic_array::typenum::U4, |a, b| a < b);
+impl_op!(lt_u8_5d, u8, u8, bool, generic_array::typenum::U5, |a, b| a < b);
+impl_op!(lt_u8_6d, u8, u8, bool, generic_array::typenum::U6, |a, b| a < b);
+impl_op!(le_u8_1d, u8, u8, bool, generic_array::typenum::U1, |a, b| a <= b);
+impl_op!(le_u8_2d, u8, u8, bool, generic_array::typenum::U2, |a, b| a <= b);
+impl_op!(le_u8_3d, u8, u8, bool, generic_array::typenum::U3, |a, b| a <= b);
+impl_op!(le_u8_4d, u8, u8, bool, generic_array::typenum::U4, |a, b| a <= b);
+impl_op!(le_u8_5d, u8, u8, bool, generic_array::typenum::U5, |a, b| a <= b);
+impl_op!(le_u8_6d, u8, u8, bool, generic_array::typenum::U6, |a, b| a <= b);
+impl_op!(gt_u8_1d, u8, u8, bool, generic_array::typenum::U1, |a, b| a > b);
+impl_op!(gt_u8_2d, u8, u8, bool, generic_array::typenum::U2, |a, b| a > b);
+impl_op!(gt_u8_3d, u8, u8, bool, generic_array::typenum::U3, |a, b| a > b);
+impl_op!(gt_u8_4d, u8, u8, bool, generic_array::typenum::U4, |a, b| a > b);
+impl_op!(gt_u8_5d, u8, u8, bool, generic_array::typenum::U5, |a, b| a > b);
+impl_op!(gt_u8_6d, u8, u8, bool, generic_array::typenum::U6, |a, b| a > b);
+impl_op!(ge_u8_1d, u8, u8, bool, generic_array::typenum::U1, |a, b| a >= b);
+impl_op!(ge_u8_2d, u8, u8, bool, generic_array::typenum::U2, |a, b| a >= b);
+impl_op!(ge_u8_3d, u8, u8, bool, generic_array::typenum::U3, |a, b| a >= b);
+impl_op!(ge_u8_4d, u8, u8, bool, generic_array::typenum::U4, |a, b| a >= b);
+impl_op!(ge_u8_5d, u8, u8, bool, generic_array::typenum::U5, |a, b| a >= b);
+impl_op!(ge_u8_6d, u8, u8, bool, generic_array::typenum::U6, |a, b| a >= b);
+
+impl_op!(add_u32_1d, u32, u32, u32, generic_array::typenum::U1, Arith::add);
+impl_op!(add_u32_2d, u32, u32, u32, generic_array::typenum::U2, Arith::add);
+impl_op!(add_u32_3d, u32, u32, u32, generic_array::typenum::U3, Arith::add);
+impl_op!(add_u32_4d, u32, u32, u32, generic_array::typenum::U4, Arith::add);
+impl_op!(add_u32_5d, u32, u32, u32, generic_array::typenum::U5, Arith::add);
+impl_op!(add_u32_6d, u32, u32, u32, generic_array::typenum::U6, Arith::add);
+impl_op!(sub_u32_1d, u32, u32, u32, generic_array::typenum::U1, Arith::sub);
+impl_op!(sub_u32_2d, u32, u32, u32, generic_array::typenum::U2, Arith::sub);
+impl_op!(sub_u32_3d, u32, u32, u32, generic_array::typenum::U3, Arith::sub);
+impl_op!(sub_u32_4d, u32, u32, u32, generic_array::typenum::U4, Arith::sub);
+impl_op!(sub_u32_5d, u32, u32, u32, generic_array::typenum::U5, Arith::sub);
+impl_op!(sub_u32_6d, u32, u32, u32, generic_array::typenum::U6, Arith::sub);
+impl_op!(mul_u32_1d, u32, u32, u32, generic_array::typenum::U1, Arith::mul);
+impl_op!(mul_u32_2d, u32, u32, u32, generic_array::typenum::U2, Arith::mul);
+impl_op!(mul_u32_3d, u32, u32, u32, generic_array::typenum::U3, Arith::mul);
+impl_op!(mul_u32_4d, u32, u32, u32, generic_array::typenum::U4, Arith::mul);
+impl_op!(mul_u32_5d, u32, u32, u32, generic_array::typenum::U5, Arith::mul);
+impl_op!(mul_u32_6d, u32, u32, u32, generic_array::typenum::U6, Arith::mul);
+impl_op!(div_u32_1d, u32, u32, u32, generic_array::typenum::U1, Arith::div);
+impl_op!(div_u32_2d, u32, u32, u32, generic_array::typenum::U2, Arith::div);
+impl_op!(div_u32_3d, u32, u32, u32, generic_array::typenum::U3, Arith::div);
+impl_op!(div_u32_4d, u32, u32, u32, generic_array::typenum::U4, Arith::div);
+impl_op!(div_u32_5d, u32, u32, u32, generic_array::typenum::U5, Arith::div);
+impl_op!(div_u32_6d, u32, u32, u32, generic_array::typenum::U6, Arith::div);
+impl_op!(rem_u32_1d, u32, u32, u32, generic_array::typenum::U1, Integer::rem);
+impl_op!(rem_u32_2d, u32, u32, u32, generic_array::typenum::U2, Integer::rem);
+impl_op!(rem_u32_3d, u32, u32, u32, generic_array::typenum::U3, Integer::rem);
+impl_op!(rem_u32_4d, u32, u32, u32, generic_array::typenum::U4, Integer::rem);
+impl_op!(rem_u32_5d, u32, u32, u32, generic_array::typenum::U5, Integer::rem);
+impl_op!(rem_u32_6d, u32, u32, u32, generic_array::typenum::U6, Integer::rem);
+impl_op!(and_u32_1d, u32, u32, u32, generic_array::typenum::U1, |a, b| a & b);
+impl_op!(and_u32_2d, u32, u32, u32, generic_array::typenum::U2, |a, b| a & b);
+impl_op!(and_u32_3d, u32, u32, u32, generic_array::typenum::U3, |a, b| a & b);
+impl_op!(and_u32_4d, u32, u32, u32, generic_array::typenum::U4, |a, b| a & b);
+impl_op!(and_u32_5d, u32, u32, u32, generic_array::typenum::U5, |a, b| a & b);
+impl_op!(and_u32_6d, u32, u32, u32, generic_array::typenum::U6, |a, b| a & b);
+impl_op!(or_u32_1d, u32, u32, u32, generic_array::typenum::U1, |a, b| a | b);
+impl_op!(or_u32_2d, u32, u32, u32, generic_array::typenum::U2, |a, b| a | b);
+impl_op!(or_u32_3d, u32, u32, u32, generic_array::typenum::U3, |a, b| a | b);
+impl_op!(or_u32_4d, u32, u32, u32, generic_array::typenum::U4, |a, b| a | b);
+impl_op!(or_u32_5d, u32, u32, u32, generic_array::typenum::U5, |a, b| a | b);
+impl_op!(or_u32_6d, u32, u32, u32, generic_array::typenum::U6, |a, b| a | b);
+impl_op!(xor_u32_1d, u32, u32, u32, generic_array::typenum::U1, |a, b| a ^ b);
+impl_op!(xor_u32_2d, u32, u32, u32, generic_array::typenum::U2, |a, b| a ^ b);
+impl_op!(xor_u32_3d, u32, u32, u32, generic_array::typenum::U3, |a, b| a ^ b);
+impl_op!(xor_u32_4d, u32, u32, u32, generic_array::typenum::U4, |a, b| a ^ b);
+impl_op!(xor_u32_5d, u32, u32, u32, generic_array::typenum::U5, |a, b| a ^ b);
+impl_op!(xor_u32_6d, u32, u32, u32, generic_array::typenum::U6, |a, b| a ^ b);
+impl_op!(shl_u32_1d, u32, u32, u32, generic_array::typenum::U1, Integer::shl);
+impl_op!(shl_u32_2d, u32, u32, u32, generic_array::typenum::U2, Integer::shl);
+impl_op!(shl_u32_3d, u32, u32, u32, generic_array::typenum::U3, Integer::shl);
+impl_op!(shl_u32_4d, u32, u32, u32, generic_array::typenum::U4, Integer::shl);
+impl_op!(shl_u32_5d, u32, u32, u32, generic_array::typenum::U5, Integer::shl);
+impl_op!(shl_u32_6d, u32, u32, u32, generic_array::typenum::U6, Integer::shl);
+impl_op!(shr_u32_1d, u32, u32, u32, generic_array::typenum::U1, Integer::shr);
+impl_op!(shr_u32_2d, u32, u32, u32, generic_array::typenum::U2, Integer::shr);
+impl_op!(shr_u32_3d, u32, u32, u32, generic_array::typenum::U3, Integer::shr);
+impl_op!(shr_u32_4d, u32, u32, u32, generic_array::typenum::U4, Integer::shr);
+impl_op!(shr_u32_5d, u32, u32, u32, generic_array::typenum::U5, Integer::shr);
+impl_op!(shr_u32_6d, u32, u32, u32, generic_array::typenum::U6, Integer::shr);
+impl_op!(eq_u32_1d, u32, u32, bool, generic_array::typenum::U1, |a, b| a == b);
+impl_op!(eq_u32_2d, u32, u32, bool, generic_array::typenum::U2, |a, b| a == b);
+impl_op!(eq_u32_3d, u32, u32, bool, generic_array::typenum::U3, |a, b| a == b);
+impl_op!(eq_u32_4d, u32, u32, bool, generic_array::typenum::U4, |a, b| a == b);
+impl_op!(eq_u32_5d, u32, u32, bool, generic_array::typenum::U5, |a, b| a == b);
+impl_op!(eq_u32_6d, u32, u32, bool, generic_array::typenum::U6, |a, b| a == b);
+impl_op!(ne_u32_1d, u32, u32, bool, generic_array::typenum::U1, |a, b| a != b);
+impl_op!(ne_u32_2d, u32, u32, bool, generic_array::typenum::U2, |a, b| a != b);
+impl_op!(ne_u32_3d, u32, u32, bool, generic_array::typenum::U3, |a, b| a != b);
+impl_op!(ne_u32_4d, u32, u32, bool, generic_array::typenum::U4, |a, b| a != b);
+impl_op!(ne_u32_5d, u32, u32, bool, generic_array::typenum::U5, |a, b| a != b);
+impl_op!(ne_u32_6d, u32, u32, bool, generic_array::typenum::U6, |a, b| a != b);
+impl_op!(lt_u32_1d, u32, u32, bool, generic_array::typenum::U1, |a, b| a < b);
+impl_op!(lt_u32_2d, u32, u32, bool, generic_array::typenum::U2, |a, b| a < b);
+impl_op!(lt_u32_3d, u32, u32, bool, generic_array::typenum::U3, |a, b| a < b);
+impl_op!(lt_u32_4d, u32, u32, bool, generic_array::typenum::U4, |a, b| a < b);
+impl_op!(lt_u32_5d, u32, u32, bool, generic_array::typenum::U5, |a, b| a < b);
+impl_op!(lt_u32_6d, u32, u32, bool, generic_array::typenum::U6, |a, b| a < b);
+impl_op!(le_u32_1d, u32, u32, bool, generic_array::typenum::U1, |a, b| a <= b);
+impl_op!(le_u32_2d, u32, u32, bool, generic_array::typenum::U2, |a, b| a <= b);
+impl_op!(le_u32_3d, u32, u32, bool, generic_array::typenum::U3, |a, b| a <= b);
+impl_op!(le_u32_4d, u32, u32, bool, generic_array::typenum::U4, |a, b| a <= b);
+impl_op!(le_u32_5d, u32, u32, bool, generic_array::typenum::U5, |a, b| a <= b);
+impl_op!(le_u32_6d, u32, u32, bool, generic_array::typenum::U6, |a, b| a <= b);
+impl_op!(gt_u32_1d, u32, u32, bool, generic_array::typenum::U1, |a, b| a > b);
+impl_op!(gt_u32_2d, u32, u32, bool, generic_array::typenum::U2, |a, b| a > b);
+impl_op!(gt_u32_3d, u32, u32, bool, generic_array::typenum::U3, |a, b| a > b);
+impl_op!(gt_u32_4d, u32, u32, bool, generic_array::typenum::U4, |a, b| a > b);
+impl_op!(gt_u32_5d, u32, u32, bool, generic_array::typenum::U5, |a, b| a > b);
+impl_op!(gt_u32_6d, u32, u32, bool, generic_array::typenum::U6, |a, b| a > b);
+impl_op!(ge_u32_1d, u32, u32, bool, generic_array::typenum::U1, |a, b| a >= b);
+impl_op!(ge_u32_2d, u32, u32, bool, generic_array::typenum::U2, |a, b| a >= b);
+impl_op!(ge_u32_3d, u32, u32, bool, generic_array::typenum::U3, |a, b| a >= b);
+impl_op!(ge_u32_4d, u32, u32, bool, generic_array::typenum::U4, |a, b| a >= b);
+impl_op!(ge_u32_5d, u32, u32, bool, generic_array::typenum::U5, |a, b| a >= b);
+impl_op!(ge_u32_6d, u32, u32, bool, generic_array::typenum::U6, |a, b| a >= b);
+
+impl_op!(add_u64_1d, u64, u64, u64, generic_array::typenum::U1, Arith::add);
+impl_op!(add_u64_2d, u64, u64, u64, generic_array::typenum::U2, Arith::add);
+impl_op!(add_u64_3d, u64, u64, u64, generic_array::typenum::U3, Arith::add);
+impl_op!(add_u64_4d, u64, u64, u64, generic_array::typenum::U4, Arith::add);
+impl_op!(add_u64_5d, u64, u64, u64, generic_array::typenum::U5, Arith::add);
+impl_op!(add_u64_6d, u64, u64, u64, generic_array::typenum::U6, Arith::add);
+impl_op!(sub_u64_1d, u64, u64, u64, generic_array::typenum::U1, Arith::sub);
+impl_op!(sub_u64_2d, u64, u64, u64, generic_array::typenum::U2, Arith::sub);
+impl_op!(sub_u64_3d, u64, u64, u64, generic_array::typenum::U3, Arith::sub);
+impl_op!(sub_u64_4d, u64, u64, u64, generic_array::typenum::U4, Arith::sub);
+impl_op!(sub_u64_5d, u64, u64, u64, generic_array::typenum::U5, Arith::sub);
+impl_op!(sub_u64_6d, u64, u64, u64, generic_array::typenum::U6, Arith::sub);
+impl_op!(mul_u64_1d, u64, u64, u64, generic_array::typenum::U1, Arith::mul);
+impl_op!(mul_u64_2d, u64, u64, u64, generic_array::typenum::U2, Arith::mul);
+impl_op!(mul_u64_3d, u64, u64, u64, generic_array::typenum::U3, Arith::mul);
+impl_op!(mul_u64_4d, u64, u64, u64, generic_array::typenum::U4, Arith::mul);
+impl_op!(mul_u64_5d, u64, u64, u64, generic_array::typenum::U5, Arith::mul);
+impl_op!(mul_u64_6d, u64, u64, u64, generic_array::typenum::U6, Arith::mul);
+impl_op!(div_u64_1d, u64, u64, u64, generic_array::typenum::U1, Arith::div);
+impl_op!(div_u64_2d, u64, u64, u64, generic_array::typenum::U2, Arith::div);
+impl_op!(div_u64_3d, u64, u64, u64, generic_array::typenum::U3, Arith::div);
+impl_op!(div_u64_4d, u64, u64, u64, generic_array::typenum::U4, Arith::div);
+impl_op!(div_u64_5d, u64, u64, u64, generic_array::typenum::U5, Arith::div);
+impl_op!(div_u64_6d, u64, u64, u64, generic_array::typenum::U6, Arith::div);
+impl_op!(rem_u64_1d, u64, u64, u64, generic_array::typenum::U1, Integer::rem);
+impl_op!(rem_u64_2d, u64, u64, u64, generic_array::typenum::U2, Integer::rem);
+impl_op!(rem_u64_3d, u64, u64, u64, generic_array::typenum::U3, Integer::rem);
+impl_op!(rem_u64_4d, u64, u64, u64, generic_array::typenum::U4, Integer::rem);
+impl_op!(rem_u64_5d, u64, u64, u64, generic_array::typenum::U5, Integer::rem);
+impl_op!(rem_u64_6d, u64, u64, u64, generic_array::typenum::U6, Integer::rem);
+impl_op!(and_u64_1d, u64, u64, u64, generic_array::typenum::U1, |a, b| a & b);
+impl_op!(and_u64_2d, u64, u64, u64, generic_array::typenum::U2, |a, b| a & b);
+impl_op!(and_u64_3d, u64, u64, u64, generic_array::typenum::U3, |a, b| a & b);
+impl_op!(and_u64_4d, u64, u64, u64, generic_array::typenum::U4, |a, b| a & b);
+impl_op!(and_u64_5d, u64, u64, u64, generic_array::typenum::U5, |a, b| a & b);
+impl_op!(and_u64_6d, u64, u64, u64, generic_array::typenum::U6, |a, b| a & b);
+impl_op!(or_u64_1d, u64, u64, u64, generic_array::typenum::U1, |a, b| a | b);
+impl_op!(or_u64_2d, u64, u64, u64, generic_array::typenum::U2, |a, b| a | b);
+impl_op!(or_u64_3d, u64, u64, u64, generic_array::typenum::U3, |a, b| a | b);
+impl_op!(or_u64_4d, u64, u64, u64, generic_array::typenum::U4, |a, b| a | b);
+impl_op!(or_u64_5d, u64, u64, u64, generic_array::typenum::U5, |a, b| a | b);
+impl_op!(or_u64_6d, u64, u64, u64, generic_array::typenum::U6, |a, b| a | b);
+impl_op!(xor_u64_1d, u64, u64, u64, generic_array::typenum::U1, |a, b| a ^ b);
+impl_op!(xor_u64_2d, u64, u64, u64, generic_array::typenum::U2, |a, b| a ^ b);
+impl_op!(xor_u64_3d, u64, u64, u64, generic_array::typenum::U3, |a, b| a ^ b);
+impl_op!(xor_u64_4d, u64, u64, u64, generic_array::typenum::U4, |a, b| a ^ b);
+impl_op!(xor_u64_5d, u64, u64, u64, generic_array::typenum::U5, |a, b| a ^ b);
+impl_op!(xor_u64_6d, u64, u64, u64, generic_array::typenum::U6, |a, b| a ^ b);
+impl_op!(shl_u64_1d, u64, u64, u64, generic_array::typenum::U1, Integer::shl);
+impl_op!(shl_u64_2d, u64, u64, u64, generic_array::typenum::U2, Integer::shl);
+impl_op!(shl_u64_3d, u64, u64, u64, generic_array::typenum::U3, Integer::shl);
+impl_op!(shl_u64_4d, u64, u64, u64, generic_array::typenum::U4, Integer::shl);
+impl_op!(shl_u64_5d, u64, u64, u64, generic_array::typenum::U5, Integer::shl);
+impl_op!(shl_u64_6d, u64, u64, u64, generic_array::typenum::U6, Integer::shl);
+impl_op!(shr_u64_1d, u64, u64, u64, generic_array::typenum::U1, Integer::shr);
+impl_op!(shr_u64_2d, u64, u64, u64, generic_array::typenum::U2, Integer::shr);
+impl_op!(shr_u64_3d, u64, u64, u64, generic_array::typenum::U3, Integer::shr);
+impl_op!(shr_u64_4d, u64, u64, u64, generic_array::typenum::U4, Integer::shr);
+impl_op!(shr_u64_5d, u64, u64, u64, generic_array::typenum::U5, Integer::shr);
+impl_op!(shr_u64_6d, u64, u64, u64, generic_array::typenum::U6, Integer::shr);
+impl_op!(eq_u64_1d, u64, u64, bool, generic_array::typenum::U1, |a, b| a == b);
+impl_op!(eq_u64_2d, u64, u64, bool, generic_array::typenum::U2, |a, b| a == b);
+impl_op!(eq_u64_3d, u64, u64, bool, generic_array::typenum::U3, |a, b| a == b);
+impl_op!(eq_u64_4d, u64, u64, bool, generic_array::typenum::U4, |a, b| a == b);
+impl_op!(eq_u64_5d, u64, u64, bool, generic_array::typenum::U5, |a, b| a == b);
+impl_op!(eq_u64_6d, u64, u64, bool, generic_array::typenum::U6, |a, b| a == b);
+impl_op!(ne_u64_1d, u64, u64, bool, generic_array::typenum::U1, |a, b| a != b);
+impl_op!(ne_u64_2d, u64, u64, bool, generic_array::typenum::U2, |a, b| a != b);
+impl_op!(ne_u64_3d, u64, u64, bool, generic_array::typenum::U3, |a, b| a != b);
+impl_op!(ne_u64_4d, u64, u64, bool, generic_array::typenum::U4, |a, b| a != b);
+impl_op!(ne_u64_5d, u64, u64, bool, generic_array::typenum::U5, |a, b| a != b);
+impl_op!(ne_u64_6d, u64, u64, bool, generic_array::typenum::U6, |a, b| a != b);
+impl_op!(lt_u64_1d, u64, u64, bool, generic_array::typenum::U1, |a, b| a < b);
+impl_op!(lt_u64_2d, u64, u64, bool, generic_array::typenum::U2, |a, b| a < b);
+impl_op!(lt_u64_3d, u64, u64, bool, generic_array::typenum::U3, |a, b| a < b);
+impl_op!(lt_u64_4d, u64, u64, bool, generic_array::typenum::U4, |a, b| a < b);
+impl_op!(lt_u64_5d, u64, u64, bool, generic_array::typenum::U5, |a, b| a < b);
+impl_op!(lt_u64_6d, u64, u64, bool, generic_array::typenum::U6, |a, b| a < b);
+impl_op!(le_u64_1d, u64, u64, bool, generic_array::typenum::U1, |a, b| a <= b);
+impl_op!(le_u64_2d, u64, u64, bool, generic_array::typenum::U2, |a, b| a <= b);
+impl_op!(le_u64_3d, u64, u64, bool, generic_array::typenum::U3, |a, b| a <= b);
+impl_op!(le_u64_4d, u64, u64, bool, generic_array::typenum::U4, |a, b| a <= b);
+impl_op!(le_u64_5d, u64, u64, bool, generic_array::typenum::U5, |a, b| a <= b);
+impl_op!(le_u64_6d, u64, u64, bool, generic_array::typenum::U6, |a, b| a <= b);
+impl_op!(gt_u64_1d, u64, u64, bool, generic_array::typenum::U1, |a, b| a > b);
+impl_op!(gt_u64_2d, u64, u64, bool, generic_array::typenum::U2, |a, b| a > b);
+impl_op!(gt_u64_3d, u64, u64, bool, generic_array::typenum::U3, |a, b| a > b);
+impl_op!(gt_u64_4d, u64, u64, bool, generic_array::typenum::U4, |a, b| a > b);
+impl_op!(gt_u64_5d, u64, u64, bool, generic_array::typenum::U5, |a, b| a > b);
+impl_op!(gt_u64_6d, u64, u64, bool, generic_array::typenum::U6, |a, b| a > b);
+impl_op!(ge_u64_1d, u64, u64, bool, generic_array::typenum::U1, |a, b| a >= b);
+impl_op!(ge_u64_2d, u64, u64, bool, generic_array::typenum::U2, |a, b| a >= b);
+impl_op!(ge_u64_3d, u64, u64, bool, generic_array::typenum::U3, |a, b| a >= b);
+impl_op!(ge_u64_4d, u64, u64, bool, generic_array::typenum::U4, |a, b| a >= b);
+impl_op!(ge_u64_5d, u64, u64, bool, generic_array::typenum::U5, |a, b| a >= b);
+impl_op!(ge_u64_6d, u64, u64, bool, generic_array::typenum::U6, |a, b| a >= b);
+
+/// In place variants of [`impl_op`] for operations whose output has the type of the operands. The
+/// first kernel computes `a[i] = op(a[i], b[..])`, the second `b[i] = op(a[..], b[i])`. The buffer
+/// that is written is passed once as a raw pointer, it is both read and written.
+macro_rules! impl_assign_op {
+    ($fn_name:ident, $rhs_fn_name:ident, $ty:ty, $dim:ty, $op:expr) => {
+        #[kernel]
+        #[allow(improper_ctypes_definitions, clippy::missing_safety_doc)]
+        pub unsafe fn $fn_name(
+            a: *mut $ty,
+            a_size: usize,
+            a_strides: generic_array::GenericArray<usize, $dim>,
+            b: &[$ty],
+            b_strides: generic_array::GenericArray<usize, $dim>,
+        ) {
+            let a = core::slice::from_raw_parts_mut(a, a_size);
+            let idx = thread::index_1d() as usize;
+            apply_op_assign(a, a_strides, b, b_strides, $op, idx);
+        }
+
+        #[kernel]
+        #[allow(improper_ctypes_definitions, clippy::missing_safety_doc)]
+        pub unsafe fn $rhs_fn_name(
+            a: &[$ty],
+            a_strides: generic_array::GenericArray<usize, $dim>,
+            b: *mut $ty,
+            b_size: usize,
+            b_strides: generic_array::GenericArray<usize, $dim>,
+        ) {
+            let b = core::slice::from_raw_parts_mut(b, b_size);
+            let idx = thread::index_1d() as usize;
+            let op: fn($ty, $ty) -> $ty = $op;
+            apply_op_assign(b, b_strides, a, a_strides, |b, a| op(a, b), idx);
+        }
+    };
+}
+
+impl_assign_op!(add_assign_f32_1d, add_assign_rhs_f32_1d, f32, generic_array::typenum::U1, |a, b| a + b);
+impl_assign_op!(add_assign_f32_2d, add_assign_rhs_f32_2d, f32, generic_array::typenum::U2, |a, b| a + b);
+impl_assign_op!(add_assign_f32_3d, add_assign_rhs_f32_3d, f32, generic_array::typenum::U3, |a, b| a + b);
+impl_assign_op!(add_assign_f32_4d, add_assign_rhs_f32_4d, f32, generic_array::typenum::U4, |a, b| a + b);
+impl_assign_op!(add_assign_f32_5d, add_assign_rhs_f32_5d, f32, generic_array::typenum::U5, |a, b| a + b);
+impl_assign_op!(add_assign_f32_6d, add_assign_rhs_f32_6d, f32, generic_array::typenum::U6, |a, b| a + b);
+
+impl_assign_op!(mul_assign_f32_1d, mul_assign_rhs_f32_1d, f32, generic_array::typenum::U1, |a, b| a * b);
+impl_assign_op!(mul_assign_f32_2d, mul_assign_rhs_f32_2d, f32, generic_array::typenum::U2, |a, b| a * b);
+impl_assign_op!(mul_assign_f32_3d, mul_assign_rhs_f32_3d, f32, generic_array::typenum::U3, |a, b| a * b);
+impl_assign_op!(mul_assign_f32_4d, mul_assign_rhs_f32_4d, f32, generic_array::typenum::U4, |a, b| a * b);
+impl_assign_op!(mul_assign_f32_5d, mul_assign_rhs_f32_5d, f32, generic_array::typenum::U5, |a, b| a * b);
+impl_assign_op!(mul_assign_f32_6d, mul_assign_rhs_f32_6d, f32, generic_array::typenum::U6, |a, b| a * b);
+
+impl_assign_op!(sub_assign_f32_1d, sub_assign_rhs_f32_1d, f32, generic_array::typenum::U1, |a, b| a - b);
+impl_assign_op!(sub_assign_f32_2d, sub_assign_rhs_f32_2d, f32, generic_array::typenum::U2, |a, b| a - b);
+impl_assign_op!(sub_assign_f32_3d, sub_assign_rhs_f32_3d, f32, generic_array::typenum::U3, |a, b| a - b);
+impl_assign_op!(sub_assign_f32_4d, sub_assign_rhs_f32_4d, f32, generic_array::typenum::U4, |a, b| a - b);
+impl_assign_op!(sub_assign_f32_5d, sub_assign_rhs_f32_5d, f32, generic_array::typenum::U5, |a, b| a - b);
+impl_assign_op!(sub_assign_f32_6d, sub_assign_rhs_f32_6d, f32, generic_array::typenum::U6, |a, b| a - b);
+
+impl_assign_op!(div_assign_f32_1d, div_assign_rhs_f32_1d, f32, generic_array::typenum::U1, |a, b| a / b);
+impl_assign_op!(div_assign_f32_2d, div_assign_rhs_f32_2d, f32, generic_array::typenum::U2, |a, b| a / b);
+impl_assign_op!(div_assign_f32_3d, div_assign_rhs_f32_3d, f32, generic_array::typenum::U3, |a, b| a / b);
+impl_assign_op!(div_assign_f32_4d, div_assign_rhs_f32_4d, f32, generic_array::typenum::U4, |a, b| a / b);
+impl_assign_op!(div_assign_f32_5d, div_assign_rhs_f32_5d, f32, generic_array::typenum::U5, |a, b| a / b);
+impl_assign_op!(div_assign_f32_6d, div_assign_rhs_f32_6d, f32, generic_array::typenum::U6, |a, b| a / b);
+
+impl_assign_op!(add_assign_f64_1d, add_assign_rhs_f64_1d, f64, generic_array::typenum::U1, |a, b| a + b);
+impl_assign_op!(add_assign_f64_2d, add_assign_rhs_f64_2d, f64, generic_array::typenum::U2, |a, b| a + b);
+impl_assign_op!(add_assign_f64_3d, add_assign_rhs_f64_3d, f64, generic_array::typenum::U3, |a, b| a + b);
+impl_assign_op!(add_assign_f64_4d, add_assign_rhs_f64_4d, f64, generic_array::typenum::U4, |a, b| a + b);
+impl_assign_op!(add_assign_f64_5d, add_assign_rhs_f64_5d, f64, generic_array::typenum::U5, |a, b| a + b);
+impl_assign_op!(add_assign_f64_6d, add_assign_rhs_f64_6d, f64, generic_array::typenum::U6, |a, b| a + b);
+
+impl_assign_op!(mul_assign_f64_1d, mul_assign_rhs_f64_1d, f64, generic_array::typenum::U1, |a, b| a * b);
+impl_assign_op!(mul_assign_f64_2d, mul_assign_rhs_f64_2d, f64, generic_array::typenum::U2, |a, b| a * b);
+impl_assign_op!(mul_assign_f64_3d, mul_assign_rhs_f64_3d, f64, generic_array::typenum::U3, |a, b| a * b);
+impl_assign_op!(mul_assign_f64_4d, mul_assign_rhs_f64_4d, f64, generic_array::typenum::U4, |a, b| a * b);
+impl_assign_op!(mul_assign_f64_5d, mul_assign_rhs_f64_5d, f64, generic_array::typenum::U5, |a, b| a * b);
+impl_assign_op!(mul_assign_f64_6d, mul_assign_rhs_f64_6d, f64, generic_array::typenum::U6, |a, b| a * b);
+
+impl_assign_op!(sub_assign_f64_1d, sub_assign_rhs_f64_1d, f64, generic_array::typenum::U1, |a, b| a - b);
+impl_assign_op!(sub_assign_f64_2d, sub_assign_rhs_f64_2d, f64, generic_array::typenum::U2, |a, b| a - b);
+impl_assign_op!(sub_assign_f64_3d, sub_assign_rhs_f64_3d, f64, generic_array::typenum::U3, |a, b| a - b);
+impl_assign_op!(sub_assign_f64_4d, sub_assign_rhs_f64_4d, f64, generic_array::typenum::U4, |a, b| a - b);
+impl_assign_op!(sub_assign_f64_5d, sub_assign_rhs_f64_5d, f64, generic_array::typenum::U5, |a, b| a - b);
+impl_assign_op!(sub_assign_f64_6d, sub_assign_rhs_f64_6d, f64, generic_array::typenum::U6, |a, b| a - b);
+
+impl_assign_op!(div_assign_f64_1d, div_assign_rhs_f64_1d, f64, generic_array::typenum::U1, |a, b| a / b);
+impl_assign_op!(div_assign_f64_2d, div_assign_rhs_f64_2d, f64, generic_array::typenum::U2, |a, b| a / b);
+impl_assign_op!(div_assign_f64_3d, div_assign_rhs_f64_3d, f64, generic_array::typenum::U3, |a, b| a / b);
+impl_assign_op!(div_assign_f64_4d, div_assign_rhs_f64_4d, f64, generic_array::typenum::U4, |a, b| a / b);
+impl_assign_op!(div_assign_f64_5d, div_assign_rhs_f64_5d, f64, generic_array::typenum::U5, |a, b| a / b);
+impl_assign_op!(div_assign_f64_6d, div_assign_rhs_f64_6d, f64, generic_array::typenum::U6, |a, b| a / b);
+
+impl_assign_op!(add_assign_f16_1d, add_assign_rhs_f16_1d, f16, generic_array::typenum::U1, |a, b| a + b);
+impl_assign_op!(add_assign_f16_2d, add_assign_rhs_f16_2d, f16, generic_array::typenum::U2, |a, b| a + b);
+impl_assign_op!(add_assign_f16_3d, add_assign_rhs_f16_3d, f16, generic_array::typenum::U3, |a, b| a + b);
+impl_assign_op!(add_assign_f16_4d, add_assign_rhs_f16_4d, f16, generic_array::typenum::U4, |a, b| a + b);
+impl_assign_op!(add_assign_f16_5d, add_assign_rhs_f16_5d, f16, generic_array::typenum::U5, |a, b| a + b);
+impl_assign_op!(add_assign_f16_6d, add_assign_rhs_f16_6d, f16, generic_array::typenum::U6, |a, b| a + b);
+
+impl_assign_op!(mul_assign_f16_1d, mul_assign_rhs_f16_1d, f16, generic_array::typenum::U1, |a, b| a * b);
+impl_assign_op!(mul_assign_f16_2d, mul_assign_rhs_f16_2d, f16, generic_array::typenum::U2, |a, b| a * b);
+impl_assign_op!(mul_assign_f16_3d, mul_assign_rhs_f16_3d, f16, generic_array::typenum::U3, |a, b| a * b);
+impl_assign_op!(mul_assign_f16_4d, mul_assign_rhs_f16_4d, f16, generic_array::typenum::U4, |a, b| a * b);
+impl_assign_op!(mul_assign_f16_5d, mul_assign_rhs_f16_5d, f16, generic_array::typenum::U5, |a, b| a * b);
+impl_assign_op!(mul_assign_f16_6d, mul_assign_rhs_f16_6d, f16, generic_array::typenum::U6, |a, b| a * b);
+
+impl_assign_op!(sub_assign_f16_1d, sub_assign_rhs_f16_1d, f16, generic_array::typenum::U1, |a, b| a - b);
+impl_assign_op!(sub_assign_f16_2d, sub_assign_rhs_f16_2d, f16, generic_array::typenum::U2, |a, b| a - b);
+impl_assign_op!(sub_assign_f16_3d, sub_assign_rhs_f16_3d, f16, generic_array::typenum::U3, |a, b| a - b);
+impl_assign_op!(sub_assign_f16_4d, sub_assign_rhs_f16_4d, f16, generic_array::typenum::U4, |a, b| a - b);
+impl_assign_op!(sub_assign_f16_5d, sub_assign_rhs_f16_5d, f16, generic_array::typenum::U5, |a, b| a - b);
+impl_assign_op!(sub_assign_f16_6d, sub_assign_rhs_f16_6d, f16, generic_array::typenum::U6, |a, b| a - b);
+
+impl_assign_op!(div_assign_f16_1d, div_assign_rhs_f16_1d, f16, generic_array::typenum::U1, |a, b| a / b);
+impl_assign_op!(div_assign_f16_2d, div_assign_rhs_f16_2d, f16, generic_array::typenum::U2, |a, b| a / b);
+impl_assign_op!(div_assign_f16_3d, div_assign_rhs_f16_3d, f16, generic_array::typenum::U3, |a, b| a / b);
+impl_assign_op!(div_assign_f16_4d, div_assign_rhs_f16_4d, f16, generic_array::typenum::U4, |a, b| a / b);
+impl_assign_op!(div_assign_f16_5d, div_assign_rhs_f16_5d, f16, generic_array::typenum::U5, |a, b| a / b);
+impl_assign_op!(div_assign_f16_6d, div_assign_rhs_f16_6d, f16, generic_array::typenum::U6, |a, b| a / b);
+
+impl_assign_op!(add_assign_bf16_1d, add_assign_rhs_bf16_1d, bf16, generic_array::typenum::U1, |a, b| a + b);
+impl_assign_op!(add_assign_bf16_2d, add_assign_rhs_bf16_2d, bf16, generic_array::typenum::U2, |a, b| a + b);
+impl_assign_op!(add_assign_bf16_3d, add_assign_rhs_bf16_3d, bf16, generic_array::typenum::U3, |a, b| a + b);
+impl_assign_op!(add_assign_bf16_4d, add_assign_rhs_bf16_4d, bf16, generic_array::typenum::U4, |a, b| a + b);
+impl_assign_op!(add_assign_bf16_5d, add_assign_rhs_bf16_5d, bf16, generic_array::typenum::U5, |a, b| a + b);
+impl_assign_op!(add_assign_bf16_6d, add_assign_rhs_bf16_6d, bf16, generic_array::typenum::U6, |a, b| a + b);
+
+impl_assign_op!(mul_assign_bf16_1d, mul_assign_rhs_bf16_1d, bf16, generic_array::typenum::U1, |a, b| a * b);
+impl_assign_op!(mul_assign_bf16_2d, mul_assign_rhs_bf16_2d, bf16, generic_array::typenum::U2, |a, b| a * b);
+impl_assign_op!(mul_assign_bf16_3d, mul_assign_rhs_bf16_3d, bf16, generic_array::typenum::U3, |a, b| a * b);
+impl_assign_op!(mul_assign_bf16_4d, mul_assign_rhs_bf16_4d, bf16, generic_array::typenum::U4, |a, b| a * b);
+impl_assign_op!(mul_assign_bf16_5d, mul_assign_rhs_bf16_5d, bf16, generic_array::typenum::U5, |a, b| a * b);
+impl_assign_op!(mul_assign_bf16_6d, mul_assign_rhs_bf16_6d, bf16, generic_array::typenum::U6, |a, b| a * b);
+
+impl_assign_op!(sub_assign_bf16_1d, sub_assign_rhs_bf16_1d, bf16, generic_array::typenum::U1, |a, b| a - b);
+impl_assign_op!(sub_assign_bf16_2d, sub_assign_rhs_bf16_2d, bf16, generic_array::typenum::U2, |a, b| a - b);
+impl_assign_op!(sub_assign_bf16_3d, sub_assign_rhs_bf16_3d, bf16, generic_array::typenum::U3, |a, b| a - b);
+impl_assign_op!(sub_assign_bf16_4d, sub_assign_rhs_bf16_4d, bf16, generic_array::typenum::U4, |a, b| a - b);
+impl_assign_op!(sub_assign_bf16_5d, sub_assign_rhs_bf16_5d, bf16, generic_array::typenum::U5, |a, b| a - b);
+impl_assign_op!(sub_assign_bf16_6d, sub_assign_rhs_bf16_6d, bf16, generic_array::typenum::U6, |a, b| a - b);
+
+impl_assign_op!(div_assign_bf16_1d, div_assign_rhs_bf16_1d, bf16, generic_array::typenum::U1, |a, b| a / b);
+impl_assign_op!(div_assign_bf16_2d, div_assign_rhs_bf16_2d, bf16, generic_array::typenum::U2, |a, b| a / b);
+impl_assign_op!(div_assign_bf16_3d, div_assign_rhs_bf16_3d, bf16, generic_array::typenum::U3, |a, b| a / b);
+impl_assign_op!(div_assign_bf16_4d, div_assign_rhs_bf16_4d, bf16, generic_array::typenum::U4, |a, b| a / b);
+impl_assign_op!(div_assign_bf16_5d, div_assign_rhs_bf16_5d, bf16, generic_array::typenum::U5, |a, b| a / b);
+impl_assign_op!(div_assign_bf16_6d, div_assign_rhs_bf16_6d, bf16, generic_array::typenum::U6, |a, b| a / b);
+
+impl_assign_op!(eq_assign_bool_1d, eq_assign_rhs_bool_1d, bool, generic_array::typenum::U1, |a, b| a == b);
+impl_assign_op!(eq_assign_bool_2d, eq_assign_rhs_bool_2d, bool, generic_array::typenum::U2, |a, b| a == b);
+impl_assign_op!(eq_assign_bool_3d, eq_assign_rhs_bool_3d, bool, generic_array::typenum::U3, |a, b| a == b);
+impl_assign_op!(eq_assign_bool_4d, eq_assign_rhs_bool_4d, bool, generic_array::typenum::U4, |a, b| a == b);
+impl_assign_op!(eq_assign_bool_5d, eq_assign_rhs_bool_5d, bool, generic_array::typenum::U5, |a, b| a == b);
+impl_assign_op!(eq_assign_bool_6d, eq_assign_rhs_bool_6d, bool, generic_array::typenum::U6, |a, b| a == b);
+
+impl_assign_op!(ne_assign_bool_1d, ne_assign_rhs_bool_1d, bool, generic_array::typenum::U1, |a, b| a != b);
+impl_assign_op!(ne_assign_bool_2d, ne_assign_rhs_bool_2d, bool, generic_array::typenum::U2, |a, b| a != b);
+impl_assign_op!(ne_assign_bool_3d, ne_assign_rhs_bool_3d, bool, generic_array::typenum::U3, |a, b| a != b);
+impl_assign_op!(ne_assign_bool_4d, ne_assign_rhs_bool_4d, bool, generic_array::typenum::U4, |a, b| a != b);
+impl_assign_op!(ne_assign_bool_5d, ne_assign_rhs_bool_5d, bool, generic_array::typenum::U5, |a, b| a != b);
+impl_assign_op!(ne_assign_bool_6d, ne_assign_rhs_bool_6d, bool, generic_array::typenum::U6, |a, b| a != b);
+
+impl_assign_op!(and_assign_bool_1d, and_assign_rhs_bool_1d, bool, generic_array::typenum::U1, |a, b| a & b);
+impl_assign_op!(and_assign_bool_2d, and_assign_rhs_bool_2d, bool, generic_array::typenum::U2, |a, b| a & b);
+impl_assign_op!(and_assign_bool_3d, and_assign_rhs_bool_3d, bool, generic_array::typenum::U3, |a, b| a & b);
+impl_assign_op!(and_assign_bool_4d, and_assign_rhs_bool_4d, bool, generic_array::typenum::U4, |a, b| a & b);
+impl_assign_op!(and_assign_bool_5d, and_assign_rhs_bool_5d, bool, generic_array::typenum::U5, |a, b| a & b);
+impl_assign_op!(and_assign_bool_6d, and_assign_rhs_bool_6d, bool, generic_array::typenum::U6, |a, b| a & b);
+
+impl_assign_op!(or_assign_bool_1d, or_assign_rhs_bool_1d, bool, generic_array::typenum::U1, |a, b| a | b);
+impl_assign_op!(or_assign_bool_2d, or_assign_rhs_bool_2d, bool, generic_array::typenum::U2, |a, b| a | b);
+impl_assign_op!(or_assign_bool_3d, or_assign_rhs_bool_3d, bool, generic_array::typenum::U3, |a, b| a | b);
+impl_assign_op!(or_assign_bool_4d, or_assign_rhs_bool_4d, bool, generic_array::typenum::U4, |a, b| a | b);
+impl_assign_op!(or_assign_bool_5d, or_assign_rhs_bool_5d, bool, generic_array::typenum::U5, |a, b| a | b);
+impl_assign_op!(or_assign_bool_6d, or_assign_rhs_bool_6d, bool, generic_array::typenum::U6, |a, b| a | b);
+
+impl_assign_op!(xor_assign_bool_1d, xor_assign_rhs_bool_1d, bool, generic_array::typenum::U1, |a, b| a ^ b);
+impl_assign_op!(xor_assign_bool_2d, xor_assign_rhs_bool_2d, bool, generic_array::typenum::U2, |a, b| a ^ b);
+impl_assign_op!(xor_assign_bool_3d, xor_assign_rhs_bool_3d, bool, generic_array::typenum::U3, |a, b| a ^ b);
+impl_assign_op!(xor_assign_bool_4d, xor_assign_rhs_bool_4d, bool, generic_array::typenum::U4, |a, b| a ^ b);
+impl_assign_op!(xor_assign_bool_5d, xor_assign_rhs_bool_5d, bool, generic_array::typenum::U5, |a, b| a ^ b);
+impl_assign_op!(xor_assign_bool_6d, xor_assign_rhs_bool_6d, bool, generic_array::typenum::U6, |a, b| a ^ b);
+
+impl_assign_op!(lt_assign_bool_1d, lt_assign_rhs_bool_1d, bool, generic_array::typenum::U1, |a, b| !a & b);
+impl_assign_op!(lt_assign_bool_2d, lt_assign_rhs_bool_2d, bool, generic_array::typenum::U2, |a, b| !a & b);
+impl_assign_op!(lt_assign_bool_3d, lt_assign_rhs_bool_3d, bool, generic_array::typenum::U3, |a, b| !a & b);
+impl_assign_op!(lt_assign_bool_4d, lt_assign_rhs_bool_4d, bool, generic_array::typenum::U4, |a, b| !a & b);
+impl_assign_op!(lt_assign_bool_5d, lt_assign_rhs_bool_5d, bool, generic_array::typenum::U5, |a, b| !a & b);
+impl_assign_op!(lt_assign_bool_6d, lt_assign_rhs_bool_6d, bool, generic_array::typenum::U6, |a, b| !a & b);
+
+impl_assign_op!(le_assign_bool_1d, le_assign_rhs_bool_1d, bool, generic_array::typenum::U1, |a, b| !a | b);
+impl_assign_op!(le_assign_bool_2d, le_assign_rhs_bool_2d, bool, generic_array::typenum::U2, |a, b| !a | b);
+impl_assign_op!(le_assign_bool_3d, le_assign_rhs_bool_3d, bool, generic_array::typenum::U3, |a, b| !a | b);
+impl_assign_op!(le_assign_bool_4d, le_assign_rhs_bool_4d, bool, generic_array::typenum::U4, |a, b| !a | b);
+impl_assign_op!(le_assign_bool_5d, le_assign_rhs_bool_5d, bool, generic_array::typenum::U5, |a, b| !a | b);
+impl_assign_op!(le_assign_bool_6d, le_assign_rhs_bool_6d, bool, generic_array::typenum::U6, |a, b| !a | b);
+
+impl_assign_op!(gt_assign_bool_1d, gt_assign_rhs_bool_1d, bool, generic_array::typenum::U1, |a, b| a & !b);
+impl_assign_op!(gt_assign_bool_2d, gt_assign_rhs_bool_2d, bool, generic_array::typenum::U2, |a, b| a & !b);
+impl_assign_op!(gt_assign_bool_3d, gt_assign_rhs_bool_3d, bool, generic_array::typenum::U3, |a, b| a & !b);
+impl_assign_op!(gt_assign_bool_4d, gt_assign_rhs_bool_4d, bool, generic_array::typenum::U4, |a, b| a & !b);
+impl_assign_op!(gt_assign_bool_5d, gt_assign_rhs_bool_5d, bool, generic_array::typenum::U5, |a, b| a & !b);
+impl_assign_op!(gt_assign_bool_6d, gt_assign_rhs_bool_6d, bool, generic_array::typenum::U6, |a, b| a & !b);
+
+impl_assign_op!(ge_assign_bool_1d, ge_assign_rhs_bool_1d, bool, generic_array::typenum::U1, |a, b| a | !b);
+impl_assign_op!(ge_assign_bool_2d, ge_assign_rhs_bool_2d, bool, generic_array::typenum::U2, |a, b| a | !b);
+impl_assign_op!(ge_assign_bool_3d, ge_assign_rhs_bool_3d, bool, generic_array::typenum::U3, |a, b| a | !b);
+impl_assign_op!(ge_assign_bool_4d, ge_assign_rhs_bool_4d, bool, generic_array::typenum::U4, |a, b| a | !b);
+impl_assign_op!(ge_assign_bool_5d, ge_assign_rhs_bool_5d, bool, generic_array::typenum::U5, |a, b| a | !b);
+impl_assign_op!(ge_assign_bool_6d, ge_assign_rhs_bool_6d, bool, generic_array::typenum::U6, |a, b| a | !b);
+
+impl_assign_op!(add_assign_i8_1d, add_assign_rhs_i8_1d, i8, generic_array::typenum::U1, Arith::add);
+impl_assign_op!(add_assign_i8_2d, add_assign_rhs_i8_2d, i8, generic_array::typenum::U2, Arith::add);
+impl_assign_op!(add_assign_i8_3d, add_assign_rhs_i8_3d, i8, generic_array::typenum::U3, Arith::add);
+impl_assign_op!(add_assign_i8_4d, add_assign_rhs_i8_4d, i8, generic_array::typenum::U4, Arith::add);
+impl_assign_op!(add_assign_i8_5d, add_assign_rhs_i8_5d, i8, generic_array::typenum::U5, Arith::add);
+impl_assign_op!(add_assign_i8_6d, add_assign_rhs_i8_6d, i8, generic_array::typenum::U6, Arith::add);
+
+impl_assign_op!(sub_assign_i8_1d, sub_assign_rhs_i8_1d, i8, generic_array::typenum::U1, Arith::sub);
+impl_assign_op!(sub_assign_i8_2d, sub_assign_rhs_i8_2d, i8, generic_array::typenum::U2, Arith::sub);
+impl_assign_op!(sub_assign_i8_3d, sub_assign_rhs_i8_3d, i8, generic_array::typenum::U3, Arith::sub);
+impl_assign_op!(sub_assign_i8_4d, sub_assign_rhs_i8_4d, i8, generic_array::typenum::U4, Arith::sub);
+impl_assign_op!(sub_assign_i8_5d, sub_assign_rhs_i8_5d, i8, generic_array::typenum::U5, Arith::sub);
+impl_assign_op!(sub_assign_i8_6d, sub_assign_rhs_i8_6d, i8, generic_array::typenum::U6, Arith::sub);
+
+impl_assign_op!(mul_assign_i8_1d, mul_assign_rhs_i8_1d, i8, generic_array::typenum::U1, Arith::mul);
+impl_assign_op!(mul_assign_i8_2d, mul_assign_rhs_i8_2d, i8, generic_array::typenum::U2, Arith::mul);
+impl_assign_op!(mul_assign_i8_3d, mul_assign_rhs_i8_3d, i8, generic_array::typenum::U3, Arith::mul);
+impl_assign_op!(mul_assign_i8_4d, mul_assign_rhs_i8_4d, i8, generic_array::typenum::U4, Arith::mul);
+impl_assign_op!(mul_assign_i8_5d, mul_assign_rhs_i8_5d, i8, generic_array::typenum::U5, Arith::mul);
+impl_assign_op!(mul_assign_i8_6d, mul_assign_rhs_i8_6d, i8, generic_array::typenum::U6, Arith::mul);
+
+impl_assign_op!(div_assign_i8_1d, div_assign_rhs_i8_1d, i8, generic_array::typenum::U1, Arith::div);
+impl_assign_op!(div_assign_i8_2d, div_assign_rhs_i8_2d, i8, generic_array::typenum::U2, Arith::div);
+impl_assign_op!(div_assign_i8_3d, div_assign_rhs_i8_3d, i8, generic_array::typenum::U3, Arith::div);
+impl_assign_op!(div_assign_i8_4d, div_assign_rhs_i8_4d, i8, generic_array::typenum::U4, Arith::div);
+impl_assign_op!(div_assign_i8_5d, div_assign_rhs_i8_5d, i8, generic_array::typenum::U5, Arith::div);
+impl_assign_op!(div_assign_i8_6d, div_assign_rhs_i8_6d, i8, generic_array::typenum::U6, Arith::div);
+
+impl_assign_op!(rem_assign_i8_1d, rem_assign_rhs_i8_1d, i8, generic_array::typenum::U1, Integer::rem);
+impl_assign_op!(rem_assign_i8_2d, rem_assign_rhs_i8_2d, i8, generic_array::typenum::U2, Integer::rem);
+impl_assign_op!(rem_assign_i8_3d, rem_assign_rhs_i8_3d, i8, generic_array::typenum::U3, Integer::rem);
+impl_assign_op!(rem_assign_i8_4d, rem_assign_rhs_i8_4d, i8, generic_array::typenum::U4, Integer::rem);
+impl_assign_op!(rem_assign_i8_5d, rem_assign_rhs_i8_5d, i8, generic_array::typenum::U5, Integer::rem);
+impl_assign_op!(rem_assign_i8_6d, rem_assign_rhs_i8_6d, i8, generic_array::typenum::U6, Integer::rem);
+
+impl_assign_op!(and_assign_i8_1d, and_assign_rhs_i8_1d, i8, generic_array::typenum::U1, |a, b| a & b);
+impl_assign_op!(and_assign_i8_2d, and_assign_rhs_i8_2d, i8, generic_array::typenum::U2, |a, b| a & b);
+impl_assign_op!(and_assign_i8_3d, and_assign_rhs_i8_3d, i8, generic_array::typenum::U3, |a, b| a & b);
+impl_assign_op!(and_assign_i8_4d, and_assign_rhs_i8_4d, i8, generic_array::typenum::U4, |a, b| a & b);
+impl_assign_op!(and_assign_i8_5d, and_assign_rhs_i8_5d, i8, generic_array::typenum::U5, |a, b| a & b);
+impl_assign_op!(and_assign_i8_6d, and_assign_rhs_i8_6d, i8, generic_array::typenum::U6, |a, b| a & b);
+
+impl_assign_op!(or_assign_i8_1d, or_assign_rhs_i8_1d, i8, generic_array::typenum::U1, |a, b| a | b);
+impl_assign_op!(or_assign_i8_2d, or_assign_rhs_i8_2d, i8, generic_array::typenum::U2, |a, b| a | b);
+impl_assign_op!(or_assign_i8_3d, or_assign_rhs_i8_3d, i8, generic_array::typenum::U3, |a, b| a | b);
+impl_assign_op!(or_assign_i8_4d, or_assign_rhs_i8_4d, i8, generic_array::typenum::U4, |a, b| a | b);
+impl_assign_op!(or_assign_i8_5d, or_assign_rhs_i8_5d, i8, generic_array::typenum::U5, |a, b| a | b);
+impl_assign_op!(or_assign_i8_6d, or_assign_rhs_i8_6d, i8, generic_array::typenum::U6, |a, b| a | b);
+
+impl_assign_op!(xor_assign_i8_1d, xor_assign_rhs_i8_1d, i8, generic_array::typenum::U1, |a, b| a ^ b);
+impl_assign_op!(xor_assign_i8_2d, xor_assign_rhs_i8_2d, i8, generic_array::typenum::U2, |a, b| a ^ b);
+impl_assign_op!(xor_assign_i8_3d, xor_assign_rhs_i8_3d, i8, generic_array::typenum::U3, |a, b| a ^ b);
+impl_assign_op!(xor_assign_i8_4d, xor_assign_rhs_i8_4d, i8, generic_array::typenum::U4, |a, b| a ^ b);
+impl_assign_op!(xor_assign_i8_5d, xor_assign_rhs_i8_5d, i8, generic_array::typenum::U5, |a, b| a ^ b);
+impl_assign_op!(xor_assign_i8_6d, xor_assign_rhs_i8_6d, i8, generic_array::typenum::U6, |a, b| a ^ b);
+
+impl_assign_op!(shl_assign_i8_1d, shl_assign_rhs_i8_1d, i8, generic_array::typenum::U1, Integer::shl);
+impl_assign_op!(shl_assign_i8_2d, shl_assign_rhs_i8_2d, i8, generic_array::typenum::U2, Integer::shl);
+impl_assign_op!(shl_assign_i8_3d, shl_assign_rhs_i8_3d, i8, generic_array::typenum::U3, Integer::shl);
+impl_assign_op!(shl_assign_i8_4d, shl_assign_rhs_i8_4d, i8, generic_array::typenum::U4, Integer::shl);
+impl_assign_op!(shl_assign_i8_5d, shl_assign_rhs_i8_5d, i8, generic_array::typenum::U5, Integer::shl);
+impl_assign_op!(shl_assign_i8_6d, shl_assign_rhs_i8_6d, i8, generic_array::typenum::U6, Integer::shl);
+
+impl_assign_op!(shr_assign_i8_1d, shr_assign_rhs_i8_1d, i8, generic_array::typenum::U1, Integer::shr);
+impl_assign_op!(shr_assign_i8_2d, shr_assign_rhs_i8_2d, i8, generic_array::typenum::U2, Integer::shr);
+impl_assign_op!(shr_assign_i8_3d, shr_assign_rhs_i8_3d, i8, generic_array::typenum::U3, Integer::shr);
+impl_assign_op!(shr_assign_i8_4d, shr_assign_rhs_i8_4d, i8, generic_array::typenum::U4, Integer::shr);
+impl_assign_op!(shr_assign_i8_5d, shr_assign_rhs_i8_5d, i8, generic_array::typenum::U5, Integer::shr);
+impl_assign_op!(shr_assign_i8_6d, shr_assign_rhs_i8_6d, i8, generic_array::typenum::U6, Integer::shr);
+
+impl_assign_op!(add_assign_i16_1d, add_assign_rhs_i16_1d, i16, generic_array::typenum::U1, Arith::add);
+impl_assign_op!(add_assign_i16_2d, add_assign_rhs_i16_2d, i16, generic_array::typenum::U2, Arith::add);
+impl_assign_op!(add_assign_i16_3d, add_assign_rhs_i16_3d, i16, generic_array::typenum::U3, Arith::add);
+impl_assign_op!(add_assign_i16_4d, add_assign_rhs_i16_4d, i16, generic_array::typenum::U4, Arith::add);
+impl_assign_op!(add_assign_i16_5d, add_assign_rhs_i16_5d, i16, generic_array::typenum::U5, Arith::add);
+impl_assign_op!(add_assign_i16_6d, add_assign_rhs_i16_6d, i16, generic_array::typenum::U6, Arith::add);
+
+impl_assign_op!(sub_assign_i16_1d, sub_assign_rhs_i16_1d, i16, generic_array::typenum::U1, Arith::sub);
+impl_assign_op!(sub_assign_i16_2d, sub_assign_rhs_i16_2d, i16, generic_array::typenum::U2, Arith::sub);
+impl_assign_op!(sub_assign_i16_3d, sub_assign_rhs_i16_3d, i16, generic_array::typenum::U3, Arith::sub);
+impl_assign_op!(sub_assign_i16_4d, sub_assign_rhs_i16_4d, i16, generic_array::typenum::U4, Arith::sub);
+impl_assign_op!(sub_assign_i16_5d, sub_assign_rhs_i16_5d, i16, generic_array::typenum::U5, Arith::sub);
+impl_assign_op!(sub_assign_i16_6d, sub_assign_rhs_i16_6d, i16, generic_array::typenum::U6, Arith::sub);
+
+impl_assign_op!(mul_assign_i16_1d, mul_assign_rhs_i16_1d, i16, generic_array::typenum::U1, Arith::mul);
+impl_assign_op!(mul_assign_i16_2d, mul_assign_rhs_i16_2d, i16, generic_array::typenum::U2, Arith::mul);
+impl_assign_op!(mul_assign_i16_3d, mul_assign_rhs_i16_3d, i16, generic_array::typenum::U3, Arith::mul);
+impl_assign_op!(mul_assign_i16_4d, mul_assign_rhs_i16_4d, i16, generic_array::typenum::U4, Arith::mul);
+impl_assign_op!(mul_assign_i16_5d, mul_assign_rhs_i16_5d, i16, generic_array::typenum::U5, Arith::mul);
+impl_assign_op!(mul_assign_i16_6d, mul_assign_rhs_i16_6d, i16, generic_array::typenum::U6, Arith::mul);
+
+impl_assign_op!(div_assign_i16_1d, div_assign_rhs_i16_1d, i16, generic_array::typenum::U1, Arith::div);
+impl_assign_op!(div_assign_i16_2d, div_assign_rhs_i16_2d, i16, generic_array::typenum::U2, Arith::div);
+impl_assign_op!(div_assign_i16_3d, div_assign_rhs_i16_3d, i16, generic_array::typenum::U3, Arith::div);
+impl_assign_op!(div_assign_i16_4d, div_assign_rhs_i16_4d, i16, generic_array::typenum::U4, Arith::div);
+impl_assign_op!(div_assign_i16_5d, div_assign_rhs_i16_5d, i16, generic_array::typenum::U5, Arith::div);
+impl_assign_op!(div_assign_i16_6d, div_assign_rhs_i16_6d, i16, generic_array::typenum::U6, Arith::div);
+
+impl_assign_op!(rem_assign_i16_1d, rem_assign_rhs_i16_1d, i16, generic_array::typenum::U1, Integer::rem);
+impl_assign_op!(rem_assign_i16_2d, rem_assign_rhs_i16_2d, i16, generic_array::typenum::U2, Integer::rem);
+impl_assign_op!(rem_assign_i16_3d, rem_assign_rhs_i16_3d, i16, generic_array::typenum::U3, Integer::rem);
+impl_assign_op!(rem_assign_i16_4d, rem_assign_rhs_i16_4d, i16, generic_array::typenum::U4, Integer::rem);
+impl_assign_op!(rem_assign_i16_5d, rem_assign_rhs_i16_5d, i16, generic_array::typenum::U5, Integer::rem);
+impl_assign_op!(rem_assign_i16_6d, rem_assign_rhs_i16_6d, i16, generic_array::typenum::U6, Integer::rem);
+
+impl_assign_op!(and_assign_i16_1d, and_assign_rhs_i16_1d, i16, generic_array::typenum::U1, |a, b| a & b);
+impl_assign_op!(and_assign_i16_2d, and_assign_rhs_i16_2d, i16, generic_array::typenum::U2, |a, b| a & b);
+impl_assign_op!(and_assign_i16_3d, and_assign_rhs_i16_3d, i16, generic_array::typenum::U3, |a, b| a & b);
+impl_assign_op!(and_assign_i16_4d, and_assign_rhs_i16_4d, i16, generic_array::typenum::U4, |a, b| a & b);
+impl_assign_op!(and_assign_i16_5d, and_assign_rhs_i16_5d, i16, generic_array::typenum::U5, |a, b| a & b);
+impl_assign_op!(and_assign_i16_6d, and_assign_rhs_i16_6d, i16, generic_array::typenum::U6, |a, b| a & b);
+
+impl_assign_op!(or_assign_i16_1d, or_assign_rhs_i16_1d, i16, generic_array::typenum::U1, |a, b| a | b);
+impl_assign_op!(or_assign_i16_2d, or_assign_rhs_i16_2d, i16, generic_array::typenum::U2, |a, b| a | b);
+impl_assign_op!(or_assign_i16_3d, or_assign_rhs_i16_3d, i16, generic_array::typenum::U3, |a, b| a | b);
+impl_assign_op!(or_assign_i16_4d, or_assign_rhs_i16_4d, i16, generic_array::typenum::U4, |a, b| a | b);
+impl_assign_op!(or_assign_i16_5d, or_assign_rhs_i16_5d, i16, generic_array::typenum::U5, |a, b| a | b);
+impl_assign_op!(or_assign_i16_6d, or_assign_rhs_i16_6d, i16, generic_array::typenum::U6, |a, b| a | b);
+
+impl_assign_op!(xor_assign_i16_1d, xor_assign_rhs_i16_1d, i16, generic_array::typenum::U1, |a, b| a ^ b);
+impl_assign_op!(xor_assign_i16_2d, xor_assign_rhs_i16_2d, i16, generic_array::typenum::U2, |a, b| a ^ b);
+impl_assign_op!(xor_assign_i16_3d, xor_assign_rhs_i16_3d, i16, generic_array::typenum::U3, |a, b| a ^ b);
+impl_assign_op!(xor_assign_i16_4d, xor_assign_rhs_i16_4d, i16, generic_array::typenum::U4, |a, b| a ^ b);
+impl_assign_op!(xor_assign_i16_5d, xor_assign_rhs_i16_5d, i16, generic_array::typenum::U5, |a, b| a ^ b);
+impl_assign_op!(xor_assign_i16_6d, xor_assign_rhs_i16_6d, i16, generic_array::typenum::U6, |a, b| a ^ b);
+
+impl_assign_op!(shl_assign_i16_1d, shl_assign_rhs_i16_1d, i16, generic_array::typenum::U1, Integer::shl);
+impl_assign_op!(shl_assign_i16_2d, shl_assign_rhs_i16_2d, i16, generic_array::typenum::U2, Integer::shl);
+impl_assign_op!(shl_assign_i16_3d, shl_assign_rhs_i16_3d, i16, generic_array::typenum::U3, Integer::shl);
+impl_assign_op!(shl_assign_i16_4d, shl_assign_rhs_i16_4d, i16, generic_array::typenum::U4, Integer::shl);
+impl_assign_op!(shl_assign_i16_5d, shl_assign_rhs_i16_5d, i16, generic_array::typenum::U5, Integer::shl);
+impl_assign_op!(shl_assign_i16_6d, shl_assign_rhs_i16_6d, i16, generic_array::typenum::U6, Integer::shl);
+
+impl_assign_op!(shr_assign_i16_1d, shr_assign_rhs_i16_1d, i16, generic_array::typenum::U1, Integer::shr);
+impl_assign_op!(shr_assign_i16_2d, shr_assign_rhs_i16_2d, i16, generic_array::typenum::U2, Integer::shr);
+impl_assign_op!(shr_assign_i16_3d, shr_assign_rhs_i16_3d, i16, generic_array::typenum::U3, Integer::shr);
+impl_assign_op!(shr_assign_i16_4d, shr_assign_rhs_i16_4d, i16, generic_array::typenum::U4, Integer::shr);
+impl_assign_op!(shr_assign_i16_5d, shr_assign_rhs_i16_5d, i16, generic_array::typenum::U5, Integer::shr);
+impl_assign_op!(shr_assign_i16_6d, shr_assign_rhs_i16_6d, i16, generic_array::typenum::U6, Integer::shr);
+
+impl_assign_op!(add_assign_i32_1d, add_assign_rhs_i32_1d, i32, generic_array::typenum::U1, Arith::add);
+impl_assign_op!(add_assign_i32_2d, add_assign_rhs_i32_2d, i32, generic_array::typenum::U2, Arith::add);
+impl_assign_op!(add_assign_i32_3d, add_assign_rhs_i32_3d, i32, generic_array::typenum::U3, Arith::add);
+impl_assign_op!(add_assign_i32_4d, add_assign_rhs_i32_4d, i32, generic_array::typenum::U4, Arith::add);
+impl_assign_op!(add_assign_i32_5d, add_assign_rhs_i32_5d, i32, generic_array::typenum::U5, Arith::add);
+impl_assign_op!(add_assign_i32_6d, add_assign_rhs_i32_6d, i32, generic_array::typenum::U6, Arith::add);
+
+impl_assign_op!(sub_assign_i32_1d, sub_assign_rhs_i32_1d, i32, generic_array::typenum::U1, Arith::sub);
+impl_assign_op!(sub_assign_i32_2d, sub_assign_rhs_i32_2d, i32, generic_array::typenum::U2, Arith::sub);
+impl_assign_op!(sub_assign_i32_3d, sub_assign_rhs_i32_3d, i32, generic_array::typenum::U3, Arith::sub);
+impl_assign_op!(sub_assign_i32_4d, sub_assign_rhs_i32_4d, i32, generic_array::typenum::U4, Arith::sub);
+impl_assign_op!(sub_assign_i32_5d, sub_assign_rhs_i32_5d, i32, generic_array::typenum::U5, Arith::sub);
+impl_assign_op!(sub_assign_i32_6d, sub_assign_rhs_i32_6d, i32, generic_array::typenum::U6, Arith::sub);
+
+impl_assign_op!(mul_assign_i32_1d, mul_assign_rhs_i32_1d, i32, generic_array::typenum::U1, Arith::mul);
+impl_assign_op!(mul_assign_i32_2d, mul_assign_rhs_i32_2d, i32, generic_array::typenum::U2, Arith::mul);
+impl_assign_op!(mul_assign_i32_3d, mul_assign_rhs_i32_3d, i32, generic_array::typenum::U3, Arith::mul);
+impl_assign_op!(mul_assign_i32_4d, mul_assign_rhs_i32_4d, i32, generic_array::typenum::U4, Arith::mul);
+impl_assign_op!(mul_assign_i32_5d, mul_assign_rhs_i32_5d, i32, generic_array::typenum::U5, Arith::mul);
+impl_assign_op!(mul_assign_i32_6d, mul_assign_rhs_i32_6d, i32, generic_array::typenum::U6, Arith::mul);
+
+impl_assign_op!(div_assign_i32_1d, div_assign_rhs_i32_1d, i32, generic_array::typenum::U1, Arith::div);
+impl_assign_op!(div_assign_i32_2d, div_assign_rhs_i32_2d, i32, generic_array::typenum::U2, Arith::div);
+impl_assign_op!(div_assign_i32_3d, div_assign_rhs_i32_3d, i32, generic_array::typenum::U3, Arith::div);
+impl_assign_op!(div_assign_i32_4d, div_assign_rhs_i32_4d, i32, generic_array::typenum::U4, Arith::div);
+impl_assign_op!(div_assign_i32_5d, div_assign_rhs_i32_5d, i32, generic_array::typenum::U5, Arith::div);
+impl_assign_op!(div_assign_i32_6d, div_assign_rhs_i32_6d, i32, generic_array::typenum::U6, Arith::div);
+
+impl_assign_op!(rem_assign_i32_1d, rem_assign_rhs_i32_1d, i32, generic_array::typenum::U1, Integer::rem);
+impl_assign_op!(rem_assign_i32_2d, rem_assign_rhs_i32_2d, i32, generic_array::typenum::U2, Integer::rem);
+impl_assign_op!(rem_assign_i32_3d, rem_assign_rhs_i32_3d, i32, generic_array::typenum::U3, Integer::rem);
+impl_assign_op!(rem_assign_i32_4d, rem_assign_rhs_i32_4d, i32, generic_array::typenum::U4, Integer::rem);
+impl_assign_op!(rem_assign_i32_5d, rem_assign_rhs_i32_5d, i32, generic_array::typenum::U5, Integer::rem);
+impl_assign_op!(rem_assign_i32_6d, rem_assign_rhs_i32_6d, i32, generic_array::typenum::U6, Integer::rem);
+
+impl_assign_op!(and_assign_i32_1d, and_assign_rhs_i32_1d, i32, generic_array::typenum::U1, |a, b| a & b);
+impl_assign_op!(and_assign_i32_2d, and_assign_rhs_i32_2d, i32, generic_array::typenum::U2, |a, b| a & b);
+impl_assign_op!(and_assign_i32_3d, and_assign_rhs_i32_3d, i32, generic_array::typenum::U3, |a, b| a & b);
+impl_assign_op!(and_assign_i32_4d, and_assign_rhs_i32_4d, i32, generic_array::typenum::U4, |a, b| a & b);
+impl_assign_op!(and_assign_i32_5d, and_assign_rhs_i32_5d, i32, generic_array::typenum::U5, |a, b| a & b);
+impl_assign_op!(and_assign_i32_6d, and_assign_rhs_i32_6d, i32, generic_array::typenum::U6, |a, b| a & b);
+
+impl_assign_op!(or_assign_i32_1d, or_assign_rhs_i32_1d, i32, generic_array::typenum::U1, |a, b| a | b);
+impl_assign_op!(or_assign_i32_2d, or_assign_rhs_i32_2d, i32, generic_array::typenum::U2, |a, b| a | b);
+impl_assign_op!(or_assign_i32_3d, or_assign_rhs_i32_3d, i32, generic_array::typenum::U3, |a, b| a | b);
+impl_assign_op!(or_assign_i32_4d, or_assign_rhs_i32_4d, i32, generic_array::typenum::U4, |a, b| a | b);
+impl_assign_op!(or_assign_i32_5d, or_assign_rhs_i32_5d, i32, generic_array::typenum::U5, |a, b| a | b);
+impl_assign_op!(or_assign_i32_6d, or_assign_rhs_i32_6d, i32, generic_array::typenum::U6, |a, b| a | b);
+
+impl_assign_op!(xor_assign_i32_1d, xor_assign_rhs_i32_1d, i32, generic_array::typenum::U1, |a, b| a ^ b);
+impl_assign_op!(xor_assign_i32_2d, xor_assign_rhs_i32_2d, i32, generic_array::typenum::U2, |a, b| a ^ b);
+impl_assign_op!(xor_assign_i32_3d, xor_assign_rhs_i32_3d, i32, generic_array::typenum::U3, |a, b| a ^ b);
+impl_assign_op!(xor_assign_i32_4d, xor_assign_rhs_i32_4d, i32, generic_array::typenum::U4, |a, b| a ^ b);
+impl_assign_op!(xor_assign_i32_5d, xor_assign_rhs_i32_5d, i32, generic_array::typenum::U5, |a, b| a ^ b);
+impl_assign_op!(xor_assign_i32_6d, xor_assign_rhs_i32_6d, i32, generic_array::typenum::U6, |a, b| a ^ b);
+
+impl_assign_op!(shl_assign_i32_1d, shl_assign_rhs_i32_1d, i32, generic_array::typenum::U1, Integer::shl);
+impl_assign_op!(shl_assign_i32_2d, shl_assign_rhs_i32_2d, i32, generic_array::typenum::U2, Integer::shl);
+impl_assign_op!(shl_assign_i32_3d, shl_assign_rhs_i32_3d, i32, generic_array::typenum::U3, Integer::shl);
+impl_assign_op!(shl_assign_i32_4d, shl_assign_rhs_i32_4d, i32, generic_array::typenum::U4, Integer::shl);
+impl_assign_op!(shl_assign_i32_5d, shl_assign_rhs_i32_5d, i32, generic_array::typenum::U5, Integer::shl);
+impl_assign_op!(shl_assign_i32_6d, shl_assign_rhs_i32_6d, i32, generic_array::typenum::U6, Integer::shl);
+
+impl_assign_op!(shr_assign_i32_1d, shr_assign_rhs_i32_1d, i32, generic_array::typenum::U1, Integer::shr);
+impl_assign_op!(shr_assign_i32_2d, shr_assign_rhs_i32_2d, i32, generic_array::typenum::U2, Integer::shr);
+impl_assign_op!(shr_assign_i32_3d, shr_assign_rhs_i32_3d, i32, generic_array::typenum::U3, Integer::shr);
+impl_assign_op!(shr_assign_i32_4d, shr_assign_rhs_i32_4d, i32, generic_array::typenum::U4, Integer::shr);
+impl_assign_op!(shr_assign_i32_5d, shr_assign_rhs_i32_5d, i32, generic_array::typenum::U5, Integer::shr);
+impl_assign_op!(shr_assign_i32_6d, shr_assign_rhs_i32_6d, i32, generic_array::typenum::U6, Integer::shr);
+
+impl_assign_op!(add_assign_i64_1d, add_assign_rhs_i64_1d, i64, generic_array::typenum::U1, Arith::add);
+impl_assign_op!(add_assign_i64_2d, add_assign_rhs_i64_2d, i64, generic_array::typenum::U2, Arith::add);
+impl_assign_op!(add_assign_i64_3d, add_assign_rhs_i64_3d, i64, generic_array::typenum::U3, Arith::add);
+impl_assign_op!(add_assign_i64_4d, add_assign_rhs_i64_4d, i64, generic_array::typenum::U4, Arith::add);
+impl_assign_op!(add_assign_i64_5d, add_assign_rhs_i64_5d, i64, generic_array::typenum::U5, Arith::add);
+impl_assign_op!(add_assign_i64_6d, add_assign_rhs_i64_6d, i64, generic_array::typenum::U6, Arith::add);
+
+impl_assign_op!(sub_assign_i64_1d, sub_assign_rhs_i64_1d, i64, generic_array::typenum::U1, Arith::sub);
+impl_assign_op!(sub_assign_i64_2d, sub_assign_rhs_i64_2d, i64, generic_array::typenum::U2, Arith::sub);
+impl_assign_op!(sub_assign_i64_3d, sub_assign_rhs_i64_3d, i64, generic_array::typenum::U3, Arith::sub);
+impl_assign_op!(sub_assign_i64_4d, sub_assign_rhs_i64_4d, i64, generic_array::typenum::U4, Arith::sub);
+impl_assign_op!(sub_assign_i64_5d, sub_assign_rhs_i64_5d, i64, generic_array::typenum::U5, Arith::sub);
+impl_assign_op!(sub_assign_i64_6d, sub_assign_rhs_i64_6d, i64, generic_array::typenum::U6, Arith::sub);
+
+impl_assign_op!(mul_assign_i64_1d, mul_assign_rhs_i64_1d, i64, generic_array::typenum::U1, Arith::mul);
+impl_assign_op!(mul_assign_i64_2d, mul_assign_rhs_i64_2d, i64, generic_array::typenum::U2, Arith::mul);
+impl_assign_op!(mul_assign_i64_3d, mul_assign_rhs_i64_3d, i64, generic_array::typenum::U3, Arith::mul);
+impl_assign_op!(mul_assign_i64_4d, mul_assign_rhs_i64_4d, i64, generic_array::typenum::U4, Arith::mul);
+impl_assign_op!(mul_assign_i64_5d, mul_assign_rhs_i64_5d, i64, generic_array::typenum::U5, Arith::mul);
+impl_assign_op!(mul_assign_i64_6d, mul_assign_rhs_i64_6d, i64, generic_array::typenum::U6, Arith::mul);
+
+impl_assign_op!(div_assign_i64_1d, div_assign_rhs_i64_1d, i64, generic_array::typenum::U1, Arith::div);
+impl_assign_op!(div_assign_i64_2d, div_assign_rhs_i64_2d, i64, generic_array::typenum::U2, Arith::div);
+impl_assign_op!(div_assign_i64_3d, div_assign_rhs_i64_3d, i64, generic_array::typenum::U3, Arith::div);
+impl_assign_op!(div_assign_i64_4d, div_assign_rhs_i64_4d, i64, generic_array::typenum::U4, Arith::div);
+impl_assign_op!(div_assign_i64_5d, div_assign_rhs_i64_5d, i64, generic_array::typenum::U5, Arith::div);
+impl_assign_op!(div_assign_i64_6d, div_assign_rhs_i64_6d, i64, generic_array::typenum::U6, Arith::div);
+
+impl_assign_op!(rem_assign_i64_1d, rem_assign_rhs_i64_1d, i64, generic_array::typenum::U1, Integer::rem);
+impl_assign_op!(rem_assign_i64_2d, rem_assign_rhs_i64_2d, i64, generic_array::typenum::U2, Integer::rem);
+impl_assign_op!(rem_assign_i64_3d, rem_assign_rhs_i64_3d, i64, generic_array::typenum::U3, Integer::rem);
+impl_assign_op!(rem_assign_i64_4d, rem_assign_rhs_i64_4d, i64, generic_array::typenum::U4, Integer::rem);
+impl_assign_op!(rem_assign_i64_5d, rem_assign_rhs_i64_5d, i64, generic_array::typenum::U5, Integer::rem);
+impl_assign_op!(rem_assign_i64_6d, rem_assign_rhs_i64_6d, i64, generic_array::typenum::U6, Integer::rem);
+
+impl_assign_op!(and_assign_i64_1d, and_assign_rhs_i64_1d, i64, generic_array::typenum::U1, |a, b| a & b);
+impl_assign_op!(and_assign_i64_2d, and_assign_rhs_i64_2d, i64, generic_array::typenum::U2, |a, b| a & b);
+impl_assign_op!(and_assign_i64_3d, and_assign_rhs_i64_3d, i64, generic_array::typenum::U3, |a, b| a & b);
+impl_assign_op!(and_assign_i64_4d, and_assign_rhs_i64_4d, i64, generic_array::typenum::U4, |a, b| a & b);
+impl_assign_op!(and_assign_i64_5d, and_assign_rhs_i64_5d, i64, generic_array::typenum::U5, |a, b| a & b);
+impl_assign_op!(and_assign_i64_6d, and_assign_rhs_i64_6d, i64, generic_array::typenum::U6, |a, b| a & b);
+
+impl_assign_op!(or_assign_i64_1d, or_assign_rhs_i64_1d, i64, generic_array::typenum::U1, |a, b| a | b);
+impl_assign_op!(or_assign_i64_2d, or_assign_rhs_i64_2d, i64, generic_array::typenum::U2, |a, b| a | b);
+impl_assign_op!(or_assign_i64_3d, or_assign_rhs_i64_3d, i64, generic_array::typenum::U3, |a, b| a | b);
+impl_assign_op!(or_assign_i64_4d, or_assign_rhs_i64_4d, i64, generic_array::typenum::U4, |a, b| a | b);
+impl_assign_op!(or_assign_i64_5d, or_assign_rhs_i64_5d, i64, generic_array::typenum::U5, |a, b| a | b);
+impl_assign_op!(or_assign_i64_6d, or_assign_rhs_i64_6d, i64, generic_array::typenum::U6, |a, b| a | b);
+
+impl_assign_op!(xor_assign_i64_1d, xor_assign_rhs_i64_1d, i64, generic_array::typenum::U1, |a, b| a ^ b);
+impl_assign_op!(xor_assign_i64_2d, xor_assign_rhs_i64_2d, i64, generic_array::typenum::U2, |a, b| a ^ b);
+impl_assign_op!(xor_assign_i64_3d, xor_assign_rhs_i64_3d, i64, generic_array::typenum::U3, |a, b| a ^ b);
+impl_assign_op!(xor_assign_i64_4d, xor_assign_rhs_i64_4d, i64, generic_array::typenum::U4, |a, b| a ^ b);
+impl_assign_op!(xor_assign_i64_5d, xor_assign_rhs_i64_5d, i64, generic_array::typenum::U5, |a, b| a ^ b);
+impl_assign_op!(xor_assign_i64_6d, xor_assign_rhs_i64_6d, i64, generic_array::typenum::U6, |a, b| a ^ b);
+
+impl_assign_op!(shl_assign_i64_1d, shl_assign_rhs_i64_1d, i64, generic_array::typenum::U1, Integer::shl);
+impl_assign_op!(shl_assign_i64_2d, shl_assign_rhs_i64_2d, i64, generic_array::typenum::U2, Integer::shl);
+impl_assign_op!(shl_assign_i64_3d, shl_assign_rhs_i64_3d, i64, generic_array::typenum::U3, Integer::shl);
+impl_assign_op!(shl_assign_i64_4d, shl_assign_rhs_i64_4d, i64, generic_array::typenum::U4, Integer::shl);
+impl_assign_op!(shl_assign_i64_5d, shl_assign_rhs_i64_5d, i64, generic_array::typenum::U5, Integer::shl);
+impl_assign_op!(shl_assign_i64_6d, shl_assign_rhs_i64_6d, i64, generic_array::typenum::U6, Integer::shl);
+
+impl_assign_op!(shr_assign_i64_1d, shr_assign_rhs_i64_1d, i64, generic_array::typenum::U1, Integer::shr);
+impl_assign_op!(shr_assign_i64_2d, shr_assign_rhs_i64_2d, i64, generic_array::typenum::U2, Integer::shr);
+impl_assign_op!(shr_assign_i64_3d, shr_assign_rhs_i64_3d, i64, generic_array::typenum::U3, Integer::shr);
+impl_assign_op!(shr_assign_i64_4d, shr_assign_rhs_i64_4d, i64, generic_array::typenum::U4, Integer::shr);
+impl_assign_op!(shr_assign_i64_5d, shr_assign_rhs_i64_5d, i64, generic_array::typenum::U5, Integer::shr);
+impl_assign_op!(shr_assign_i64_6d, shr_assign_rhs_i64_6d, i64, generic_array::typenum::U6, Integer::shr);
+
+impl_assign_op!(add_assign_u8_1d, add_assign_rhs_u8_1d, u8, generic_array::typenum::U1, Arith::add);
+impl_assign_op!(add_assign_u8_2d, add_assign_rhs_u8_2d, u8, generic_array::typenum::U2, Arith::add);
+impl_assign_op!(add_assign_u8_3d, add_assign_rhs_u8_3d, u8, generic_array::typenum::U3, Arith::add);
+impl_assign_op!(add_assign_u8_4d, add_assign_rhs_u8_4d, u8, generic_array::typenum::U4, Arith::add);
+impl_assign_op!(add_assign_u8_5d, add_assign_rhs_u8_5d, u8, generic_array::typenum::U5, Arith::add);
+impl_assign_op!(add_assign_u8_6d, add_assign_rhs_u8_6d, u8, generic_array::typenum::U6, Arith::add);
+
+impl_assign_op!(sub_assign_u8_1d, sub_assign_rhs_u8_1d, u8, generic_array::typenum::U1, Arith::sub);
+impl_assign_op!(sub_assign_u8_2d, sub_assign_rhs_u8_2d, u8, generic_array::typenum::U2, Arith::sub);
+impl_assign_op!(sub_assign_u8_3d, sub_assign_rhs_u8_3d, u8, generic_array::typenum::U3, Arith::sub);
+impl_assign_op!(sub_assign_u8_4d, sub_assign_rhs_u8_4d, u8, generic_array::typenum::U4, Arith::sub);
+impl_assign_op!(sub_assign_u8_5d, sub_assign_rhs_u8_5d, u8, generic_array::typenum::U5, Arith::sub);
+impl_assign_op!(sub_assign_u8_6d, sub_assign_rhs_u8_6d, u8, generic_array::typenum::U6, Arith::sub);
+
+impl_assign_op!(mul_assign_u8_1d, mul_assign_rhs_u8_1d, u8, generic_array::typenum::U1, Arith::mul);
+impl_assign_op!(mul_assign_u8_2d, mul_assign_rhs_u8_2d, u8, generic_array::typenum::U2, Arith::mul);
+impl_assign_op!(mul_assign_u8_3d, mul_assign_rhs_u8_3d, u8, generic_array::typenum::U3, Arith::mul);
+impl_assign_op!(mul_assign_u8_4d, mul_assign_rhs_u8_4d, u8, generic_array::typenum::U4, Arith::mul);
+impl_assign_op!(mul_assign_u8_5d, mul_assign_rhs_u8_5d, u8, generic_array::typenum::U5, Arith::mul);
+impl_assign_op!(mul_assign_u8_6d, mul_assign_rhs_u8_6d, u8, generic_array::typenum::U6, Arith::mul);
+
+impl_assign_op!(div_assign_u8_1d, div_assign_rhs_u8_1d, u8, generic_array::typenum::U1, Arith::div);
+impl_assign_op!(div_assign_u8_2d, div_assign_rhs_u8_2d, u8, generic_array::typenum::U2, Arith::div);
+impl_assign_op!(div_assign_u8_3d, div_assign_rhs_u8_3d, u8, generic_array::typenum::U3, Arith::div);
+impl_assign_op!(div_assign_u8_4d, div_assign_rhs_u8_4d, u8, generic_array::typenum::U4, Arith::div);
+impl_assign_op!(div_assign_u8_5d, div_assign_rhs_u8_5d, u8, generic_array::typenum::U5, Arith::div);
+impl_assign_op!(div_assign_u8_6d, div_assign_rhs_u8_6d, u8, generic_array::typenum::U6, Arith::div);
+
+impl_assign_op!(rem_assign_u8_1d, rem_assign_rhs_u8_1d, u8, generic_array::typenum::U1, Integer::rem);
+impl_assign_op!(rem_assign_u8_2d, rem_assign_rhs_u8_2d, u8, generic_array::typenum::U2, Integer::rem);
+impl_assign_op!(rem_assign_u8_3d, rem_assign_rhs_u8_3d, u8, generic_array::typenum::U3, Integer::rem);
+impl_assign_op!(rem_assign_u8_4d, rem_assign_rhs_u8_4d, u8, generic_array::typenum::U4, Integer::rem);
+impl_assign_op!(rem_assign_u8_5d, rem_assign_rhs_u8_5d, u8, generic_array::typenum::U5, Integer::rem);
+impl_assign_op!(rem_assign_u8_6d, rem_assign_rhs_u8_6d, u8, generic_array::typenum::U6, Integer::rem);
+
+impl_assign_op!(and_assign_u8_1d, and_assign_rhs_u8_1d, u8, generic_array::typenum::U1, |a, b| a & b);
+impl_assign_op!(and_assign_u8_2d, and_assign_rhs_u8_2d, u8, generic_array::typenum::U2, |a, b| a & b);
+impl_assign_op!(and_assign_u8_3d, and_assign_rhs_u8_3d, u8, generic_array::typenum::U3, |a, b| a & b);
+impl_assign_op!(and_assign_u8_4d, and_assign_rhs_u8_4d, u8, generic_array::typenum::U4, |a, b| a & b);
+impl_assign_op!(and_assign_u8_5d, and_assign_rhs_u8_5d, u8, generic_array::typenum::U5, |a, b| a & b);
+impl_assign_op!(and_assign_u8_6d, and_assign_rhs_u8_6d, u8, generic_array::typenum::U6, |a, b| a & b);
+
+impl_assign_op!(or_assign_u8_1d, or_assign_rhs_u8_1d, u8, generic_array::typenum::U1, |a, b| a | b);
+impl_assign_op!(or_assign_u8_2d, or_assign_rhs_u8_2d, u8, generic_array::typenum::U2, |a, b| a | b);
+impl_assign_op!(or_assign_u8_3d, or_assign_rhs_u8_3d, u8, generic_array::typenum::U3, |a, b| a | b);
+impl_assign_op!(or_assign_u8_4d, or_assign_rhs_u8_4d, u8, generic_array::typenum::U4, |a, b| a | b);
+impl_assign_op!(or_assign_u8_5d, or_assign_rhs_u8_5d, u8, generic_array::typenum::U5, |a, b| a | b);
+impl_assign_op!(or_assign_u8_6d, or_assign_rhs_u8_6d, u8, generic_array::typenum::U6, |a, b| a | b);
+
+impl_assign_op!(xor_assign_u8_1d, xor_assign_rhs_u8_1d, u8, generic_array::typenum::U1, |a, b| a ^ b);
+impl_assign_op!(xor_assign_u8_2d, xor_assign_rhs_u8_2d, u8, generic_array::typenum::U2, |a, b| a ^ b);
+impl_assign_op!(xor_assign_u8_3d, xor_assign_rhs_u8_3d, u8, generic_array::typenum::U3, |a, b| a ^ b);
+impl_assign_op!(xor_assign_u8_4d, xor_assign_rhs_u8_4d, u8, generic_array::typenum::U4, |a, b| a ^ b);
+impl_assign_op!(xor_assign_u8_5d, xor_assign_rhs_u8_5d, u8, generic_array::typenum::U5, |a, b| a ^ b);
+impl_assign_op!(xor_assign_u8_6d, xor_assign_rhs_u8_6d, u8, generic_array::typenum::U6, |a, b| a ^ b);
+
+impl_assign_op!(shl_assign_u8_1d, shl_assign_rhs_u8_1d, u8, generic_array::typenum::U1, Integer::shl);
+impl_assign_op!(shl_assign_u8_2d, shl_assign_rhs_u8_2d, u8, generic_array::typenum::U2, Integer::shl);
+impl_assign_op!(shl_assign_u8_3d, shl_assign_rhs_u8_3d, u8, generic_array::typenum::U3, Integer::shl);
+impl_assign_op!(shl_assign_u8_4d, shl_assign_rhs_u8_4d, u8, generic_array::typenum::U4, Integer::shl);
+impl_assign_op!(shl_assign_u8_5d, shl_assign_rhs_u8_5d, u8, generic_array::typenum::U5, Integer::shl);
+impl_assign_op!(shl_assign_u8_6d, shl_assign_rhs_u8_6d, u8, generic_array::typenum::U6, Integer::shl);
+
+impl_assign_op!(shr_assign_u8_1d, shr_assign_rhs_u8_1d, u8, generic_array::typenum::U1, Integer::shr);
+impl_assign_op!(shr_assign_u8_2d, shr_assign_rhs_u8_2d, u8, generic_array::typenum::U2, Integer::shr);
+impl_assign_op!(shr_assign_u8_3d, shr_assign_rhs_u8_3d, u8, generic_array::typenum::U3, Integer::shr);
+impl_assign_op!(shr_assign_u8_4d, shr_assign_rhs_u8_4d, u8, generic_array::typenum::U4, Integer::shr);
+impl_assign_op!(shr_assign_u8_5d, shr_assign_rhs_u8_5d, u8, generic_array::typenum::U5, Integer::shr);
+impl_assign_op!(shr_assign_u8_6d, shr_assign_rhs_u8_6d, u8, generic_array::typenum::U6, Integer::shr);
+
+impl_assign_op!(add_assign_u32_1d, add_assign_rhs_u32_1d, u32, generic_array::typenum::U1, Arith::add);
+impl_assign_op!(add_assign_u32_2d, add_assign_rhs_u32_2d, u32, generic_array::typenum::U2, Arith::add);
+impl_assign_op!(add_assign_u32_3d, add_assign_rhs_u32_3d, u32, generic_array::typenum::U3, Arith::add);
+impl_assign_op!(add_assign_u32_4d, add_assign_rhs_u32_4d, u32, generic_array::typenum::U4, Arith::add);
+impl_assign_op!(add_assign_u32_5d, add_assign_rhs_u32_5d, u32, generic_array::typenum::U5, Arith::add);
+impl_assign_op!(add_assign_u32_6d, add_assign_rhs_u32_6d, u32, generic_array::typenum::U6, Arith::add);
+
+impl_assign_op!(sub_assign_u32_1d, sub_assign_rhs_u32_1d, u32, generic_array::typenum::U1, Arith::sub);
+impl_assign_op!(sub_assign_u32_2d, sub_assign_rhs_u32_2d, u32, generic_array::typenum::U2, Arith::sub);
+impl_assign_op!(sub_assign_u32_3d, sub_assign_rhs_u32_3d, u32, generic_array::typenum::U3, Arith::sub);
+impl_assign_op!(sub_assign_u32_4d, sub_assign_rhs_u32_4d, u32, generic_array::typenum::U4, Arith::sub);
+impl_assign_op!(sub_assign_u32_5d, sub_assign_rhs_u32_5d, u32, generic_array::typenum::U5, Arith::sub);
+impl_assign_op!(sub_assign_u32_6d, sub_assign_rhs_u32_6d, u32, generic_array::typenum::U6, Arith::sub);
+
+impl_assign_op!(mul_assign_u32_1d, mul_assign_rhs_u32_1d, u32, generic_array::typenum::U1, Arith::mul);
+impl_assign_op!(mul_assign_u32_2d, mul_assign_rhs_u32_2d, u32, generic_array::typenum::U2, Arith::mul);
+impl_assign_op!(mul_assign_u32_3d, mul_assign_rhs_u32_3d, u32, generic_array::typenum::U3, Arith::mul);
+impl_assign_op!(mul_assign_u32_4d, mul_assign_rhs_u32_4d, u32, generic_array::typenum::U4, Arith::mul);
+impl_assign_op!(mul_assign_u32_5d, mul_assign_rhs_u32_5d, u32, generic_array::typenum::U5, Arith::mul);
+impl_assign_op!(mul_assign_u32_6d, mul_assign_rhs_u32_6d, u32, generic_array::typenum::U6, Arith::mul);
+
+impl_assign_op!(div_assign_u32_1d, div_assign_rhs_u32_1d, u32, generic_array::typenum::U1, Arith::div);
+impl_assign_op!(div_assign_u32_2d, div_assign_rhs_u32_2d, u32, generic_array::typenum::U2, Arith::div);
+impl_assign_op!(div_assign_u32_3d, div_assign_rhs_u32_3d, u32, generic_array::typenum::U3, Arith::div);
+impl_assign_op!(div_assign_u32_4d, div_assign_rhs_u32_4d, u32, generic_array::typenum::U4, Arith::div);
+impl_assign_op!(div_assign_u32_5d, div_assign_rhs_u32_5d, u32, generic_array::typenum::U5, Arith::div);
+impl_assign_op!(div_assign_u32_6d, div_assign_rhs_u32_6d, u32, generic_array::typenum::U6, Arith::div);
+
+impl_assign_op!(rem_assign_u32_1d, rem_assign_rhs_u32_1d, u32, generic_array::typenum::U1, Integer::rem);
+impl_assign_op!(rem_assign_u32_2d, rem_assign_rhs_u32_2d, u32, generic_array::typenum::U2, Integer::rem);
+impl_assign_op!(rem_assign_u32_3d, rem_assign_rhs_u32_3d, u32, generic_array::typenum::U3, Integer::rem);
+impl_assign_op!(rem_assign_u32_4d, rem_assign_rhs_u32_4d, u32, generic_array::typenum::U4, Integer::rem);
+impl_assign_op!(rem_assign_u32_5d, rem_assign_rhs_u32_5d, u32, generic_array::typenum::U5, Integer::rem);
+impl_assign_op!(rem_assign_u32_6d, rem_assign_rhs_u32_6d, u32, generic_array::typenum::U6, Integer::rem);
+
+impl_assign_op!(and_assign_u32_1d, and_assign_rhs_u32_1d, u32, generic_array::typenum::U1, |a, b| a & b);
+impl_assign_op!(and_assign_u32_2d, and_assign_rhs_u32_2d, u32, generic_array::typenum::U2, |a, b| a & b);
+impl_assign_op!(and_assign_u32_3d, and_assign_rhs_u32_3d, u32, generic_array::typenum::U3, |a, b| a & b);
+impl_assign_op!(and_assign_u32_4d, and_assign_rhs_u32_4d, u32, generic_array::typenum::U4, |a, b| a & b);
+impl_assign_op!(and_assign_u32_5d, and_assign_rhs_u32_5d, u32, generic_array::typenum::U5, |a, b| a & b);
+impl_assign_op!(and_assign_u32_6d, and_assign_rhs_u32_6d, u32, generic_array::typenum::U6, |a, b| a & b);
+
+impl_assign_op!(or_assign_u32_1d, or_assign_rhs_u32_1d, u32, generic_array::typenum::U1, |a, b| a | b);
+impl_assign_op!(or_assign_u32_2d, or_assign_rhs_u32_2d, u32, generic_array::typenum::U2, |a, b| a | b);
+impl_assign_op!(or_assign_u32_3d, or_assign_rhs_u32_3d, u32, generic_array::typenum::U3, |a, b| a | b);
+impl_assign_op!(or_assign_u32_4d, or_assign_rhs_u32_4d, u32, generic_array::typenum::U4, |a, b| a | b);
+impl_assign_op!(or_assign_u32_5d, or_assign_rhs_u32_5d, u32, generic_array::typenum::U5, |a, b| a | b);
+impl_assign_op!(or_assign_u32_6d, or_assign_rhs_u32_6d, u32, generic_array::typenum::U6, |a, b| a | b);
+
+impl_assign_op!(xor_assign_u32_1d, xor_assign_rhs_u32_1d, u32, generic_array::typenum::U1, |a, b| a ^ b);
+impl_assign_op!(xor_assign_u32_2d, xor_assign_rhs_u32_2d, u32, generic_array::typenum::U2, |a, b| a ^ b);
+impl_assign_op!(xor_assign_u32_3d, xor_assign_rhs_u32_3d, u32, generic_array::typenum::U3, |a, b| a ^ b);
+impl_assign_op!(xor_assign_u32_4d, xor_assign_rhs_u32_4d, u32, generic_array::typenum::U4, |a, b| a ^ b);
+impl_assign_op!(xor_assign_u32_5d, xor_assign_rhs_u32_5d, u32, generic_array::typenum::U5, |a, b| a ^ b);
+impl_assign_op!(xor_assign_u32_6d, xor_assign_rhs_u32_6d, u32, generic_array::typenum::U6, |a, b| a ^ b);
+
+impl_assign_op!(shl_assign_u32_1d, shl_assign_rhs_u32_1d, u32, generic_array::typenum::U1, Integer::shl);
+impl_assign_op!(shl_assign_u32_2d, shl_assign_rhs_u32_2d, u32, generic_array::typenum::U2, Integer::shl);
+impl_assign_op!(shl_assign_u32_3d, shl_assign_rhs_u32_3d, u32, generic_array::typenum::U3, Integer::shl);
+impl_assign_op!(shl_assign_u32_4d, shl_assign_rhs_u32_4d, u32, generic_array::typenum::U4, Integer::shl);
+impl_assign_op!(shl_assign_u32_5d, shl_assign_rhs_u32_5d, u32, generic_array::typenum::U5, Integer::shl);
+impl_assign_op!(shl_assign_u32_6d, shl_assign_rhs_u32_6d, u32, generic_array::typenum::U6, Integer::shl);
+
+impl_assign_op!(shr_assign_u32_1d, shr_assign_rhs_u32_1d, u32, generic_array::typenum::U1, Integer::shr);
+impl_assign_op!(shr_assign_u32_2d, shr_assign_rhs_u32_2d, u32, generic_array::typenum::U2, Integer::shr);
+impl_assign_op!(shr_assign_u32_3d, shr_assign_rhs_u32_3d, u32, generic_array::typenum::U3, Integer::shr);
+impl_assign_op!(shr_assign_u32_4d, shr_assign_rhs_u32_4d, u32, generic_array::typenum::U4, Integer::shr);
+impl_assign_op!(shr_assign_u32_5d, shr_assign_rhs_u32_5d, u32, generic_array::typenum::U5, Integer::shr);
+impl_assign_op!(shr_assign_u32_6d, shr_assign_rhs_u32_6d, u32, generic_array::typenum::U6, Integer::shr);
+
+impl_assign_op!(add_assign_u64_1d, add_assign_rhs_u64_1d, u64, generic_array::typenum::U1, Arith::add);
+impl_assign_op!(add_assign_u64_2d, add_assign_rhs_u64_2d, u64, generic_array::typenum::U2, Arith::add);
+impl_assign_op!(add_assign_u64_3d, add_assign_rhs_u64_3d, u64, generic_array::typenum::U3, Arith::add);
+impl_assign_op!(add_assign_u64_4d, add_assign_rhs_u64_4d, u64, generic_array::typenum::U4, Arith::add);
+impl_assign_op!(add_assign_u64_5d, add_assign_rhs_u64_5d, u64, generic_array::typenum::U5, Arith::add);
+impl_assign_op!(add_assign_u64_6d, add_assign_rhs_u64_6d, u64, generic_array::typenum::U6, Arith::add);
+
+impl_assign_op!(sub_assign_u64_1d, sub_assign_rhs_u64_1d, u64, generic_array::typenum::U1, Arith::sub);
+impl_assign_op!(sub_assign_u64_2d, sub_assign_rhs_u64_2d, u64, generic_array::typenum::U2, Arith::sub);
+impl_assign_op!(sub_assign_u64_3d, sub_assign_rhs_u64_3d, u64, generic_array::typenum::U3, Arith::sub);
+impl_assign_op!(sub_assign_u64_4d, sub_assign_rhs_u64_4d, u64, generic_array::typenum::U4, Arith::sub);
+impl_assign_op!(sub_assign_u64_5d, sub_assign_rhs_u64_5d, u64, generic_array::typenum::U5, Arith::sub);
+impl_assign_op!(sub_assign_u64_6d, sub_assign_rhs_u64_6d, u64, generic_array::typenum::U6, Arith::sub);
+
+impl_assign_op!(mul_assign_u64_1d, mul_assign_rhs_u64_1d, u64, generic_array::typenum::U1, Arith::mul);
+impl_assign_op!(mul_assign_u64_2d, mul_assign_rhs_u64_2d, u64, generic_array::typenum::U2, Arith::mul);
+impl_assign_op!(mul_assign_u64_3d, mul_assign_rhs_u64_3d, u64, generic_array::typenum::U3, Arith::mul);
+impl_assign_op!(mul_assign_u64_4d, mul_assign_rhs_u64_4d, u64, generic_array::typenum::U4, Arith::mul);
+impl_assign_op!(mul_assign_u64_5d, mul_assign_rhs_u64_5d, u64, generic_array::typenum::U5, Arith::mul);
+impl_assign_op!(mul_assign_u64_6d, mul_assign_rhs_u64_6d, u64, generic_array::typenum::U6, Arith::mul);
+
+impl_assign_op!(div_assign_u64_1d, div_assign_rhs_u64_1d, u64, generic_array::typenum::U1, Arith::div);
+impl_assign_op!(div_assign_u64_2d, div_assign_rhs_u64_2d, u64, generic_array::typenum::U2, Arith::div);
+impl_assign_op!(div_assign_u64_3d, div_assign_rhs_u64_3d, u64, generic_array::typenum::U3, Arith::div);
+impl_assign_op!(div_assign_u64_4d, div_assign_rhs_u64_4d, u64, generic_array::typenum::U4, Arith::div);
+impl_assign_op!(div_assign_u64_5d, div_assign_rhs_u64_5d, u64, generic_array::typenum::U5, Arith::div);
+impl_assign_op!(div_assign_u64_6d, div_assign_rhs_u64_6d, u64, generic_array::typenum::U6, Arith::div);
+
+impl_assign_op!(rem_assign_u64_1d, rem_assign_rhs_u64_1d, u64, generic_array::typenum::U1, Integer::rem);
+impl_assign_op!(rem_assign_u64_2d, rem_assign_rhs_u64_2d, u64, generic_array::typenum::U2, Integer::rem);
+impl_assign_op!(rem_assign_u64_3d, rem_assign_rhs_u64_3d, u64, generic_array::typenum::U3, Integer::rem);
+impl_assign_op!(rem_assign_u64_4d, rem_assign_rhs_u64_4d, u64, generic_array::typenum::U4, Integer::rem);
+impl_assign_op!(rem_assign_u64_5d, rem_assign_rhs_u64_5d, u64, generic_array::typenum::U5, Integer::rem);
+impl_assign_op!(rem_assign_u64_6d, rem_assign_rhs_u64_6d, u64, generic_array::typenum::U6, Integer::rem);
+
+impl_assign_op!(and_assign_u64_1d, and_assign_rhs_u64_1d, u64, generic_array::typenum::U1, |a, b| a & b);
+impl_assign_op!(and_assign_u64_2d, and_assign_rhs_u64_2d, u64, generic_array::typenum::U2, |a, b| a & b);
+impl_assign_op!(and_assign_u64_3d, and_assign_rhs_u64_3d, u64, generic_array::typenum::U3, |a, b| a & b);
+impl_assign_op!(and_assign_u64_4d, and_assign_rhs_u64_4d, u64, generic_array::typenum::U4, |a, b| a & b);
+impl_assign_op!(and_assign_u64_5d, and_assign_rhs_u64_5d, u64, generic_array::typenum::U5, |a, b| a & b);
+impl_assign_op!(and_assign_u64_6d, and_assign_rhs_u64_6d, u64, generic_array::typenum::U6, |a, b| a & b);
+
+impl_assign_op!(or_assign_u64_1d, or_assign_rhs_u64_1d, u64, generic_array::typenum::U1, |a, b| a | b);
+impl_assign_op!(or_assign_u64_2d, or_assign_rhs_u64_2d, u64, generic_array::typenum::U2, |a, b| a | b);
+impl_assign_op!(or_assign_u64_3d, or_assign_rhs_u64_3d, u64, generic_array::typenum::U3, |a, b| a | b);
+impl_assign_op!(or_assign_u64_4d, or_assign_rhs_u64_4d, u64, generic_array::typenum::U4, |a, b| a | b);
+impl_assign_op!(or_assign_u64_5d, or_assign_rhs_u64_5d, u64, generic_array::typenum::U5, |a, b| a | b);
+impl_assign_op!(or_assign_u64_6d, or_assign_rhs_u64_6d, u64, generic_array::typenum::U6, |a, b| a | b);
+
+impl_assign_op!(xor_assign_u64_1d, xor_assign_rhs_u64_1d, u64, generic_array::typenum::U1, |a, b| a ^ b);
+impl_assign_op!(xor_assign_u64_2d, xor_assign_rhs_u64_2d, u64, generic_array::typenum::U2, |a, b| a ^ b);
+impl_assign_op!(xor_assign_u64_3d, xor_assign_rhs_u64_3d, u64, generic_array::typenum::U3, |a, b| a ^ b);
+impl_assign_op!(xor_assign_u64_4d, xor_assign_rhs_u64_4d, u64, generic_array::typenum::U4, |a, b| a ^ b);
+impl_assign_op!(xor_assign_u64_5d, xor_assign_rhs_u64_5d, u64, generic_array::typenum::U5, |a, b| a ^ b);
+impl_assign_op!(xor_assign_u64_6d, xor_assign_rhs_u64_6d, u64, generic_array::typenum::U6, |a, b| a ^ b);
+
+impl_assign_op!(shl_assign_u64_1d, shl_assign_rhs_u64_1d, u64, generic_array::typenum::U1, Integer::shl);
+impl_assign_op!(shl_assign_u64_2d, shl_assign_rhs_u64_2d, u64, generic_array::typenum::U2, Integer::shl);
+impl_assign_op!(shl_assign_u64_3d, shl_assign_rhs_u64_3d, u64, generic_array::typenum::U3, Integer::shl);
+impl_assign_op!(shl_assign_u64_4d, shl_assign_rhs_u64_4d, u64, generic_array::typenum::U4, Integer::shl);
+impl_assign_op!(shl_assign_u64_5d, shl_assign_rhs_u64_5d, u64, generic_array::typenum::U5, Integer::shl);
+impl_assign_op!(shl_assign_u64_6d, shl_assign_rhs_u64_6d, u64, generic_array::typenum::U6, Integer::shl);
+
+impl_assign_op!(shr_assign_u64_1d, shr_assign_rhs_u64_1d, u64, generic_array::typenum::U1, Integer::shr);
+impl_assign_op!(shr_assign_u64_2d, shr_assign_rhs_u64_2d, u64, generic_array::typenum::U2, Integer::shr);
+impl_assign_op!(shr_assign_u64_3d, shr_assign_rhs_u64_3d, u64, generic_array::typenum::U3, Integer::shr);
+impl_assign_op!(shr_assign_u64_4d, shr_assign_rhs_u64_4d, u64, generic_array::typenum::U4, Integer::shr);
+impl_assign_op!(shr_assign_u64_5d, shr_assign_rhs_u64_5d, u64, generic_array::typenum::U5, Integer::shr);
+impl_assign_op!(shr_assign_u64_6d, shr_assign_rhs_u64_6d, u64, generic_array::typenum::U6, Integer::shr);
+
+macro_rules! impl_where {
+    ($fn_name:ident, $ty:ty, $dim:ty) => {
+        #[kernel]
+        #[allow(improper_ctypes_definitions, clippy::missing_safety_doc, clippy::too_many_arguments)]
+        pub unsafe fn $fn_name(
+            cond: &[bool],
+            cond_strides: generic_array::GenericArray<usize, $dim>,
+            a: &[$ty],
+            a_strides: generic_array::GenericArray<usize, $dim>,
+            b: &[$ty],
+            b_strides: generic_array::GenericArray<usize, $dim>,
+            o: *mut $ty,
+            o_size: usize,
+            o_strides: generic_array::GenericArray<usize, $dim>,
+        ) {
+            let o = core::slice::from_raw_parts_mut(o, o_size);
+            let idx = thread::index_1d() as usize;
+            apply_op_broadcast3(
+                cond,
+                cond_strides,
+                a,
+                a_strides,
+                b,
+                b_strides,
+                o,
+                o_strides,
+                |cond, a, b| if cond { a } else { b },
+                idx,
+            );
+        }
+    };
+}
+
+impl_where!(where_f32_1d, f32, generic_array::typenum::U1);
+impl_where!(where_f32_2d, f32, generic_array::typenum::U2);
+impl_where!(where_f32_3d, f32, generic_array::typenum::U3);
+impl_where!(where_f32_4d, f32, generic_array::typenum::U4);
+impl_where!(where_f32_5d, f32, generic_array::typenum::U5);
+impl_where!(where_f32_6d, f32, generic_array::typenum::U6);
+
+impl_where!(where_f64_1d, f64, generic_array::typenum::U1);
+impl_where!(where_f64_2d, f64, generic_array::typenum::U2);
+impl_where!(where_f64_3d, f64, generic_array::typenum::U3);
+impl_where!(where_f64_4d, f64, generic_array::typenum::U4);
+impl_where!(where_f64_5d, f64, generic_array::typenum::U5);
+impl_where!(where_f64_6d, f64, generic_array::typenum::U6);
+
+impl_where!(where_f16_1d, f16, generic_array::typenum::U1);
+impl_where!(where_f16_2d, f16, generic_array::typenum::U2);
+impl_where!(where_f16_3d, f16, generic_array::typenum::U3);
+impl_where!(where_f16_4d, f16, generic_array::typenum::U4);
+impl_where!(where_f16_5d, f16, generic_array::typenum::U5);
+impl_where!(where_f16_6d, f16, generic_array::typenum::U6);
+
+impl_where!(where_bf16_1d, bf16, generic_array::typenum::U1);
+impl_where!(where_bf16_2d, bf16, generic_array::typenum::U2);
+impl_where!(where_bf16_3d, bf16, generic_array::typenum::U3);
+impl_where!(where_bf16_4d, bf16, generic_array::typenum::U4);
+impl_where!(where_bf16_5d, bf16, generic_array::typenum::U5);
+impl_where!(where_bf16_6d, bf16, generic_array::typenum::U6);
+
+impl_where!(where_bool_1d, bool, generic_array::typenum::U1);
+impl_where!(where_bool_2d, bool, generic_array::typenum::U2);
+impl_where!(where_bool_3d, bool, generic_array::typenum::U3);
+impl_where!(where_bool_4d, bool, generic_array::typenum::U4);
+impl_where!(where_bool_5d, bool, generic_array::typenum::U5);
+impl_where!(where_bool_6d, bool, generic_array::typenum::U6);
+
+impl_where!(where_i8_1d, i8, generic_array::typenum::U1);
+impl_where!(where_i8_2d, i8, generic_array::typenum::U2);
+impl_where!(where_i8_3d, i8, generic_array::typenum::U3);
+impl_where!(where_i8_4d, i8, generic_array::typenum::U4);
+impl_where!(where_i8_5d, i8, generic_array::typenum::U5);
+impl_where!(where_i8_6d, i8, generic_array::typenum::U6);
+
+impl_where!(where_i16_1d, i16, generic_array::typenum::U1);
+impl_where!(where_i16_2d, i16, generic_array::typenum::U2);
+impl_where!(where_i16_3d, i16, generic_array::typenum::U3);
+impl_where!(where_i16_4d, i16, generic_array::typenum::U4);
+impl_where!(where_i16_5d, i16, generic_array::typenum::U5);
+impl_where!(where_i16_6d, i16, generic_array::typenum::U6);
+
+impl_where!(where_i32_1d, i32, generic_array::typenum::U1);
+impl_where!(where_i32_2d, i32, generic_array::typenum::U2);
+impl_where!(where_i32_3d, i32, generic_array::typenum::U3);
+impl_where!(where_i32_4d, i32, generic_array::typenum::U4);
+impl_where!(where_i32_5d, i32, generic_array::typenum::U5);
+impl_where!(where_i32_6d, i32, generic_array::typenum::U6);
+
+impl_where!(where_i64_1d, i64, generic_array::typenum::U1);
+impl_where!(where_i64_2d, i64, generic_array::typenum::U2);
+impl_where!(where_i64_3d, i64, generic_array::typenum::U3);
+impl_where!(where_i64_4d, i64, generic_array::typenum::U4);
+impl_where!(where_i64_5d, i64, generic_array::typenum::U5);
+impl_where!(where_i64_6d, i64, generic_array::typenum::U6);
+
+impl_where!(where_u8_1d, u8, generic_array::typenum::U1);
+impl_where!(where_u8_2d, u8, generic_array::typenum::U2);
+impl_where!(where_u8_3d, u8, generic_array::typenum::U3);
+impl_where!(where_u8_4d, u8, generic_array::typenum::U4);
+impl_where!(where_u8_5d, u8, generic_array::typenum::U5);
+impl_where!(where_u8_6d, u8, generic_array::typenum::U6);
+
+impl_where!(where_u32_1d, u32, generic_array::typenum::U1);
+impl_where!(where_u32_2d, u32, generic_array::typenum::U2);
+impl_where!(where_u32_3d, u32, generic_array::typenum::U3);
+impl_where!(where_u32_4d, u32, generic_array::typenum::U4);
+impl_where!(where_u32_5d, u32, generic_array::typenum::U5);
+impl_where!(where_u32_6d, u32, generic_array::typenum::U6);
+
+impl_where!(where_u64_1d, u64, generic_array::typenum::U1);
+impl_where!(where_u64_2d, u64, generic_array::typenum::U2);
+impl_where!(where_u64_3d, u64, generic_array::typenum::U3);
+impl_where!(where_u64_4d, u64, generic_array::typenum::U4);
+impl_where!(where_u64_5d, u64, generic_array::typenum::U5);
+impl_where!(where_u64_6d, u64, generic_array::typenum::U6);
+
+macro_rules! impl_unary_op {
+    ($fn_name:ident, $i_ty:ty, $o_ty:ty, $op:expr) => {
+        #[kernel]
+        #[allow(improper_ctypes_definitions, clippy::missing_safety_doc)]
+        pub unsafe fn $fn_name(
+            a: &[$i_ty],
+            o: *mut $o_ty,
+            o_size: usize,
+            param: $i_ty,
+        ) {
+            let o = core::slice::from_raw_parts_mut(o, o_size);
+            let idx = thread::index_1d() as usize;
+            let op: fn($i_ty, $i_ty) -> $o_ty = $op;
+            apply_unary(a, o, param, op, idx);
+        }
+    };
+}
+
+impl_unary_op!(not_bool, bool, bool, |x, _| !x);
+
+impl_unary_op!(exp_f32, f32, f32, |x, _| x.exp());
+impl_unary_op!(ln_f32, f32, f32, |x, _| x.ln());
+impl_unary_op!(sqrt_f32, f32, f32, |x, _| x.sqrt());
+impl_unary_op!(rsqrt_f32, f32, f32, |x, _| 1.0 / x.sqrt());
+impl_unary_op!(sin_f32, f32, f32, |x, _| x.sin());
+impl_unary_op!(cos_f32, f32, f32, |x, _| x.cos());
+impl_unary_op!(tanh_f32, f32, f32, |x, _| x.tanh());
+impl_unary_op!(sigmoid_f32, f32, f32, |x, _| 1.0 / (1.0 + (-x).exp()));
+impl_unary_op!(relu_f32, f32, f32, |x, _| if x > 0.0 { x } else { 0.0 });
+impl_unary_op!(gelu_f32, f32, f32, |x, _| gelu_approx_f32(x));
+impl_unary_op!(abs_f32, f32, f32, |x, _| Abs::abs(x));
+impl_unary_op!(neg_f32, f32, f32, |x, _| Neg::neg(x));
+impl_unary_op!(powf_f32, f32, f32, |x, p| x.powf(p));
+impl_unary_op!(add_scalar_f32, f32, f32, |x, s| x + s);
+impl_unary_op!(sub_scalar_f32, f32, f32, |x, s| x - s);
+impl_unary_op!(rsub_scalar_f32, f32, f32, |x, s| s - x);
+impl_unary_op!(mul_scalar_f32, f32, f32, |x, s| x * s);
+impl_unary_op!(div_scalar_f32, f32, f32, |x, s| x / s);
+impl_unary_op!(rdiv_scalar_f32, f32, f32, |x, s| s / x);
+
+impl_unary_op!(exp_f64, f64, f64, |x, _| x.exp());
+impl_unary_op!(ln_f64, f64, f64, |x, _| x.ln());
+impl_unary_op!(sqrt_f64, f64, f64, |x, _| x.sqrt());
+impl_unary_op!(rsqrt_f64, f64, f64, |x, _| 1.0 / x.sqrt());
+impl_unary_op!(sin_f64, f64, f64, |x, _| x.sin());
+impl_unary_op!(cos_f64, f64, f64, |x, _| x.cos());
+impl_unary_op!(tanh_f64, f64, f64, |x, _| x.tanh());
+impl_unary_op!(sigmoid_f64, f64, f64, |x, _| 1.0 / (1.0 + (-x).exp()));
+impl_unary_op!(relu_f64, f64, f64, |x, _| if x > 0.0 { x } else { 0.0 });
+impl_unary_op!(gelu_f64, f64, f64, |x, _| gelu_approx_f64(x));
+impl_unary_op!(abs_f64, f64, f64, |x, _| Abs::abs(x));
+impl_unary_op!(neg_f64, f64, f64, |x, _| Neg::neg(x));
+impl_unary_op!(powf_f64, f64, f64, |x, p| x.powf(p));
+impl_unary_op!(add_scalar_f64, f64, f64, |x, s| x + s);
+impl_unary_op!(sub_scalar_f64, f64, f64, |x, s| x - s);
+impl_unary_op!(rsub_scalar_f64, f64, f64, |x, s| s - x);
+impl_unary_op!(mul_scalar_f64, f64, f64, |x, s| x * s);
+impl_unary_op!(div_scalar_f64, f64, f64, |x, s| x / s);
+impl_unary_op!(rdiv_scalar_f64, f64, f64, |x, s| s / x);
+
+impl_unary_op!(exp_f16, f16, f16, |x, _| f16::from_f32(x.to_f32().exp()));
+impl_unary_op!(ln_f16, f16, f16, |x, _| f16::from_f32(x.to_f32().ln()));
+impl_unary_op!(sqrt_f16, f16, f16, |x, _| f16::from_f32(x.to_f32().sqrt()));
+impl_unary_op!(rsqrt_f16, f16, f16, |x, _| f16::from_f32(1.0 / x.to_f32().sqrt()));
+impl_unary_op!(sin_f16, f16, f16, |x, _| f16::from_f32(x.to_f32().sin()));
+impl_unary_op!(cos_f16, f16, f16, |x, _| f16::from_f32(x.to_f32().cos()));
+impl_unary_op!(tanh_f16, f16, f16, |x, _| f16::from_f32(x.to_f32().tanh()));
+impl_unary_op!(sigmoid_f16, f16, f16, |x, _| f16::from_f32(1.0 / (1.0 + (-x.to_f32()).exp())));
+impl_unary_op!(relu_f16, f16, f16, |x, _| if x > f16::ZERO { x } else { f16::ZERO });
+impl_unary_op!(gelu_f16, f16, f16, |x, _| f16::from_f32(gelu_approx_f32(x.to_f32())));
+impl_unary_op!(abs_f16, f16, f16, |x, _| Abs::abs(x));
+impl_unary_op!(neg_f16, f16, f16, |x, _| Neg::neg(x));
+impl_unary_op!(powf_f16, f16, f16, |x, p| f16::from_f32(x.to_f32().powf(p.to_f32())));
+impl_unary_op!(add_scalar_f16, f16, f16, |x, s| x + s);
+impl_unary_op!(sub_scalar_f16, f16, f16, |x, s| x - s);
+impl_unary_op!(rsub_scalar_f16, f16, f16, |x, s| s - x);
+impl_unary_op!(mul_scalar_f16, f16, f16, |x, s| x * s);
+impl_unary_op!(div_scalar_f16, f16, f16, |x, s| x / s);
+impl_unary_op!(rdiv_scalar_f16, f16, f16, |x, s| s / x);
+
+impl_unary_op!(exp_bf16, bf16, bf16, |x, _| bf16::from_f32(x.to_f32().exp()));
+impl_unary_op!(ln_bf16, bf16, bf16, |x, _| bf16::from_f32(x.to_f32().ln()));
+impl_unary_op!(sqrt_bf16, bf16, bf16, |x, _| bf16::from_f32(x.to_f32().sqrt()));
+impl_unary_op!(rsqrt_bf16, bf16, bf16, |x, _| bf16::from_f32(1.0 / x.to_f32().sqrt()));
+impl_unary_op!(sin_bf16, bf16, bf16, |x, _| bf16::from_f32(x.to_f32().sin()));
+impl_unary_op!(cos_bf16, bf16, bf16, |x, _| bf16::from_f32(x.to_f32().cos()));
+impl_unary_op!(tanh_bf16, bf16, bf16, |x, _| bf16::from_f32(x.to_f32().tanh()));
+impl_unary_op!(sigmoid_bf16, bf16, bf16, |x, _| bf16::from_f32(1.0 / (1.0 + (-x.to_f32()).exp())));
+impl_unary_op!(relu_bf16, bf16, bf16, |x, _| if x > bf16::ZERO { x } else { bf16::ZERO });
+impl_unary_op!(gelu_bf16, bf16, bf16, |x, _| bf16::from_f32(gelu_approx_f32(x.to_f32())));
+impl_unary_op!(abs_bf16, bf16, bf16, |x, _| Abs::abs(x));
+impl_unary_op!(neg_bf16, bf16, bf16, |x, _| Neg::neg(x));
+impl_unary_op!(powf_bf16, bf16, bf16, |x, p| bf16::from_f32(x.to_f32().powf(p.to_f32())));
+impl_unary_op!(add_scalar_bf16, bf16, bf16, |x, s| x + s);
+impl_unary_op!(sub_scalar_bf16, bf16, bf16, |x, s| x - s);
+impl_unary_op!(rsub_scalar_bf16, bf16, bf16, |x, s| s - x);
+impl_unary_op!(mul_scalar_bf16, bf16, bf16, |x, s| x * s);
+impl_unary_op!(div_scalar_bf16, bf16, bf16, |x, s| x / s);
+impl_unary_op!(rdiv_scalar_bf16, bf16, bf16, |x, s| s / x);
+
+impl_unary_op!(abs_i8, i8, i8, |x, _| Abs::abs(x));
+impl_unary_op!(neg_i8, i8, i8, |x, _| Neg::neg(x));
+impl_unary_op!(add_scalar_i8, i8, i8, |x, s| Arith::add(x, s));
+impl_unary_op!(sub_scalar_i8, i8, i8, |x, s| Arith::sub(x, s));
+impl_unary_op!(rsub_scalar_i8, i8, i8, |x, s| Arith::sub(s, x));
+impl_unary_op!(mul_scalar_i8, i8, i8, |x, s| Arith::mul(x, s));
+impl_unary_op!(div_scalar_i8, i8, i8, |x, s| Arith::div(x, s));
+impl_unary_op!(rdiv_scalar_i8, i8, i8, |x, s| Arith::div(s, x));
+
+impl_unary_op!(abs_i16, i16, i16, |x, _| Abs::abs(x));
+impl_unary_op!(neg_i16, i16, i16, |x, _| Neg::neg(x));
+impl_unary_op!(add_scalar_i16, i16, i16, |x, s| Arith::add(x, s));
+impl_unary_op!(sub_scalar_i16, i16, i16, |x, s| Arith::sub(x, s));
+impl_unary_op!(rsub_scalar_i16, i16, i16, |x, s| Arith::sub(s, x));
+impl_unary_op!(mul_scalar_i16, i16, i16, |x, s| Arith::mul(x, s));
+impl_unary_op!(div_scalar_i16, i16, i16, |x, s| Arith::div(x, s));
+impl_unary_op!(rdiv_scalar_i16, i16, i16, |x, s| Arith::div(s, x));
+
+impl_unary_op!(abs_i32, i32, i32, |x, _| Abs::abs(x));
+impl_unary_op!(neg_i32, i32, i32, |x, _| Neg::neg(x));
+impl_unary_op!(add_scalar_i32, i32, i32, |x, s| Arith::add(x, s));
+impl_unary_op!(sub_scalar_i32, i32, i32, |x, s| Arith::sub(x, s));
+impl_unary_op!(rsub_scalar_i32, i32, i32, |x, s| Arith::sub(s, x));
+impl_unary_op!(mul_scalar_i32, i32, i32, |x, s| Arith::mul(x, s));
+impl_unary_op!(div_scalar_i32, i32, i32, |x, s| Arith::div(x, s));
+impl_unary_op!(rdiv_scalar_i32, i32, i32, |x, s| Arith::div(s, x));
+
+impl_unary_op!(abs_i64, i64, i64, |x, _| Abs::abs(x));
+impl_unary_op!(neg_i64, i64, i64, |x, _| Neg::neg(x));
+impl_unary_op!(add_scalar_i64, i64, i64, |x, s| Arith::add(x, s));
+impl_unary_op!(sub_scalar_i64, i64, i64, |x, s| Arith::sub(x, s));
+impl_unary_op!(rsub_scalar_i64, i64, i64, |x, s| Arith::sub(s, x));
+impl_unary_op!(mul_scalar_i64, i64, i64, |x, s| Arith::mul(x, s));
+impl_unary_op!(div_scalar_i64, i64, i64, |x, s| Arith::div(x, s));
+impl_unary_op!(rdiv_scalar_i64, i64, i64, |x, s| Arith::div(s, x));
+
+impl_unary_op!(add_scalar_u8, u8, u8, |x, s| Arith::add(x, s));
+impl_unary_op!(sub_scalar_u8, u8, u8, |x, s| Arith::sub(x, s));
+impl_unary_op!(rsub_scalar_u8, u8, u8, |x, s| Arith::sub(s, x));
+impl_unary_op!(mul_scalar_u8, u8, u8, |x, s| Arith::mul(x, s));
+impl_unary_op!(div_scalar_u8, u8, u8, |x, s| Arith::div(x, s));
+impl_unary_op!(rdiv_scalar_u8, u8, u8, |x, s| Arith::div(s, x));
+
+impl_unary_op!(add_scalar_u32, u32, u32, |x, s| Arith::add(x, s));
+impl_unary_op!(sub_scalar_u32, u32, u32, |x, s| Arith::sub(x, s));
+impl_unary_op!(rsub_scalar_u32, u32, u32, |x, s| Arith::sub(s, x));
+impl_unary_op!(mul_scalar_u32, u32, u32, |x, s| Arith::mul(x, s));
+impl_unary_op!(div_scalar_u32, u32, u32, |x, s| Arith::div(x, s));
+impl_unary_op!(rdiv_scalar_u32, u32, u32, |x, s| Arith::div(s, x));
+
+impl_unary_op!(add_scalar_u64, u64, u64, |x, s| Arith::add(x, s));
+impl_unary_op!(sub_scalar_u64, u64, u64, |x, s| Arith::sub(x, s));
+impl_unary_op!(rsub_scalar_u64, u64, u64, |x, s| Arith::sub(s, x));
+impl_unary_op!(mul_scalar_u64, u64, u64, |x, s| Arith::mul(x, s));
+impl_unary_op!(div_scalar_u64, u64, u64, |x, s| Arith::div(x, s));
+impl_unary_op!(rdiv_scalar_u64, u64, u64, |x, s| Arith::div(s, x));
+
+impl_unary_op!(cast_f32_f32, f32, f32, |x, _| x.cast());
+impl_unary_op!(cast_f64_f32, f32, f64, |x, _| x.cast());
+impl_unary_op!(cast_f16_f32, f32, f16, |x, _| x.cast());
+impl_unary_op!(cast_bf16_f32, f32, bf16, |x, _| x.cast());
+impl_unary_op!(cast_bool_f32, f32, bool, |x, _| x.cast());
+impl_unary_op!(cast_i8_f32, f32, i8, |x, _| x.cast());
+impl_unary_op!(cast_i16_f32, f32, i16, |x, _| x.cast());
+impl_unary_op!(cast_i32_f32, f32, i32, |x, _| x.cast());
+impl_unary_op!(cast_i64_f32, f32, i64, |x, _| x.cast());
+impl_unary_op!(cast_u8_f32, f32, u8, |x, _| x.cast());
+impl_unary_op!(cast_u32_f32, f32, u32, |x, _| x.cast());
+impl_unary_op!(cast_u64_f32, f32, u64, |x, _| x.cast());
+
+impl_unary_op!(cast_f32_f64, f64, f32, |x, _| x.cast());
+impl_unary_op!(cast_f64_f64, f64, f64, |x, _| x.cast());
+impl_unary_op!(cast_f16_f64, f64, f16, |x, _| x.cast());
+impl_unary_op!(cast_bf16_f64, f64, bf16, |x, _| x.cast());
+impl_unary_op!(cast_bool_f64, f64, bool, |x, _| x.cast());
+impl_unary_op!(cast_i8_f64, f64, i8, |x, _| x.cast());
+impl_unary_op!(cast_i16_f64, f64, i16, |x, _| x.cast());
+impl_unary_op!(cast_i32_f64, f64, i32, |x, _| x.cast());
+impl_unary_op!(cast_i64_f64, f64, i64, |x, _| x.cast());
+impl_unary_op!(cast_u8_f64, f64, u8, |x, _| x.cast());
+impl_unary_op!(cast_u32_f64, f64, u32, |x, _| x.cast());
+impl_unary_op!(cast_u64_f64, f64, u64, |x, _| x.cast());
+
+impl_unary_op!(cast_f32_f16, f16, f32, |x, _| x.cast());
+impl_unary_op!(cast_f64_f16, f16, f64, |x, _| x.cast());
+impl_unary_op!(cast_f16_f16, f16, f16, |x, _| x.cast());
+impl_unary_op!(cast_bf16_f16, f16, bf16, |x, _| x.cast());
+impl_unary_op!(cast_bool_f16, f16, bool, |x, _| x.cast());
+impl_unary_op!(cast_i8_f16, f16, i8, |x, _| x.cast());
+impl_unary_op!(cast_i16_f16, f16, i16, |x, _| x.cast());
+impl_unary_op!(cast_i32_f16, f16, i32, |x, _| x.cast());
+impl_unary_op!(cast_i64_f16, f16, i64, |x, _| x.cast());
+impl_unary_op!(cast_u8_f16, f16, u8, |x, _| x.cast());
+impl_unary_op!(cast_u32_f16, f16, u32, |x, _| x.cast());
+impl_unary_op!(cast_u64_f16, f16, u64, |x, _| x.cast());
+
+impl_unary_op!(cast_f32_bf16, bf16, f32, |x, _| x.cast());
+impl_unary_op!(cast_f64_bf16, bf16, f64, |x, _| x.cast());
+impl_unary_op!(cast_f16_bf16, bf16, f16, |x, _| x.cast());
+impl_unary_op!(cast_bf16_bf16, bf16, bf16, |x, _| x.cast());
+impl_unary_op!(cast_bool_bf16, bf16, bool, |x, _| x.cast());
+impl_unary_op!(cast_i8_bf16, bf16, i8, |x, _| x.cast());
+impl_unary_op!(cast_i16_bf16, bf16, i16, |x, _| x.cast());
+impl_unary_op!(cast_i32_bf16, bf16, i32, |x, _| x.cast());
+impl_unary_op!(cast_i64_bf16, bf16, i64, |x, _| x.cast());
+impl_unary_op!(cast_u8_bf16, bf16, u8, |x, _| x.cast());
+impl_unary_op!(cast_u32_bf16, bf16, u32, |x, _| x.cast());
+impl_unary_op!(cast_u64_bf16, bf16, u64, |x, _| x.cast());
+
+impl_unary_op!(cast_f32_bool, bool, f32, |x, _| x.cast());
+impl_unary_op!(cast_f64_bool, bool, f64, |x, _| x.cast());
+impl_unary_op!(cast_f16_bool, bool, f16, |x, _| x.cast());
+impl_unary_op!(cast_bf16_bool, bool, bf16, |x, _| x.cast());
+impl_unary_op!(cast_bool_bool, bool, bool, |x, _| x.cast());
+impl_unary_op!(cast_i8_bool, bool, i8, |x, _| x.cast());
+impl_unary_op!(cast_i16_bool, bool, i16, |x, _| x.cast());
+impl_unary_op!(cast_i32_bool, bool, i32, |x, _| x.cast());
+impl_unary_op!(cast_i64_bool, bool, i64, |x, _| x.cast());
+impl_unary_op!(cast_u8_bool, bool, u8, |x, _| x.cast());
+impl_unary_op!(cast_u32_bool, bool, u32, |x, _| x.cast());
+impl_unary_op!(cast_u64_bool, bool, u64, |x, _| x.cast());
+
+impl_unary_op!(cast_f32_i8, i8, f32, |x, _| x.cast());
+impl_unary_op!(cast_f64_i8, i8, f64, |x, _| x.cast());
+impl_unary_op!(cast_f16_i8, i8, f16, |x, _| x.cast());
+impl_unary_op!(cast_bf16_i8, i8, bf16, |x, _| x.cast());
+impl_unary_op!(cast_bool_i8, i8, bool, |x, _| x.cast());
+impl_unary_op!(cast_i8_i8, i8, i8, |x, _| x.cast());
+impl_unary_op!(cast_i16_i8, i8, i16, |x, _| x.cast());
+impl_unary_op!(cast_i32_i8, i8, i32, |x, _| x.cast());
+impl_unary_op!(cast_i64_i8, i8, i64, |x, _| x.cast());
+impl_unary_op!(cast_u8_i8, i8, u8, |x, _| x.cast());
+impl_unary_op!(cast_u32_i8, i8, u32, |x, _| x.cast());
+impl_unary_op!(cast_u64_i8, i8, u64, |x, _| x.cast());
+impl_unary_op!(not_i8, i8, i8, |x, _| !x);
+impl_unary_op!(relu_i8, i8, i8, |x, _| if x > 0 { x } else { 0 });
+
+impl_unary_op!(cast_f32_i16, i16, f32, |x, _| x.cast());
+impl_unary_op!(cast_f64_i16, i16, f64, |x, _| x.cast());
+impl_unary_op!(cast_f16_i16, i16, f16, |x, _| x.cast());
+impl_unary_op!(cast_bf16_i16, i16, bf16, |x, _| x.cast());
+impl_unary_op!(cast_bool_i16, i16, bool, |x, _| x.cast());
+impl_unary_op!(cast_i8_i16, i16, i8, |x, _| x.cast());
+impl_unary_op!(cast_i16_i16, i16, i16, |x, _| x.cast());
+impl_unary_op!(cast_i32_i16, i16, i32, |x, _| x.cast());
+impl_unary_op!(cast_i64_i16, i16, i64, |x, _| x.cast());
+impl_unary_op!(cast_u8_i16, i16, u8, |x, _| x.cast());
+impl_unary_op!(cast_u32_i16, i16, u32, |x, _| x.cast());
+impl_unary_op!(cast_u64_i16, i16, u64, |x, _| x.cast());
+impl_unary_op!(not_i16, i16, i16, |x, _| !x);
+impl_unary_op!(relu_i16, i16, i16, |x, _| if x > 0 { x } else { 0 });
+
+impl_unary_op!(cast_f32_i32, i32, f32, |x, _| x.cast());
+impl_unary_op!(cast_f64_i32, i32, f64, |x, _| x.cast());
+impl_unary_op!(cast_f16_i32, i32, f16, |x, _| x.cast());
+impl_unary_op!(cast_bf16_i32, i32, bf16, |x, _| x.cast());
+impl_unary_op!(cast_bool_i32, i32, bool, |x, _| x.cast());
+impl_unary_op!(cast_i8_i32, i32, i8, |x, _| x.cast());
+impl_unary_op!(cast_i16_i32, i32, i16, |x, _| x.cast());
+impl_unary_op!(cast_i32_i32, i32, i32, |x, _| x.cast());
+impl_unary_op!(cast_i64_i32, i32, i64, |x, _| x.cast());
+impl_unary_op!(cast_u8_i32, i32, u8, |x, _| x.cast());
+impl_unary_op!(cast_u32_i32, i32, u32, |x, _| x.cast());
+impl_unary_op!(cast_u64_i32, i32, u64, |x, _| x.cast());
+impl_unary_op!(not_i32, i32, i32, |x, _| !x);
+impl_unary_op!(relu_i32, i32, i32, |x, _| if x > 0 { x } else { 0 });
+
+impl_unary_op!(cast_f32_i64, i64, f32, |x, _| x.cast());
+impl_unary_op!(cast_f64_i64, i64, f64, |x, _| x.cast());
+impl_unary_op!(cast_f16_i64, i64, f16, |x, _| x.cast());
+impl_unary_op!(cast_bf16_i64, i64, bf16, |x, _| x.cast());
+impl_unary_op!(cast_bool_i64, i64, bool, |x, _| x.cast());
+impl_unary_op!(cast_i8_i64, i64, i8, |x, _| x.cast());
+impl_unary_op!(cast_i16_i64, i64, i16, |x, _| x.cast());
+impl_unary_op!(cast_i32_i64, i64, i32, |x, _| x.cast());
+impl_unary_op!(cast_i64_i64, i64, i64, |x, _| x.cast());
+impl_unary_op!(cast_u8_i64, i64, u8, |x, _| x.cast());
+impl_unary_op!(cast_u32_i64, i64, u32, |x, _| x.cast());
+impl_unary_op!(cast_u64_i64, i64, u64, |x, _| x.cast());
+impl_unary_op!(not_i64, i64, i64, |x, _| !x);
+impl_unary_op!(relu_i64, i64, i64, |x, _| if x > 0 { x } else { 0 });
+
+impl_unary_op!(cast_f32_u8, u8, f32, |x, _| x.cast());
+impl_unary_op!(cast_f64_u8, u8, f64, |x, _| x.cast());
+impl_unary_op!(cast_f16_u8, u8, f16, |x, _| x.cast());
+impl_unary_op!(cast_bf16_u8, u8, bf16, |x, _| x.cast());
+impl_unary_op!(cast_bool_u8, u8, bool, |x, _| x.cast());
+impl_unary_op!(cast_i8_u8, u8, i8, |x, _| x.cast());
+impl_unary_op!(cast_i16_u8, u8, i16, |x, _| x.cast());
+impl_unary_op!(cast_i32_u8, u8, i32, |x, _| x.cast());
+impl_unary_op!(cast_i64_u8, u8, i64, |x, _| x.cast());
+impl_unary_op!(cast_u8_u8, u8, u8, |x, _| x.cast());
+impl_unary_op!(cast_u32_u8, u8, u32, |x, _| x.cast());
+impl_unary_op!(cast_u64_u8, u8, u64, |x, _| x.cast());
+impl_unary_op!(not_u8, u8, u8, |x, _| !x);
+impl_unary_op!(relu_u8, u8, u8, |x, _| if x > 0 { x } else { 0 });
+
+impl_unary_op!(cast_f32_u32, u32, f32, |x, _| x.cast());
+impl_unary_op!(cast_f64_u32, u32, f64, |x, _| x.cast());
+impl_unary_op!(cast_f16_u32, u32, f16, |x, _| x.cast());
+impl_unary_op!(cast_bf16_u32, u32, bf16, |x, _| x.cast());
+impl_unary_op!(cast_bool_u32, u32, bool, |x, _| x.cast());
+impl_unary_op!(cast_i8_u32, u32, i8, |x, _| x.cast());
+impl_unary_op!(cast_i16_u32, u32, i16, |x, _| x.cast());
+impl_unary_op!(cast_i32_u32, u32, i32, |x, _| x.cast());
+impl_unary_op!(cast_i64_u32, u32, i64, |x, _| x.cast());
+impl_unary_op!(cast_u8_u32, u32, u8, |x, _| x.cast());
+impl_unary_op!(cast_u32_u32, u32, u32, |x, _| x.cast());
+impl_unary_op!(cast_u64_u32, u32, u64, |x, _| x.cast());
+impl_unary_op!(not_u32, u32, u32, |x, _| !x);
+impl_unary_op!(relu_u32, u32, u32, |x, _| if x > 0 { x } else { 0 });
+
+impl_unary_op!(cast_f32_u64, u64, f32, |x, _| x.cast());
+impl_unary_op!(cast_f64_u64, u64, f64, |x, _| x.cast());
+impl_unary_op!(cast_f16_u64, u64, f16, |x, _| x.cast());
+impl_unary_op!(cast_bf16_u64, u64, bf16, |x, _| x.cast());
+impl_unary_op!(cast_bool_u64, u64, bool, |x, _| x.cast());
+impl_unary_op!(cast_i8_u64, u64, i8, |x, _| x.cast());
+impl_unary_op!(cast_i16_u64, u64, i16, |x, _| x.cast());
+impl_unary_op!(cast_i32_u64, u64, i32, |x, _| x.cast());
+impl_unary_op!(cast_i64_u64, u64, i64, |x, _| x.cast());
+impl_unary_op!(cast_u8_u64, u64, u8, |x, _| x.cast());
+impl_unary_op!(cast_u32_u64, u64, u32, |x, _| x.cast());
+impl_unary_op!(cast_u64_u64, u64, u64, |x, _| x.cast());
+impl_unary_op!(not_u64, u64, u64, |x, _| !x);
+impl_unary_op!(relu_u64, u64, u64, |x, _| if x > 0 { x } else { 0 });
+
+macro_rules! impl_permute {
+    ($fn_name:ident, $ty:ty, $dim:ty) => {
+        #[kernel]
+        #[allow(improper_ctypes_definitions, clippy::missing_safety_doc)]
+        pub unsafe fn $fn_name(
+            a: &[$ty],
+            a_offset: usize,
+            a_strides: generic_array::GenericArray<usize, $dim>,
+            o: *mut $ty,
+            o_size: usize,
+            o_strides: generic_array::GenericArray<usize, $dim>,
+        ) {
+            let o = core::slice::from_raw_parts_mut(o, o_size);
+            let idx = thread::index_1d() as usize;
+            apply_permute(a, a_offset, a_strides, o, o_strides, idx);
+        }
+    };
+}
+
+impl_permute!(permute_f32_1d, f32, generic_array::typenum::U1);
+impl_permute!(permute_f32_2d, f32, generic_array::typenum::U2);
+impl_permute!(permute_f32_3d, f32, generic_array::typenum::U3);
+impl_permute!(permute_f32_4d, f32, generic_array::typenum::U4);
+impl_permute!(permute_f32_5d, f32, generic_array::typenum::U5);
+impl_permute!(permute_f32_6d, f32, generic_array::typenum::U6);
+
+impl_permute!(permute_f64_1d, f64, generic_array::typenum::U1);
+impl_permute!(permute_f64_2d, f64, generic_array::typenum::U2);
+impl_permute!(permute_f64_3d, f64, generic_array::typenum::U3);
+impl_permute!(permute_f64_4d, f64, generic_array::typenum::U4);
+impl_permute!(permute_f64_5d, f64, generic_array::typenum::U5);
+impl_permute!(permute_f64_6d, f64, generic_array::typenum::U6);
+
+impl_permute!(permute_f16_1d, f16, generic_array::typenum::U1);
+impl_permute!(permute_f16_2d, f16, generic_array::typenum::U2);
+impl_permute!(permute_f16_3d, f16, generic_array::typenum::U3);
+impl_permute!(permute_f16_4d, f16, generic_array::typenum::U4);
+impl_permute!(permute_f16_5d, f16, generic_array::typenum::U5);
+impl_permute!(permute_f16_6d, f16, generic_array::typenum::U6);
+
+impl_permute!(permute_bf16_1d, bf16, generic_array::typenum::U1);
+impl_permute!(permute_bf16_2d, bf16, generic_array::typenum::U2);
+impl_permute!(permute_bf16_3d, bf16, generic_array::typenum::U3);
+impl_permute!(permute_bf16_4d, bf16, generic_array::typenum::U4);
+impl_permute!(permute_bf16_5d, bf16, generic_array::typenum::U5);
+impl_permute!(permute_bf16_6d, bf16, generic_array::typenum::U6);
+
+impl_permute!(permute_bool_1d, bool, generic_array::typenum::U1);
+impl_permute!(permute_bool_2d, bool, generic_array::typenum::U2);
+impl_permute!(permute_bool_3d, bool, generic_array::typenum::U3);
+impl_permute!(permute_bool_4d, bool, generic_array::typenum::U4);
+impl_permute!(permute_bool_5d, bool, generic_array::typenum::U5);
+impl_permute!(permute_bool_6d, bool, generic_array::typenum::U6);
+
+impl_permute!(permute_i8_1d, i8, generic_array::typenum::U1);
+impl_permute!(permute_i8_2d, i8, generic_array::typenum::U2);
+impl_permute!(permute_i8_3d, i8, generic_array::typenum::U3);
+impl_permute!(permute_i8_4d, i8, generic_array::typenum::U4);
+impl_permute!(permute_i8_5d, i8, generic_array::typenum::U5);
+impl_permute!(permute_i8_6d, i8, generic_array::typenum::U6);
+
+impl_permute!(permute_i16_1d, i16, generic_array::typenum::U1);
+impl_permute!(permute_i16_2d, i16, generic_array::typenum::U2);
+impl_permute!(permute_i16_3d, i16, generic_array::typenum::U3);
+impl_permute!(permute_i16_4d, i16, generic_array::typenum::U4);
+impl_permute!(permute_i16_5d, i16, generic_array::typenum::U5);
+impl_permute!(permute_i16_6d, i16, generic_array::typenum::U6);
+
+impl_permute!(permute_i32_1d, i32, generic_array::typenum::U1);
+impl_permute!(permute_i32_2d, i32, generic_array::typenum::U2);
+impl_permute!(permute_i32_3d, i32, generic_array::typenum::U3);
+impl_permute!(permute_i32_4d, i32, generic_array::typenum::U4);
+impl_permute!(permute_i32_5d, i32, generic_array::typenum::U5);
+impl_permute!(permute_i32_6d, i32, generic_array::typenum::U6);
+
+impl_permute!(permute_i64_1d, i64, generic_array::typenum::U1);
+impl_permute!(permute_i64_2d, i64, generic_array::typenum::U2);
+impl_permute!(permute_i64_3d, i64, generic_array::typenum::U3);
+impl_permute!(permute_i64_4d, i64, generic_array::typenum::U4);
+impl_permute!(permute_i64_5d, i64, generic_array::typenum::U5);
+impl_permute!(permute_i64_6d, i64, generic_array::typenum::U6);
+
+impl_permute!(permute_u8_1d, u8, generic_array::typenum::U1);
+impl_permute!(permute_u8_2d, u8, generic_array::typenum::U2);
+impl_permute!(permute_u8_3d, u8, generic_array::typenum::U3);
+impl_permute!(permute_u8_4d, u8, generic_array::typenum::U4);
+impl_permute!(permute_u8_5d, u8, generic_array::typenum::U5);
+impl_permute!(permute_u8_6d, u8, generic_array::typenum::U6);
+
+impl_permute!(permute_u32_1d, u32, generic_array::typenum::U1);
+impl_permute!(permute_u32_2d, u32, generic_array::typenum::U2);
+impl_permute!(permute_u32_3d, u32, generic_array::typenum::U3);
+impl_permute!(permute_u32_4d, u32, generic_array::typenum::U4);
+impl_permute!(permute_u32_5d, u32, generic_array::typenum::U5);
+impl_permute!(permute_u32_6d, u32, generic_array::typenum::U6);
+
+impl_permute!(permute_u64_1d, u64, generic_array::typenum::U1);
+impl_permute!(permute_u64_2d, u64, generic_array::typenum::U2);
+impl_permute!(permute_u64_3d, u64, generic_array::typenum::U3);
+impl_permute!(permute_u64_4d, u64, generic_array::typenum::U4);
+impl_permute!(permute_u64_5d, u64, generic_array::typenum::U5);
+impl_permute!(permute_u64_6d, u64, generic_array::typenum::U6);
+
+/// Threads per block of the reduction kernels.
+const REDUCE_BLOCK: usize = 256;
+
+macro_rules! impl_reduce {
+    ($fn_name:ident, $ty:ty, $acc_ty:ty, $init:expr, $op:expr, $finish:expr) => {
+        #[kernel]
+        #[allow(improper_ctypes_definitions, clippy::missing_safety_doc)]
+        pub unsafe fn $fn_name(
+            a: &[$ty],
+            o: *mut $ty,
+            o_size: usize,
+            len: usize,
+            inner: usize,
+        ) {
+            // One block per output element, every thread folds a strided part of the axis and
+            // the block combines the partial results as a tree in shared memory.
+            let op: fn($acc_ty, $acc_ty) -> $acc_ty = $op;
+            let finish: fn($acc_ty, usize) -> $ty = $finish;
+            let shared = cuda_std::shared_array![$acc_ty; REDUCE_BLOCK];
+            let shared = core::slice::from_raw_parts_mut(shared, REDUCE_BLOCK);
+            let tid = thread::thread_idx_x() as usize;
+            let idx = thread::block_idx_x() as usize;
+            shared[tid] = reduce_partial(a, idx, len, inner, tid, REDUCE_BLOCK, $init, op);
+            thread::sync_threads();
+
+            let mut step = REDUCE_BLOCK / 2;
+            while step > 0 {
+                reduce_step(shared, tid, step, op);
+                thread::sync_threads();
+                step /= 2;
+            }
+            if tid == 0 && idx < o_size {
+                *o.add(idx) = finish(shared[0], len);
+            }
+        }
+    };
+}
+
+impl_reduce!(sum_f32, f32, f32, 0.0, |a, b| a + b, |acc, _| acc);
+impl_reduce!(mean_f32, f32, f32, 0.0, |a, b| a + b, |acc, len| acc / len as f32);
+impl_reduce!(prod_f32, f32, f32, 1.0, |a, b| a * b, |acc, _| acc);
+impl_reduce!(max_f32, f32, f32, f32::NEG_INFINITY, |a, b| a.max(b), |acc, _| acc);
+impl_reduce!(min_f32, f32, f32, f32::INFINITY, |a, b| a.min(b), |acc, _| acc);
+
+impl_reduce!(sum_f64, f64, f64, 0.0, |a, b| a + b, |acc, _| acc);
+impl_reduce!(mean_f64, f64, f64, 0.0, |a, b| a + b, |acc, len| acc / len as f64);
+impl_reduce!(prod_f64, f64, f64, 1.0, |a, b| a * b, |acc, _| acc);
+impl_reduce!(max_f64, f64, f64, f64::NEG_INFINITY, |a, b| a.max(b), |acc, _| acc);
+impl_reduce!(min_f64, f64, f64, f64::INFINITY, |a, b| a.min(b), |acc, _| acc);
+
+impl_reduce!(sum_f16, f16, f32, 0.0, |a, b| a + b, |acc, _| f16::from_f32(acc));
+impl_reduce!(mean_f16, f16, f32, 0.0, |a, b| a + b, |acc, len| f16::from_f32(acc / len as f32));
+impl_reduce!(prod_f16, f16, f32, 1.0, |a, b| a * b, |acc, _| f16::from_f32(acc));
+impl_reduce!(max_f16, f16, f32, f32::NEG_INFINITY, |a, b| a.max(b), |acc, _| f16::from_f32(acc));
+impl_reduce!(min_f16, f16, f32, f32::INFINITY, |a, b| a.min(b), |acc, _| f16::from_f32(acc));
+
+impl_reduce!(sum_bf16, bf16, f32, 0.0, |a, b| a + b, |acc, _| bf16::from_f32(acc));
+impl_reduce!(mean_bf16, bf16, f32, 0.0, |a, b| a + b, |acc, len| bf16::from_f32(acc / len as f32));
+impl_reduce!(prod_bf16, bf16, f32, 1.0, |a, b| a * b, |acc, _| bf16::from_f32(acc));
+impl_reduce!(max_bf16, bf16, f32, f32::NEG_INFINITY, |a, b| a.max(b), |acc, _| bf16::from_f32(acc));
+impl_reduce!(min_bf16, bf16, f32, f32::INFINITY, |a, b| a.min(b), |acc, _| bf16::from_f32(acc));
+
+impl_reduce!(all_bool, bool, bool, true, |a, b| a & b, |acc, _| acc);
+impl_reduce!(any_bool, bool, bool, false, |a, b| a | b, |acc, _| acc);
+
+macro_rules! impl_arg_reduce {
+    ($fn_name:ident, $ty:ty, $better:expr) => {
+        #[kernel]
+        #[allow(improper_ctypes_definitions, clippy::missing_safety_doc)]
+        pub unsafe fn $fn_name(
+            a: &[$ty],
+            o: *mut u32,
+            o_size: usize,
+            len: usize,
+            inner: usize,
+        ) {
+            // Like `impl_reduce`, but the tree carries the position of the best element.
+            let better: fn($ty, $ty) -> bool = $better;
+            let values = cuda_std::shared_array![$ty; REDUCE_BLOCK];
+            let values = core::slice::from_raw_parts_mut(values, REDUCE_BLOCK);
+            let positions = cuda_std::shared_array![u32; REDUCE_BLOCK];
+            let positions = core::slice::from_raw_parts_mut(positions, REDUCE_BLOCK);
+            let tid = thread::thread_idx_x() as usize;
+            let idx = thread::block_idx_x() as usize;
+            (values[tid], positions[tid]) = arg_partial(a, idx, len, inner, tid, REDUCE_BLOCK, better);
+            thread::sync_threads();
+
+            let mut step = REDUCE_BLOCK / 2;
+            while step > 0 {
+                arg_step(values, positions, tid, step, better);
+                thread::sync_threads();
+                step /= 2;
+            }
+            if tid == 0 && idx < o_size {
+                *o.add(idx) = positions[0];
+            }
+        }
+    };
+}
+
+impl_arg_reduce!(argmax_f32, f32, |x, best| x > best);
+impl_arg_reduce!(argmin_f32, f32, |x, best| x < best);
+impl_arg_reduce!(argmax_f64, f64, |x, best| x > best);
+impl_arg_reduce!(argmin_f64, f64, |x, best| x < best);
+impl_arg_reduce!(argmax_f16, f16, |x, best| x > best);
+impl_arg_reduce!(argmin_f16, f16, |x, best| x < best);
+impl_arg_reduce!(argmax_bf16, bf16, |x, best| x > best);
+impl_arg_reduce!(argmin_bf16, bf16, |x, best| x < best);
+impl_arg_reduce!(argmax_bool, bool, |x, best| x & !best);
+impl_arg_reduce!(argmin_bool, bool, |x, best| !x & best);
+impl_arg_reduce!(argmax_i8, i8, |x, best| x > best);
+impl_arg_reduce!(argmin_i8, i8, |x, best| x < best);
+impl_arg_reduce!(argmax_i16, i16, |x, best| x > best);
+impl_arg_reduce!(argmin_i16, i16, |x, best| x < best);
+impl_arg_reduce!(argmax_i32, i32, |x, best| x > best);
+impl_arg_reduce!(argmin_i32, i32, |x, best| x < best);
+impl_arg_reduce!(argmax_i64, i64, |x, best| x > best);
+impl_arg_reduce!(argmin_i64, i64, |x, best| x < best);
+impl_arg_reduce!(argmax_u8, u8, |x, best| x > best);
+impl_arg_reduce!(argmin_u8, u8, |x, best| x < best);
+impl_arg_reduce!(argmax_u32, u32, |x, best| x > best);
+impl_arg_reduce!(argmin_u32, u32, |x, best| x < best);
+impl_arg_reduce!(argmax_u64, u64, |x, best| x > best);
+impl_arg_reduce!(argmin_u64, u64, |x, best| x < best);
+
+macro_rules! impl_topk {
+    ($fn_name:ident, $ty:ty) => {
+        #[kernel]
+        #[allow(improper_ctypes_definitions, clippy::missing_safety_doc)]
+        pub unsafe fn $fn_name(
+            a: &[$ty],
+            values: *mut $ty,
+            indices: *mut u32,
+            rows: usize,
+            len: usize,
+            k: usize,
+        ) {
+            let row = thread::index_1d() as usize;
+            if row < rows {
+                let values = core::slice::from_raw_parts_mut(values.add(row * k), k);
+                let indices = core::slice::from_raw_parts_mut(indices.add(row * k), k);
+                apply_topk(&a[row * len..(row + 1) * len], values, indices);
+            }
+        }
+    };
+}
+
+impl_topk!(topk_f32, f32);
+impl_topk!(topk_f64, f64);
+impl_topk!(topk_f16, f16);
+impl_topk!(topk_bf16, bf16);
+impl_topk!(topk_bool, bool);
+impl_topk!(topk_i8, i8);
+impl_topk!(topk_i16, i16);
+impl_topk!(topk_i32, i32);
+impl_topk!(topk_i64, i64);
+impl_topk!(topk_u8, u8);
+impl_topk!(topk_u32, u32);
+impl_topk!(topk_u64, u64);
+
+macro_rules! impl_matmul {
+    ($fn_name:ident, $ty:ty, $acc_ty:ty, $dim:ty, $finish:expr) => {
+        #[kernel]
+        #[allow(improper_ctypes_definitions, clippy::missing_safety_doc)]
+        pub unsafe fn $fn_name(
+            a: &[$ty],
+            a_strides: generic_array::GenericArray<usize, $dim>,
+            b: &[$ty],
+            b_strides: generic_array::GenericArray<usize, $dim>,
+            o: *mut $ty,
+            o_size: usize,
+            o_strides: generic_array::GenericArray<usize, $dim>,
+            k: usize,
+        ) {
+            let o = core::slice::from_raw_parts_mut(o, o_size);
+            let idx = thread::index_1d() as usize;
+            let finish: fn($acc_ty) -> $ty = $finish;
+            apply_matmul(a, a_strides, b, b_strides, o, o_strides, k, <$acc_ty>::default(), finish, idx);
+        }
+    };
+}
+
+impl_matmul!(matmul_f32_2d, f32, f32, generic_array::typenum::U2, |acc| acc);
+impl_matmul!(matmul_f32_3d, f32, f32, generic_array::typenum::U3, |acc| acc);
+impl_matmul!(matmul_f32_4d, f32, f32, generic_array::typenum::U4, |acc| acc);
+impl_matmul!(matmul_f32_5d, f32, f32, generic_array::typenum::U5, |acc| acc);
+impl_matmul!(matmul_f32_6d, f32, f32, generic_array::typenum::U6, |acc| acc);
+
+impl_matmul!(matmul_f64_2d, f64, f64, generic_array::typenum::U2, |acc| acc);
+impl_matmul!(matmul_f64_3d, f64, f64, generic_array::typenum::U3, |acc| acc);
+impl_matmul!(matmul_f64_4d, f64, f64, generic_array::typenum::U4, |acc| acc);
+impl_matmul!(matmul_f64_5d, f64, f64, generic_array::typenum::U5, |acc| acc);
+impl_matmul!(matmul_f64_6d, f64, f64, generic_array::typenum::U6, |acc| acc);
+
+impl_matmul!(matmul_f16_2d, f16, f32, generic_array::typenum::U2, f16::from_f32);
+impl_matmul!(matmul_f16_3d, f16, f32, generic_array::typenum::U3, f16::from_f32);
+impl_matmul!(matmul_f16_4d, f16, f32, generic_array::typenum::U4, f16::from_f32);
+impl_matmul!(matmul_f16_5d, f16, f32, generic_array::typenum::U5, f16::from_f32);
+impl_matmul!(matmul_f16_6d, f16, f32, generic_array::typenum::U6, f16::from_f32);
+
+impl_matmul!(matmul_bf16_2d, bf16, f32, generic_array::typenum::U2, bf16::from_f32);
+impl_matmul!(matmul_bf16_3d, bf16, f32, generic_array::typenum::U3, bf16::from_f32);
+impl_matmul!(matmul_bf16_4d, bf16, f32, generic_array::typenum::U4, bf16::from_f32);
+impl_matmul!(matmul_bf16_5d, bf16, f32, generic_array::typenum::U5, bf16::from_f32);
+impl_matmul!(matmul_bf16_6d, bf16, f32, generic_array::typenum::U6, bf16::from_f32);
+
+impl_matmul!(matmul_i8_2d, i8, i8, generic_array::typenum::U2, |acc| acc);
+impl_matmul!(matmul_i8_3d, i8, i8, generic_array::typenum::U3, |acc| acc);
+impl_matmul!(matmul_i8_4d, i8, i8, generic_array::typenum::U4, |acc| acc);
+impl_matmul!(matmul_i8_5d, i8, i8, generic_array::typenum::U5, |acc| acc);
+impl_matmul!(matmul_i8_6d, i8, i8, generic_array::typenum::U6, |acc| acc);
+
+impl_matmul!(matmul_i16_2d, i16, i16, generic_array::typenum::U2, |acc| acc);
+impl_matmul!(matmul_i16_3d, i16, i16, generic_array::typenum::U3, |acc| acc);
+impl_matmul!(matmul_i16_4d, i16, i16, generic_array::typenum::U4, |acc| acc);
+impl_matmul!(matmul_i16_5d, i16, i16, generic_array::typenum::U5, |acc| acc);
+impl_matmul!(matmul_i16_6d, i16, i16, generic_array::typenum::U6, |acc| acc);
+
+impl_matmul!(matmul_i32_2d, i32, i32, generic_array::typenum::U2, |acc| acc);
+impl_matmul!(matmul_i32_3d, i32, i32, generic_array::typenum::U3, |acc| acc);
+impl_matmul!(matmul_i32_4d, i32, i32, generic_array::typenum::U4, |acc| acc);
+impl_matmul!(matmul_i32_5d, i32, i32, generic_array::typenum::U5, |acc| acc);
+impl_matmul!(matmul_i32_6d, i32, i32, generic_array::typenum::U6, |acc| acc);
+
+impl_matmul!(matmul_i64_2d, i64, i64, generic_array::typenum::U2, |acc| acc);
+impl_matmul!(matmul_i64_3d, i64, i64, generic_array::typenum::U3, |acc| acc);
+impl_matmul!(matmul_i64_4d, i64, i64, generic_array::typenum::U4, |acc| acc);
+impl_matmul!(matmul_i64_5d, i64, i64, generic_array::typenum::U5, |acc| acc);
+impl_matmul!(matmul_i64_6d, i64, i64, generic_array::typenum::U6, |acc| acc);
+
+impl_matmul!(matmul_u8_2d, u8, u8, generic_array::typenum::U2, |acc| acc);
+impl_matmul!(matmul_u8_3d, u8, u8, generic_array::typenum::U3, |acc| acc);
+impl_matmul!(matmul_u8_4d, u8, u8, generic_array::typenum::U4, |acc| acc);
+impl_matmul!(matmul_u8_5d, u8, u8, generic_array::typenum::U5, |acc| acc);
+impl_matmul!(matmul_u8_6d, u8, u8, generic_array::typenum::U6, |acc| acc);
+
+impl_matmul!(matmul_u32_2d, u32, u32, generic_array::typenum::U2, |acc| acc);
+impl_matmul!(matmul_u32_3d, u32, u32, generic_array::typenum::U3, |acc| acc);
+impl_matmul!(matmul_u32_4d, u32, u32, generic_array::typenum::U4, |acc| acc);
+impl_matmul!(matmul_u32_5d, u32, u32, generic_array::typenum::U5, |acc| acc);
+impl_matmul!(matmul_u32_6d, u32, u32, generic_array::typenum::U6, |acc| acc);
+
+impl_matmul!(matmul_u64_2d, u64, u64, generic_array::typenum::U2, |acc| acc);
+impl_matmul!(matmul_u64_3d, u64, u64, generic_array::typenum::U3, |acc| acc);
+impl_matmul!(matmul_u64_4d, u64, u64, generic_array::typenum::U4, |acc| acc);
+impl_matmul!(matmul_u64_5d, u64, u64, generic_array::typenum::U5, |acc| acc);
+impl_matmul!(matmul_u64_6d, u64, u64, generic_array::typenum::U6, |acc| acc);
 
 #[inline(always)]
 fn apply_op_broadcast<
@@ -156,6 +2560,281 @@ fn apply_op_broadcast<
     }
 }
 
+/// Computes `o[idx] = op(o[idx], b[..])` for the output `o` of `o_strides`, reading `b` like
+/// [`apply_op_broadcast`] does.
+#[inline(always)]
+fn apply_op_assign<
+    D: Into<[usize; DIMS]>,
+    O: Copy,
+    R: Copy,
+    F: Fn(O, R) -> O,
+    const DIMS: usize,
+>(
+    o: &mut [O],
+    o_strides: D,
+    b: &[R],
+    b_strides: D,
+    op: F,
+    idx: usize,
+) {
+    let o_strides = o_strides.into();
+    let b_strides = b_strides.into();
+    if idx < o.len() {
+        let mut b_idx = 0;
+        let mut o_idx = idx;
+        for i in 0..DIMS {
+            let o_idx_dim = o_idx / o_strides[i];
+            b_idx += o_idx_dim * b_strides[i];
+            o_idx -= o_idx_dim * o_strides[i];
+        }
+        o[idx] = op(o[idx], b[b_idx]);
+    }
+}
+
+/// Like [`apply_op_broadcast`], but for three inputs.
+#[inline(always)]
+#[allow(clippy::too_many_arguments)]
+fn apply_op_broadcast3<
+    D: Into<[usize; DIMS]>,
+    A: Copy,
+    B: Copy,
+    C: Copy,
+    O: Copy,
+    F: Fn(A, B, C) -> O,
+    const DIMS: usize,
+>(
+    a: &[A],
+    a_strides: D,
+    b: &[B],
+    b_strides: D,
+    c: &[C],
+    c_strides: D,
+    o: &mut [O],
+    o_strides: D,
+    op: F,
+    idx: usize,
+) {
+    let a_strides = a_strides.into();
+    let b_strides = b_strides.into();
+    let c_strides = c_strides.into();
+    let o_strides = o_strides.into();
+    if idx < o.len() {
+        let mut a_idx = 0;
+        let mut b_idx = 0;
+        let mut c_idx = 0;
+        let mut o_idx = idx;
+        for i in 0..DIMS {
+            let o_stride = o_strides[i];
+            let o_idx_dim = o_idx / o_stride;
+            a_idx += o_idx_dim * a_strides[i];
+            b_idx += o_idx_dim * b_strides[i];
+            c_idx += o_idx_dim * c_strides[i];
+            o_idx -= o_idx_dim * o_stride;
+        }
+        o[idx] = op(a[a_idx], b[b_idx], c[c_idx]);
+    }
+}
+
+/// Folds the elements `tid`, `tid + threads`, ... of the axis reduced into output element `idx`,
+/// with `a` seen as `[outer, len, inner]`.
+#[inline(always)]
+#[allow(clippy::too_many_arguments)]
+fn reduce_partial<T: Copy, A: Copy + From<T>, F: Fn(A, A) -> A>(
+    a: &[T],
+    idx: usize,
+    len: usize,
+    inner: usize,
+    tid: usize,
+    threads: usize,
+    init: A,
+    op: F,
+) -> A {
+    let (outer, i) = (idx / inner, idx % inner);
+    let mut acc = init;
+    let mut j = tid;
+    while j < len {
+        acc = op(acc, A::from(a[(outer * len + j) * inner + i]));
+        j += threads;
+    }
+
+    acc
+}
+
+/// One level of the tree reduction, thread `tid` folds the value `step` places after its own.
+#[inline(always)]
+fn reduce_step<T: Copy, F: Fn(T, T) -> T>(shared: &mut [T], tid: usize, step: usize, op: F) {
+    if tid < step {
+        shared[tid] = op(shared[tid], shared[tid + step]);
+    }
+}
+
+/// The best of the elements `tid`, `tid + threads`, ... of the axis reduced into output element
+/// `idx` and its position, `u32::MAX` if the thread has no element.
+#[inline(always)]
+fn arg_partial<T: Copy, F: Fn(T, T) -> bool>(
+    a: &[T],
+    idx: usize,
+    len: usize,
+    inner: usize,
+    tid: usize,
+    threads: usize,
+    better: F,
+) -> (T, u32) {
+    let (outer, i) = (idx / inner, idx % inner);
+    let mut best = (a[0], u32::MAX);
+    let mut j = tid;
+    while j < len {
+        let x = a[(outer * len + j) * inner + i];
+        if best.1 == u32::MAX || better(x, best.0) {
+            best = (x, j as u32);
+        }
+        j += threads;
+    }
+
+    best
+}
+
+/// One level of the tree reduction of `arg_partial` results, ties keep the first position.
+#[inline(always)]
+fn arg_step<T: Copy, F: Fn(T, T) -> bool>(
+    values: &mut [T],
+    positions: &mut [u32],
+    tid: usize,
+    step: usize,
+    better: F,
+) {
+    if tid < step {
+        let (x, j) = (values[tid + step], positions[tid + step]);
+        let replace = j != u32::MAX && (
+            positions[tid] == u32::MAX
+                || better(x, values[tid])
+                || (!better(values[tid], x) && j < positions[tid])
+        );
+        if replace {
+            values[tid] = x;
+            positions[tid] = j;
+        }
+    }
+}
+
+/// Writes the `values.len()` largest elements of `row` in descending order and their positions,
+/// by insertion into the sorted front of `values`.
+#[inline(always)]
+fn apply_topk<T: Copy + PartialOrd>(row: &[T], values: &mut [T], indices: &mut [u32]) {
+    let k = values.len();
+    let mut filled = 0;
+    for (j, &x) in row.iter().enumerate() {
+        let mut pos = filled;
+        while pos > 0 && x > values[pos - 1] {
+            pos -= 1;
+        }
+        if pos < k {
+            filled = (filled + 1).min(k);
+            let mut p = filled - 1;
+            while p > pos {
+                values[p] = values[p - 1];
+                indices[p] = indices[p - 1];
+                p -= 1;
+            }
+            values[pos] = x;
+            indices[pos] = j as u32;
+        }
+    }
+}
+
+#[inline(always)]
+fn apply_unary<I: Copy, O, F: Fn(I, I) -> O>(a: &[I], o: &mut [O], param: I, op: F, idx: usize) {
+    if idx < o.len() {
+        o[idx] = op(a[idx], param);
+    }
+}
+
+/// The tanh approximation of GELU, `0.5 x (1 + tanh(sqrt(2 / pi) (x + 0.044715 x^3)))`.
+#[inline(always)]
+fn gelu_approx_f32(x: f32) -> f32 {
+    0.5 * x * (1.0 + (0.797_884_6 * (x + 0.044715 * x * x * x)).tanh())
+}
+
+#[inline(always)]
+fn gelu_approx_f64(x: f64) -> f64 {
+    0.5 * x * (1.0 + (0.7978845608028654 * (x + 0.044715 * x * x * x)).tanh())
+}
+
+/// Copies one element of `a`, read at `a_offset` with `a_strides`, into the contiguous `o`.
+#[inline(always)]
+fn apply_permute<D: Into<[usize; DIMS]>, T: Copy, const DIMS: usize>(
+    a: &[T],
+    a_offset: usize,
+    a_strides: D,
+    o: &mut [T],
+    o_strides: D,
+    idx: usize,
+) {
+    let a_strides = a_strides.into();
+    let o_strides = o_strides.into();
+    if idx < o.len() {
+        let mut a_idx = a_offset;
+        let mut o_idx = idx;
+        for i in 0..DIMS {
+            let o_idx_dim = o_idx / o_strides[i];
+            a_idx += o_idx_dim * a_strides[i];
+            o_idx -= o_idx_dim * o_strides[i];
+        }
+        o[idx] = a[a_idx];
+    }
+}
+
+/// Computes one element of a batched matrix product.
+///
+/// The batch axes map onto both inputs, the second to last axis (the rows) only onto `a` and the
+/// last axis (the columns) only onto `b`. The sum runs over `k` using the last stride of `a` and
+/// the second to last stride of `b`.
+#[inline(always)]
+#[allow(clippy::too_many_arguments)]
+fn apply_matmul<
+    D: Into<[usize; DIMS]>,
+    T: Copy,
+    A: Arith + From<T>,
+    F: Fn(A) -> T,
+    const DIMS: usize,
+>(
+    a: &[T],
+    a_strides: D,
+    b: &[T],
+    b_strides: D,
+    o: &mut [T],
+    o_strides: D,
+    k: usize,
+    zero: A,
+    finish: F,
+    idx: usize,
+) {
+    let a_strides = a_strides.into();
+    let b_strides = b_strides.into();
+    let o_strides = o_strides.into();
+    if idx < o.len() {
+        let mut a_idx = 0;
+        let mut b_idx = 0;
+        let mut o_idx = idx;
+        for i in 0..DIMS {
+            let o_idx_dim = o_idx / o_strides[i];
+            if i != DIMS - 1 {
+                a_idx += o_idx_dim * a_strides[i];
+            }
+            if i != DIMS - 2 {
+                b_idx += o_idx_dim * b_strides[i];
+            }
+            o_idx -= o_idx_dim * o_strides[i];
+        }
+        let mut acc = zero;
+        for kk in 0..k {
+            let product = Arith::mul(A::from(a[a_idx + kk * a_strides[DIMS - 1]]), A::from(b[b_idx + kk * b_strides[DIMS - 2]]));
+            acc = Arith::add(acc, product);
+        }
+        o[idx] = finish(acc);
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -187,4 +2866,149 @@ mod test {
             4.0, 5.0, 6.0,
         ]);
     }
+
+    #[test]
+    fn test_add_assign() {
+        let mut a = [1.0, 2.0, 3.0, 4.0];
+        let b = [10.0, 20.0];
+        for idx in 0..100 {
+            apply_op_assign(&mut a, [2, 1], &b, [1, 0], |a, b| a - b, idx);
+        }
+
+        std::assert_eq!(a, [-9.0, -8.0, -17.0, -16.0]);
+    }
+
+    #[test]
+    fn test_where() {
+        // a [2] condition selecting between a [2, 1] and a scalar
+        let cond = [true, false];
+        let a = [1.0, 2.0];
+        let b = [0.0];
+        let mut o = [9.0; 4];
+        for idx in 0..100 {
+            apply_op_broadcast3(
+                &cond,
+                [0, 1],
+                &a,
+                [1, 0],
+                &b,
+                [0, 0],
+                &mut o,
+                [2, 1],
+                |cond, a, b| if cond { a } else { b },
+                idx,
+            );
+        }
+
+        std::assert_eq!(o, [1.0, 0.0, 2.0, 0.0]);
+    }
+
+    #[test]
+    fn test_matmul() {
+        // [2, 3] x [3, 2], with `b` stored transposed
+        let a = [1.0, 2.0, 3.0, 4.0, 5.0, 6.0];
+        let b = [1.0, 3.0, 5.0, 2.0, 4.0, 6.0];
+        let mut o = [0.0; 4];
+        let a_strides = [3, 1];
+        let b_strides = [1, 3];
+        let o_strides = [2, 1];
+        for idx in 0..100 {
+            apply_matmul(&a, a_strides, &b, b_strides, &mut o, o_strides, 3, 0.0, |acc| acc, idx);
+        }
+
+        std::assert_eq!(o, [
+            22.0, 28.0,
+            49.0, 64.0,
+        ]);
+    }
+
+    #[test]
+    fn test_permute() {
+        // transposes a [2, 3] matrix stored after one leading element
+        let a = [0, 1, 2, 3, 4, 5, 6];
+        let mut o = [0; 6];
+        let a_strides = [1, 3];
+        let o_strides = [2, 1];
+        for idx in 0..100 {
+            apply_permute(&a, 1, a_strides, &mut o, o_strides, idx);
+        }
+
+        std::assert_eq!(o, [1, 4, 2, 5, 3, 6]);
+    }
+
+    #[test]
+    fn test_reduce() {
+        // sums the middle axis of a [2, 3, 2] tensor with 4 threads per output element
+        let a = [1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0, 10.0, 11.0, 12.0];
+        let mut o = [0.0; 4];
+        for (idx, o) in o.iter_mut().enumerate() {
+            let mut shared = [0.0; 4];
+            for (tid, shared) in shared.iter_mut().enumerate() {
+                *shared = reduce_partial(&a, idx, 3, 2, tid, 4, 0.0, |a, b| a + b);
+            }
+            let mut step = 2;
+            while step > 0 {
+                for tid in 0..4 {
+                    reduce_step(&mut shared, tid, step, |a, b| a + b);
+                }
+                step /= 2;
+            }
+            *o = shared[0];
+        }
+
+        std::assert_eq!(o, [9.0, 12.0, 27.0, 30.0]);
+    }
+
+    #[test]
+    fn test_arg_reduce() {
+        // argmax of the rows of a [2, 5] tensor with 2 threads per output element
+        let a = [1.0, 5.0, 2.0, 5.0, 0.0, 3.0, 1.0, 4.0, 1.0, 4.0];
+        let mut o = [0; 2];
+        for (idx, o) in o.iter_mut().enumerate() {
+            let mut values = [0.0; 2];
+            let mut positions = [0; 2];
+            for tid in 0..2 {
+                (values[tid], positions[tid]) = arg_partial(&a, idx, 5, 1, tid, 2, |x, best| x > best);
+            }
+            arg_step(&mut values, &mut positions, 0, 1, |x, best| x > best);
+            *o = positions[0];
+        }
+
+        std::assert_eq!(o, [1, 2]);
+    }
+
+    #[test]
+    fn test_topk() {
+        let row = [3.0, 1.0, 4.0, 1.0, 5.0, 9.0, 2.0, 6.0];
+        let mut values = [0.0; 3];
+        let mut indices = [0; 3];
+        apply_topk(&row, &mut values, &mut indices);
+
+        std::assert_eq!(values, [9.0, 6.0, 5.0]);
+        std::assert_eq!(indices, [5, 7, 4]);
+    }
+
+    #[test]
+    fn test_unary() {
+        let a = [-1.0, 0.0, 2.0];
+        let mut o = [0.0; 3];
+        for idx in 0..100 {
+            apply_unary(&a, &mut o, 3.0, |x: f32, p| if x > 0.0 { x.powf(p) } else { 0.0 }, idx);
+        }
+
+        std::assert_eq!(o, [0.0, 0.0, 8.0]);
+        std::assert!((gelu_approx_f32(1.0) - 0.841192).abs() < 1e-5);
+    }
+
+    #[test]
+    fn test_cast() {
+        std::assert_eq!(Cast::<i32>::cast(-2.5f32), -2);
+        std::assert_eq!(Cast::<u8>::cast(f32::NAN), 0);
+        std::assert_eq!(Cast::<u8>::cast(-3i64), 0);
+        std::assert_eq!(Cast::<i8>::cast(1000u32), i8::MAX);
+        std::assert!(Cast::<bool>::cast(f16::from_f32(0.5)));
+        std::assert_eq!(Cast::<bf16>::cast(true), bf16::ONE);
+        std::assert_eq!(Cast::<bf16>::cast((1i64 << 62) + (1 << 54) + 1), bf16::from_f32(((1u64 << 62) + (1 << 55)) as f32));
+        std::assert_eq!(Cast::<bf16>::cast(1.0 + 2f64.powi(-8) + 2f64.powi(-40)), bf16::from_f32(1.0 + 2f32.powi(-7)));
+    }
 }
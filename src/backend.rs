@@ -0,0 +1,204 @@
+mod cpu;
+#[cfg(feature = "cuda")]
+mod cuda;
+pub mod ops;
+
+pub use cpu::Cpu;
+#[cfg(feature = "cuda")]
+pub use cuda::Cuda;
+
+use half::{bf16, f16};
+use crate::backend::ops::{Arith, ArgReduceOp, BinaryOp, ReduceOp, UnaryOp};
+use crate::error::DeeperError;
+
+/// Types that can be copied to the device.
+///
+/// With the `cuda` feature this is `cust`'s `DeviceCopy`, without it every type qualifies.
+#[cfg(feature = "cuda")]
+pub trait DeviceElement: cust::memory::DeviceCopy {}
+#[cfg(feature = "cuda")]
+impl<T: cust::memory::DeviceCopy> DeviceElement for T {}
+#[cfg(not(feature = "cuda"))]
+pub trait DeviceElement {}
+#[cfg(not(feature = "cuda"))]
+impl<T> DeviceElement for T {}
+
+/// Element types a tensor can hold.
+///
+/// `NAME` is the suffix used by the kernels in the `cuda` crate, e.g. `f32` in `add_f32_2d`.
+/// `Acc` is the type reductions and matrix products sum in, which is `f32` for the half precision
+/// types and the type itself otherwise.
+///
+/// Integer arithmetic wraps around on overflow and never panics, see [`Arith`](ops::Arith) and
+/// [`Integer`](ops::Integer) for division by zero and shifts.
+pub trait DType: Copy + Default + DeviceElement + 'static {
+    type Acc: DType;
+    const NAME: &'static str;
+    fn to_acc(self) -> Self::Acc;
+    fn from_acc(acc: Self::Acc) -> Self;
+}
+
+macro_rules! dtype {
+    ($($ty:ty),*) => {
+        $(
+            impl DType for $ty {
+                type Acc = $ty;
+                const NAME: &'static str = stringify!($ty);
+                #[inline(always)]
+                fn to_acc(self) -> $ty {
+                    self
+                }
+                #[inline(always)]
+                fn from_acc(acc: $ty) -> $ty {
+                    acc
+                }
+            }
+        )*
+    };
+}
+
+dtype!(f32, f64, bool, i8, i16, i32, i64, u8, u32, u64);
+
+macro_rules! half_dtype {
+    ($($ty:ident),*) => {
+        $(
+            impl DType for $ty {
+                type Acc = f32;
+                const NAME: &'static str = stringify!($ty);
+                #[inline(always)]
+                fn to_acc(self) -> f32 {
+                    self.to_f32()
+                }
+                #[inline(always)]
+                fn from_acc(acc: f32) -> $ty {
+                    $ty::from_f32(acc)
+                }
+            }
+        )*
+    };
+}
+
+half_dtype!(f16, bf16);
+
+/// A device tensors can live on.
+///
+/// A backend owns the storage of a tensor and knows how to move data between the host and its
+/// storage and how to run the elementwise kernels on it. Strides are passed as plain slices, one
+/// entry per axis of the output, in row-major order.
+pub trait Backend: Sized {
+    type Storage<T: DType>;
+
+    /// Allocates storage for `size` elements, the contents are unspecified.
+    fn alloc<T: DType>(size: usize) -> Result<Self::Storage<T>, DeeperError>;
+    /// Moves host data into the backend.
+    fn from_vec<T: DType>(data: Vec<T>) -> Result<Self::Storage<T>, DeeperError>;
+    /// Copies the storage back to the host.
+    fn to_vec<T: DType>(storage: &Self::Storage<T>) -> Result<Vec<T>, DeeperError>;
+
+    /// Computes `o[i] = op.apply(a[i])` for every element of the contiguous `a` and `o`.
+    fn unary<Op: UnaryOp<T>, T: DType>(
+        op: &Op,
+        a: &Self::Storage<T>,
+        o: &mut Self::Storage<Op::Output>,
+    ) -> Result<(), DeeperError>;
+
+    /// Computes `o[i] = Op::apply(a[..], b[..])` for every element of `o`, using the strides to
+    /// map the output index onto the (possibly broadcast) inputs.
+    fn binary<Op: BinaryOp<L, R>, L: DType, R: DType>(
+        a: &Self::Storage<L>,
+        a_strides: &[usize],
+        b: &Self::Storage<R>,
+        b_strides: &[usize],
+        o: &mut Self::Storage<Op::Output>,
+        o_strides: &[usize],
+    ) -> Result<(), DeeperError>;
+
+    /// Computes `a[i] = Op::apply(a[i], b[..])` in place for the contiguous `a` of `a_strides`,
+    /// using `b_strides` like for [`Backend::binary`].
+    fn binary_assign<Op: BinaryOp<L, R, Output = L>, L: DType, R: DType>(
+        a: &mut Self::Storage<L>,
+        a_strides: &[usize],
+        b: &Self::Storage<R>,
+        b_strides: &[usize],
+    ) -> Result<(), DeeperError>;
+
+    /// Like [`Backend::binary_assign`], but the result replaces the right operand,
+    /// `b[i] = Op::apply(a[..], b[i])`.
+    fn binary_assign_rhs<Op: BinaryOp<L, R, Output = R>, L: DType, R: DType>(
+        a: &Self::Storage<L>,
+        a_strides: &[usize],
+        b: &mut Self::Storage<R>,
+        b_strides: &[usize],
+    ) -> Result<(), DeeperError>;
+
+    /// Computes `o[i] = if cond[..] { a[..] } else { b[..] }` for every element of `o`, with the
+    /// strides used like for [`Backend::binary`].
+    #[allow(clippy::too_many_arguments)]
+    fn where_<T: DType>(
+        cond: &Self::Storage<bool>,
+        cond_strides: &[usize],
+        a: &Self::Storage<T>,
+        a_strides: &[usize],
+        b: &Self::Storage<T>,
+        b_strides: &[usize],
+        o: &mut Self::Storage<T>,
+        o_strides: &[usize],
+    ) -> Result<(), DeeperError>;
+
+    /// Reduces the contiguous `a`, seen as `[o.len() / inner, len, inner]`, along its middle axis
+    /// into `o`.
+    fn reduce<Op: ReduceOp<T>, T: DType>(
+        a: &Self::Storage<T>,
+        o: &mut Self::Storage<T>,
+        len: usize,
+        inner: usize,
+    ) -> Result<(), DeeperError>;
+
+    /// Like [`Backend::reduce`], but writes the position of the selected element along the axis.
+    /// The axis must not be empty.
+    fn arg_reduce<Op: ArgReduceOp<T>, T: DType>(
+        a: &Self::Storage<T>,
+        o: &mut Self::Storage<u32>,
+        len: usize,
+        inner: usize,
+    ) -> Result<(), DeeperError>;
+
+    /// Writes the `k` largest elements of every row of the contiguous `a`, seen as
+    /// `[values.len() / k, len]`, in descending order to `values` and their positions to
+    /// `indices`.
+    fn topk<T: DType + PartialOrd>(
+        a: &Self::Storage<T>,
+        values: &mut Self::Storage<T>,
+        indices: &mut Self::Storage<u32>,
+        len: usize,
+        k: usize,
+    ) -> Result<(), DeeperError>;
+
+    /// Copies `a` into the contiguous `o`, reading the element of output index `i` at `a_offset`
+    /// plus the position given by `a_strides`. With permuted strides this transposes `a`.
+    fn permute<T: DType>(
+        a: &Self::Storage<T>,
+        a_offset: usize,
+        a_strides: &[usize],
+        o: &mut Self::Storage<T>,
+        o_strides: &[usize],
+    ) -> Result<(), DeeperError>;
+
+    /// Batched matrix product `o = a x b` with `k` as the length of the contracted axis.
+    ///
+    /// `o_dims` are the dimensions of the contiguous output, the last two are the rows and
+    /// columns. The strides of `a` and `b` have one entry per output axis: the (broadcast) batch
+    /// strides, then the strides along the rows and `k` for `a` and along `k` and the columns for
+    /// `b`. The products are summed in `T::Acc` with [`Arith`], so integers wrap around.
+    fn matmul<T: DType>(
+        a: &Self::Storage<T>,
+        a_strides: &[usize],
+        b: &Self::Storage<T>,
+        b_strides: &[usize],
+        o: &mut Self::Storage<T>,
+        o_dims: &[usize],
+        k: usize,
+    ) -> Result<(), DeeperError>
+        where
+            T::Acc: Arith;
+}
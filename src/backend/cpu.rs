@@ -0,0 +1,285 @@
+use crate::backend::{Backend, DType};
+use crate::backend::ops::{Arith, ArgReduceOp, BinaryOp, ReduceOp, UnaryOp};
+use crate::error::DeeperError;
+
+/// Edge length of the tiles the matrix product is computed in.
+const BLOCK: usize = 64;
+
+/// Host backend, the storage is a plain `Vec`.
+#[derive(Copy, Clone, Debug, Default)]
+pub struct Cpu;
+
+impl Backend for Cpu {
+    type Storage<T: DType> = Vec<T>;
+
+    fn alloc<T: DType>(size: usize) -> Result<Vec<T>, DeeperError> {
+        Ok(vec![T::default(); size])
+    }
+
+    fn from_vec<T: DType>(data: Vec<T>) -> Result<Vec<T>, DeeperError> {
+        Ok(data)
+    }
+
+    fn to_vec<T: DType>(storage: &Vec<T>) -> Result<Vec<T>, DeeperError> {
+        Ok(storage.clone())
+    }
+
+    fn unary<Op: UnaryOp<T>, T: DType>(
+        op: &Op,
+        a: &Vec<T>,
+        o: &mut Vec<Op::Output>,
+    ) -> Result<(), DeeperError> {
+        for (o, &a) in o.iter_mut().zip(a) {
+            *o = op.apply(a);
+        }
+
+        Ok(())
+    }
+
+    fn binary<Op: BinaryOp<L, R>, L: DType, R: DType>(
+        a: &Vec<L>,
+        a_strides: &[usize],
+        b: &Vec<R>,
+        b_strides: &[usize],
+        o: &mut Vec<Op::Output>,
+        o_strides: &[usize],
+    ) -> Result<(), DeeperError> {
+        // Same index arithmetic as `apply_op_broadcast` in the cuda crate, one iteration per thread.
+        for (idx, o) in o.iter_mut().enumerate() {
+            let mut a_idx = 0;
+            let mut b_idx = 0;
+            let mut o_idx = idx;
+            for i in 0..o_strides.len() {
+                let o_idx_dim = o_idx / o_strides[i];
+                a_idx += o_idx_dim * a_strides[i];
+                b_idx += o_idx_dim * b_strides[i];
+                o_idx -= o_idx_dim * o_strides[i];
+            }
+            *o = Op::apply(a[a_idx], b[b_idx]);
+        }
+
+        Ok(())
+    }
+
+    fn binary_assign<Op: BinaryOp<L, R, Output = L>, L: DType, R: DType>(
+        a: &mut Vec<L>,
+        a_strides: &[usize],
+        b: &Vec<R>,
+        b_strides: &[usize],
+    ) -> Result<(), DeeperError> {
+        for (idx, a) in a.iter_mut().enumerate() {
+            let mut b_idx = 0;
+            let mut a_idx = idx;
+            for i in 0..a_strides.len() {
+                let a_idx_dim = a_idx / a_strides[i];
+                b_idx += a_idx_dim * b_strides[i];
+                a_idx -= a_idx_dim * a_strides[i];
+            }
+            *a = Op::apply(*a, b[b_idx]);
+        }
+
+        Ok(())
+    }
+
+    fn binary_assign_rhs<Op: BinaryOp<L, R, Output = R>, L: DType, R: DType>(
+        a: &Vec<L>,
+        a_strides: &[usize],
+        b: &mut Vec<R>,
+        b_strides: &[usize],
+    ) -> Result<(), DeeperError> {
+        for (idx, b) in b.iter_mut().enumerate() {
+            let mut a_idx = 0;
+            let mut b_idx = idx;
+            for i in 0..b_strides.len() {
+                let b_idx_dim = b_idx / b_strides[i];
+                a_idx += b_idx_dim * a_strides[i];
+                b_idx -= b_idx_dim * b_strides[i];
+            }
+            *b = Op::apply(a[a_idx], *b);
+        }
+
+        Ok(())
+    }
+
+    fn where_<T: DType>(
+        cond: &Vec<bool>,
+        cond_strides: &[usize],
+        a: &Vec<T>,
+        a_strides: &[usize],
+        b: &Vec<T>,
+        b_strides: &[usize],
+        o: &mut Vec<T>,
+        o_strides: &[usize],
+    ) -> Result<(), DeeperError> {
+        // Same index arithmetic as `apply_op_broadcast3` in the cuda crate.
+        for (idx, o) in o.iter_mut().enumerate() {
+            let mut cond_idx = 0;
+            let mut a_idx = 0;
+            let mut b_idx = 0;
+            let mut o_idx = idx;
+            for i in 0..o_strides.len() {
+                let o_idx_dim = o_idx / o_strides[i];
+                cond_idx += o_idx_dim * cond_strides[i];
+                a_idx += o_idx_dim * a_strides[i];
+                b_idx += o_idx_dim * b_strides[i];
+                o_idx -= o_idx_dim * o_strides[i];
+            }
+            *o = if cond[cond_idx] { a[a_idx] } else { b[b_idx] };
+        }
+
+        Ok(())
+    }
+
+    fn reduce<Op: ReduceOp<T>, T: DType>(
+        a: &Vec<T>,
+        o: &mut Vec<T>,
+        len: usize,
+        inner: usize,
+    ) -> Result<(), DeeperError> {
+        for (idx, o) in o.iter_mut().enumerate() {
+            let (outer, i) = (idx / inner, idx % inner);
+            let mut acc = Op::init();
+            for j in 0..len {
+                acc = Op::apply(acc, a[(outer * len + j) * inner + i]);
+            }
+            *o = Op::finish(acc, len);
+        }
+
+        Ok(())
+    }
+
+    fn arg_reduce<Op: ArgReduceOp<T>, T: DType>(
+        a: &Vec<T>,
+        o: &mut Vec<u32>,
+        len: usize,
+        inner: usize,
+    ) -> Result<(), DeeperError> {
+        for (idx, o) in o.iter_mut().enumerate() {
+            let (outer, i) = (idx / inner, idx % inner);
+            let mut best = 0;
+            for j in 1..len {
+                if Op::better(a[(outer * len + j) * inner + i], a[(outer * len + best) * inner + i]) {
+                    best = j;
+                }
+            }
+            *o = best as u32;
+        }
+
+        Ok(())
+    }
+
+    fn topk<T: DType + PartialOrd>(
+        a: &Vec<T>,
+        values: &mut Vec<T>,
+        indices: &mut Vec<u32>,
+        len: usize,
+        k: usize,
+    ) -> Result<(), DeeperError> {
+        if k == 0 {
+            return Ok(());
+        }
+        let rows = values.chunks_mut(k).zip(indices.chunks_mut(k));
+        for (row, (values, indices)) in rows.enumerate() {
+            // Insertion into the sorted first `filled` entries, like `apply_topk` in the cuda crate.
+            let mut filled = 0;
+            for j in 0..len {
+                let x = a[row * len + j];
+                let mut pos = filled;
+                while pos > 0 && x > values[pos - 1] {
+                    pos -= 1;
+                }
+                if pos < k {
+                    filled = (filled + 1).min(k);
+                    for p in (pos + 1..filled).rev() {
+                        values[p] = values[p - 1];
+                        indices[p] = indices[p - 1];
+                    }
+                    values[pos] = x;
+                    indices[pos] = j as u32;
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    fn permute<T: DType>(
+        a: &Vec<T>,
+        a_offset: usize,
+        a_strides: &[usize],
+        o: &mut Vec<T>,
+        o_strides: &[usize],
+    ) -> Result<(), DeeperError> {
+        for (idx, o) in o.iter_mut().enumerate() {
+            let mut a_idx = a_offset;
+            let mut o_idx = idx;
+            for i in 0..o_strides.len() {
+                let o_idx_dim = o_idx / o_strides[i];
+                a_idx += o_idx_dim * a_strides[i];
+                o_idx -= o_idx_dim * o_strides[i];
+            }
+            *o = a[a_idx];
+        }
+
+        Ok(())
+    }
+
+    fn matmul<T: DType>(
+        a: &Vec<T>,
+        a_strides: &[usize],
+        b: &Vec<T>,
+        b_strides: &[usize],
+        o: &mut Vec<T>,
+        o_dims: &[usize],
+        k: usize,
+    ) -> Result<(), DeeperError>
+        where
+            T::Acc: Arith,
+    {
+        let rank = o_dims.len();
+        let (m, n) = (o_dims[rank - 2], o_dims[rank - 1]);
+        let (a_row, a_k) = (a_strides[rank - 2], a_strides[rank - 1]);
+        let (b_k, b_col) = (b_strides[rank - 2], b_strides[rank - 1]);
+        if m * n == 0 {
+            return Ok(());
+        }
+
+        let mut acc = vec![T::Acc::default(); m * n];
+        for (batch, o) in o.chunks_mut(m * n).enumerate() {
+            // Offsets of this batch in the inputs, the batch axes are unravelled last axis first.
+            let mut a_offset = 0;
+            let mut b_offset = 0;
+            let mut rest = batch;
+            for axis in (0..rank - 2).rev() {
+                let idx = rest % o_dims[axis];
+                rest /= o_dims[axis];
+                a_offset += idx * a_strides[axis];
+                b_offset += idx * b_strides[axis];
+            }
+
+            // Tiled i-k-j loop, the innermost loop runs along a row of `b` and `acc`.
+            acc.fill(T::Acc::default());
+            for i0 in (0..m).step_by(BLOCK) {
+                for k0 in (0..k).step_by(BLOCK) {
+                    for j0 in (0..n).step_by(BLOCK) {
+                        for i in i0..(i0 + BLOCK).min(m) {
+                            let acc_row = &mut acc[i * n..(i + 1) * n];
+                            for kk in k0..(k0 + BLOCK).min(k) {
+                                let a = a[a_offset + i * a_row + kk * a_k].to_acc();
+                                let b_row = b_offset + kk * b_k;
+                                for j in j0..(j0 + BLOCK).min(n) {
+                                    acc_row[j] = Arith::add(acc_row[j], Arith::mul(a, b[b_row + j * b_col].to_acc()));
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+            for (o, &acc) in o.iter_mut().zip(&acc) {
+                *o = T::from_acc(acc);
+            }
+        }
+
+        Ok(())
+    }
+}
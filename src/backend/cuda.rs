@@ -0,0 +1,483 @@
+use cust::error::CudaError;
+use cust::function::Function;
+use cust::memory::{CopyDestination, DeviceBuffer, DeviceCopy};
+use cust::module::Module;
+use cust::stream::Stream;
+use cust::util::SliceExt;
+use crate::backend::{Backend, DType};
+use crate::backend::ops::{Arith, ArgReduceOp, BinaryOp, ReduceOp, UnaryOp};
+use crate::error::DeeperError;
+
+/// The highest rank the kernels in the `cuda` crate are generated for.
+const MAX_DIMS: usize = 6;
+/// Threads per block of the reduction kernels, has to match `REDUCE_BLOCK` in the `cuda` crate.
+const REDUCE_BLOCK: u32 = 256;
+
+/// CUDA backend, the storage is a `DeviceBuffer` on the thread's context.
+#[derive(Copy, Clone, Debug, Default)]
+pub struct Cuda;
+
+/// Runs `f` on the thread's stream, making sure the context is set up first.
+fn with_stream<R>(
+    f: impl FnOnce(&Stream) -> Result<R, DeeperError>,
+) -> Result<R, DeeperError> {
+    crate::CTX.with(|ctx| {
+        ctx.as_ref().map_err(|err| DeeperError::Device(*err))?;
+        crate::STREAM.with(|stream| {
+            f(stream.as_ref().map_err(|err| DeeperError::Device(*err))?)
+        })
+    })
+}
+
+fn with_module<R>(
+    f: impl FnOnce(&Stream, &Module) -> Result<R, DeeperError>,
+) -> Result<R, DeeperError> {
+    with_stream(|stream| crate::MODULE.with(|module| {
+        f(stream, module.as_ref().map_err(|err| DeeperError::Device(*err))?)
+    }))
+}
+
+fn get_function(module: &Module, name: String) -> Result<Function<'_>, DeeperError> {
+    module.get_function(&name).map_err(|err| match err {
+        CudaError::NotFound => DeeperError::KernelNotFound(name),
+        err => DeeperError::Device(err),
+    })
+}
+
+impl Backend for Cuda {
+    type Storage<T: DType> = DeviceBuffer<T>;
+
+    fn alloc<T: DType>(size: usize) -> Result<DeviceBuffer<T>, DeeperError> {
+        with_stream(|_stream| {
+            unsafe { DeviceBuffer::uninitialized(size) }
+                .map_err(|source| DeeperError::Allocation { size, source })
+        })
+    }
+
+    fn from_vec<T: DType>(data: Vec<T>) -> Result<DeviceBuffer<T>, DeeperError> {
+        with_stream(|_stream| data.as_slice().as_dbuf().map_err(DeeperError::Transfer))
+    }
+
+    fn to_vec<T: DType>(storage: &DeviceBuffer<T>) -> Result<Vec<T>, DeeperError> {
+        let mut data = vec![T::default(); storage.len()];
+        with_stream(|stream| {
+            stream.synchronize().map_err(DeeperError::Device)?;
+            storage.copy_to(&mut data).map_err(DeeperError::Transfer)
+        })?;
+
+        Ok(data)
+    }
+
+    fn unary<Op: UnaryOp<T>, T: DType>(
+        op: &Op,
+        a: &DeviceBuffer<T>,
+        o: &mut DeviceBuffer<Op::Output>,
+    ) -> Result<(), DeeperError> {
+        if o.is_empty() {
+            return Ok(());
+        }
+        let size = o.len();
+        with_module(|stream, module| {
+            let func = get_function(module, format!("{}_{}", Op::NAME, T::NAME))?;
+            let (grid_size, block_size) = launch_config(&func, size)?;
+            let param = op.param();
+
+            unsafe {
+                cust::launch!(
+                    func<<<grid_size, block_size, 0, stream>>>(
+                        a.as_device_ptr(),
+                        a.len(),
+                        o.as_device_ptr(),
+                        o.len(),
+                        param,
+                    )
+                ).map_err(DeeperError::Device)
+            }
+        })
+    }
+
+    fn binary<Op: BinaryOp<L, R>, L: DType, R: DType>(
+        a: &DeviceBuffer<L>,
+        a_strides: &[usize],
+        b: &DeviceBuffer<R>,
+        b_strides: &[usize],
+        o: &mut DeviceBuffer<Op::Output>,
+        o_strides: &[usize],
+    ) -> Result<(), DeeperError> {
+        if o.is_empty() {
+            return Ok(());
+        }
+        let size = o.len();
+        with_module(|stream, module| {
+            let func = get_function(module, format!(
+                "{}_{}_{}d",
+                Op::NAME,
+                L::NAME,
+                o_strides.len().max(1),
+            ))?;
+            let (grid_size, block_size) = launch_config(&func, size)?;
+            let a_strides = KernelStrides::input(a_strides);
+            let b_strides = KernelStrides::input(b_strides);
+            let o_strides = KernelStrides::output(o_strides);
+
+            unsafe {
+                cust::launch!(
+                    func<<<grid_size, block_size, 0, stream>>>(
+                        a.as_device_ptr(),
+                        a.len(),
+                        a_strides,
+                        b.as_device_ptr(),
+                        b.len(),
+                        b_strides,
+                        o.as_device_ptr(),
+                        o.len(),
+                        o_strides,
+                    )
+                ).map_err(DeeperError::Device)
+            }
+        })
+    }
+
+    fn binary_assign<Op: BinaryOp<L, R, Output = L>, L: DType, R: DType>(
+        a: &mut DeviceBuffer<L>,
+        a_strides: &[usize],
+        b: &DeviceBuffer<R>,
+        b_strides: &[usize],
+    ) -> Result<(), DeeperError> {
+        if a.is_empty() {
+            return Ok(());
+        }
+        let size = a.len();
+        with_module(|stream, module| {
+            let func = get_function(module, format!(
+                "{}_assign_{}_{}d",
+                Op::NAME,
+                L::NAME,
+                a_strides.len().max(1),
+            ))?;
+            let (grid_size, block_size) = launch_config(&func, size)?;
+            let a_strides = KernelStrides::output(a_strides);
+            let b_strides = KernelStrides::input(b_strides);
+
+            unsafe {
+                cust::launch!(
+                    func<<<grid_size, block_size, 0, stream>>>(
+                        a.as_device_ptr(),
+                        a.len(),
+                        a_strides,
+                        b.as_device_ptr(),
+                        b.len(),
+                        b_strides,
+                    )
+                ).map_err(DeeperError::Device)
+            }
+        })
+    }
+
+    fn binary_assign_rhs<Op: BinaryOp<L, R, Output = R>, L: DType, R: DType>(
+        a: &DeviceBuffer<L>,
+        a_strides: &[usize],
+        b: &mut DeviceBuffer<R>,
+        b_strides: &[usize],
+    ) -> Result<(), DeeperError> {
+        if b.is_empty() {
+            return Ok(());
+        }
+        let size = b.len();
+        with_module(|stream, module| {
+            let func = get_function(module, format!(
+                "{}_assign_rhs_{}_{}d",
+                Op::NAME,
+                L::NAME,
+                b_strides.len().max(1),
+            ))?;
+            let (grid_size, block_size) = launch_config(&func, size)?;
+            let a_strides = KernelStrides::input(a_strides);
+            let b_strides = KernelStrides::output(b_strides);
+
+            unsafe {
+                cust::launch!(
+                    func<<<grid_size, block_size, 0, stream>>>(
+                        a.as_device_ptr(),
+                        a.len(),
+                        a_strides,
+                        b.as_device_ptr(),
+                        b.len(),
+                        b_strides,
+                    )
+                ).map_err(DeeperError::Device)
+            }
+        })
+    }
+
+    fn where_<T: DType>(
+        cond: &DeviceBuffer<bool>,
+        cond_strides: &[usize],
+        a: &DeviceBuffer<T>,
+        a_strides: &[usize],
+        b: &DeviceBuffer<T>,
+        b_strides: &[usize],
+        o: &mut DeviceBuffer<T>,
+        o_strides: &[usize],
+    ) -> Result<(), DeeperError> {
+        if o.is_empty() {
+            return Ok(());
+        }
+        let size = o.len();
+        with_module(|stream, module| {
+            let func = get_function(module, format!(
+                "where_{}_{}d",
+                T::NAME,
+                o_strides.len().max(1),
+            ))?;
+            let (grid_size, block_size) = launch_config(&func, size)?;
+            let cond_strides = KernelStrides::input(cond_strides);
+            let a_strides = KernelStrides::input(a_strides);
+            let b_strides = KernelStrides::input(b_strides);
+            let o_strides = KernelStrides::output(o_strides);
+
+            unsafe {
+                cust::launch!(
+                    func<<<grid_size, block_size, 0, stream>>>(
+                        cond.as_device_ptr(),
+                        cond.len(),
+                        cond_strides,
+                        a.as_device_ptr(),
+                        a.len(),
+                        a_strides,
+                        b.as_device_ptr(),
+                        b.len(),
+                        b_strides,
+                        o.as_device_ptr(),
+                        o.len(),
+                        o_strides,
+                    )
+                ).map_err(DeeperError::Device)
+            }
+        })
+    }
+
+    fn reduce<Op: ReduceOp<T>, T: DType>(
+        a: &DeviceBuffer<T>,
+        o: &mut DeviceBuffer<T>,
+        len: usize,
+        inner: usize,
+    ) -> Result<(), DeeperError> {
+        if o.is_empty() {
+            return Ok(());
+        }
+        with_module(|stream, module| {
+            // One block per output element, its threads reduce the axis as a tree.
+            let func = get_function(module, format!("{}_{}", Op::NAME, T::NAME))?;
+            let grid_size = grid_len(o.len())?;
+
+            unsafe {
+                cust::launch!(
+                    func<<<grid_size, REDUCE_BLOCK, 0, stream>>>(
+                        a.as_device_ptr(),
+                        a.len(),
+                        o.as_device_ptr(),
+                        o.len(),
+                        len,
+                        inner,
+                    )
+                ).map_err(DeeperError::Device)
+            }
+        })
+    }
+
+    fn arg_reduce<Op: ArgReduceOp<T>, T: DType>(
+        a: &DeviceBuffer<T>,
+        o: &mut DeviceBuffer<u32>,
+        len: usize,
+        inner: usize,
+    ) -> Result<(), DeeperError> {
+        if o.is_empty() {
+            return Ok(());
+        }
+        with_module(|stream, module| {
+            // One block per output element, like `reduce`.
+            let func = get_function(module, format!("{}_{}", Op::NAME, T::NAME))?;
+            let grid_size = grid_len(o.len())?;
+
+            unsafe {
+                cust::launch!(
+                    func<<<grid_size, REDUCE_BLOCK, 0, stream>>>(
+                        a.as_device_ptr(),
+                        a.len(),
+                        o.as_device_ptr(),
+                        o.len(),
+                        len,
+                        inner,
+                    )
+                ).map_err(DeeperError::Device)
+            }
+        })
+    }
+
+    fn topk<T: DType + PartialOrd>(
+        a: &DeviceBuffer<T>,
+        values: &mut DeviceBuffer<T>,
+        indices: &mut DeviceBuffer<u32>,
+        len: usize,
+        k: usize,
+    ) -> Result<(), DeeperError> {
+        if values.is_empty() {
+            return Ok(());
+        }
+        let rows = values.len() / k;
+        with_module(|stream, module| {
+            // One thread per row.
+            let func = get_function(module, format!("topk_{}", T::NAME))?;
+            let (grid_size, block_size) = launch_config(&func, rows)?;
+
+            unsafe {
+                cust::launch!(
+                    func<<<grid_size, block_size, 0, stream>>>(
+                        a.as_device_ptr(),
+                        a.len(),
+                        values.as_device_ptr(),
+                        indices.as_device_ptr(),
+                        rows,
+                        len,
+                        k,
+                    )
+                ).map_err(DeeperError::Device)
+            }
+        })
+    }
+
+    fn permute<T: DType>(
+        a: &DeviceBuffer<T>,
+        a_offset: usize,
+        a_strides: &[usize],
+        o: &mut DeviceBuffer<T>,
+        o_strides: &[usize],
+    ) -> Result<(), DeeperError> {
+        if o.is_empty() {
+            return Ok(());
+        }
+        let size = o.len();
+        with_module(|stream, module| {
+            let func = get_function(module, format!(
+                "permute_{}_{}d",
+                T::NAME,
+                o_strides.len().max(1),
+            ))?;
+            let (grid_size, block_size) = launch_config(&func, size)?;
+            let a_strides = KernelStrides::input(a_strides);
+            let o_strides = KernelStrides::output(o_strides);
+
+            unsafe {
+                cust::launch!(
+                    func<<<grid_size, block_size, 0, stream>>>(
+                        a.as_device_ptr(),
+                        a.len(),
+                        a_offset,
+                        a_strides,
+                        o.as_device_ptr(),
+                        o.len(),
+                        o_strides,
+                    )
+                ).map_err(DeeperError::Device)
+            }
+        })
+    }
+
+    fn matmul<T: DType>(
+        a: &DeviceBuffer<T>,
+        a_strides: &[usize],
+        b: &DeviceBuffer<T>,
+        b_strides: &[usize],
+        o: &mut DeviceBuffer<T>,
+        o_dims: &[usize],
+        k: usize,
+    ) -> Result<(), DeeperError>
+        where
+            T::Acc: Arith,
+    {
+        if o.is_empty() {
+            return Ok(());
+        }
+        let size = o.len();
+        with_module(|stream, module| {
+            let func = get_function(module, format!("matmul_{}_{}d", T::NAME, o_dims.len()))?;
+            let (grid_size, block_size) = launch_config(&func, size)?;
+            let a_strides = KernelStrides::input(a_strides);
+            let b_strides = KernelStrides::input(b_strides);
+            let o_strides = KernelStrides::output(&contiguous_strides(o_dims));
+
+            unsafe {
+                cust::launch!(
+                    func<<<grid_size, block_size, 0, stream>>>(
+                        a.as_device_ptr(),
+                        a.len(),
+                        a_strides,
+                        b.as_device_ptr(),
+                        b.len(),
+                        b_strides,
+                        o.as_device_ptr(),
+                        o.len(),
+                        o_strides,
+                        k,
+                    )
+                ).map_err(DeeperError::Device)
+            }
+        })
+    }
+}
+
+/// Launch configuration to run one thread per element of an output of `size` elements.
+fn launch_config(func: &Function<'_>, size: usize) -> Result<(u32, u32), DeeperError> {
+    let (_, block_size) = func.suggested_launch_configuration(
+        0, 0.into()
+    ).map_err(DeeperError::Device)?;
+
+    Ok((grid_len(size)?.div_ceil(block_size), block_size))
+}
+
+/// Converts a launch dimension to the `u32` the driver expects, larger sizes are rejected instead
+/// of truncated.
+fn grid_len(size: usize) -> Result<u32, DeeperError> {
+    u32::try_from(size).map_err(|_| DeeperError::Device(CudaError::InvalidValue))
+}
+
+/// Row-major strides of the contiguous `dims`.
+fn contiguous_strides(dims: &[usize]) -> Vec<usize> {
+    let mut strides = vec![1; dims.len()];
+    for i in (1..dims.len()).rev() {
+        strides[i - 1] = strides[i] * dims[i];
+    }
+
+    strides
+}
+
+/// Strides as they are passed to a kernel.
+///
+/// The kernels take a `GenericArray<usize, N>` of their rank by value. The driver copies exactly
+/// as many bytes as the kernel parameter is wide, so one fixed size array serves every rank and
+/// only the leading entries are read. Scalars are launched as rank 1, which is why unused output
+/// strides are 1 and unused input strides are 0.
+#[derive(Clone, Copy)]
+#[repr(transparent)]
+struct KernelStrides([usize; MAX_DIMS]);
+
+impl KernelStrides {
+    fn input(strides: &[usize]) -> Self {
+        Self::padded(strides, 0)
+    }
+
+    fn output(strides: &[usize]) -> Self {
+        Self::padded(strides, 1)
+    }
+
+    fn padded(strides: &[usize], fill: usize) -> Self {
+        assert!(strides.len() <= MAX_DIMS, "rank {} is not supported by the cuda kernels", strides.len());
+        let mut data = [fill; MAX_DIMS];
+        data[..strides.len()].copy_from_slice(strides);
+
+        Self(data)
+    }
+}
+
+unsafe impl DeviceCopy for KernelStrides {}
@@ -0,0 +1,338 @@
+mod element;
+
+use half::{bf16, f16};
+use crate::backend::DType;
+
+pub use element::{Abs, Arith, Cast, Integer, Neg};
+
+/// An elementwise binary operation.
+///
+/// `NAME` is the kernel family in the `cuda` crate, the backend appends the element type and the
+/// rank to find the actual kernel, e.g. `add` becomes `add_f32_2d`.
+pub trait BinaryOp<L: DType, R: DType> {
+    type Output: DType;
+    const NAME: &'static str;
+    fn apply(l: L, r: R) -> Self::Output;
+}
+
+macro_rules! binary_op {
+    ($op:ident, $name:literal, $bound:path, |$l:ident, $r:ident| $body:expr) => {
+        pub struct $op;
+        impl<T: DType + $bound> BinaryOp<T, T> for $op {
+            type Output = T;
+            const NAME: &'static str = $name;
+            #[inline(always)]
+            fn apply($l: T, $r: T) -> T {
+                $body
+            }
+        }
+    };
+}
+
+binary_op!(AddOp, "add", Arith, |l, r| Arith::add(l, r));
+binary_op!(SubOp, "sub", Arith, |l, r| Arith::sub(l, r));
+binary_op!(MulOp, "mul", Arith, |l, r| Arith::mul(l, r));
+binary_op!(DivOp, "div", Arith, |l, r| Arith::div(l, r));
+binary_op!(AndOp, "and", std::ops::BitAnd<Output = T>, |l, r| l & r);
+binary_op!(OrOp, "or", std::ops::BitOr<Output = T>, |l, r| l | r);
+binary_op!(XorOp, "xor", std::ops::BitXor<Output = T>, |l, r| l ^ r);
+binary_op!(RemOp, "rem", Integer, |l, r| Integer::rem(l, r));
+binary_op!(ShlOp, "shl", Integer, |l, r| Integer::shl(l, r));
+binary_op!(ShrOp, "shr", Integer, |l, r| Integer::shr(l, r));
+
+macro_rules! compare_op {
+    ($op:ident, $name:literal, $bound:ident, |$l:ident, $r:ident| $body:expr) => {
+        pub struct $op;
+        impl<T: DType + $bound> BinaryOp<T, T> for $op {
+            type Output = bool;
+            const NAME: &'static str = $name;
+            #[inline(always)]
+            fn apply($l: T, $r: T) -> bool {
+                $body
+            }
+        }
+    };
+}
+
+compare_op!(EqOp, "eq", PartialEq, |l, r| l == r);
+compare_op!(NeOp, "ne", PartialEq, |l, r| l != r);
+compare_op!(LtOp, "lt", PartialOrd, |l, r| l < r);
+compare_op!(LeOp, "le", PartialOrd, |l, r| l <= r);
+compare_op!(GtOp, "gt", PartialOrd, |l, r| l > r);
+compare_op!(GeOp, "ge", PartialOrd, |l, r| l >= r);
+
+/// A reduction of the elements along an axis.
+///
+/// The elements are folded into `init()` with `apply` in an unspecified order and the result is
+/// passed through `finish` together with the number of reduced elements. The accumulator has the
+/// type [`DType::Acc`], so half precision elements are summed in `f32`. `NAME` is the kernel family
+/// like for [`BinaryOp`], the backend appends the element type, e.g. `sum_f32`.
+pub trait ReduceOp<T: DType> {
+    const NAME: &'static str;
+    fn init() -> T::Acc;
+    fn apply(acc: T::Acc, x: T) -> T::Acc;
+    fn finish(acc: T::Acc, len: usize) -> T;
+}
+
+pub struct SumOp;
+pub struct MeanOp;
+pub struct ProdOp;
+pub struct MaxOp;
+pub struct MinOp;
+
+macro_rules! float_reduce_ops {
+    ($ty:ty, $acc:ty) => {
+        impl ReduceOp<$ty> for SumOp {
+            const NAME: &'static str = "sum";
+            #[inline(always)]
+            fn init() -> $acc {
+                0.0
+            }
+            #[inline(always)]
+            fn apply(acc: $acc, x: $ty) -> $acc {
+                acc + x.to_acc()
+            }
+            #[inline(always)]
+            fn finish(acc: $acc, _len: usize) -> $ty {
+                <$ty>::from_acc(acc)
+            }
+        }
+        impl ReduceOp<$ty> for MeanOp {
+            const NAME: &'static str = "mean";
+            #[inline(always)]
+            fn init() -> $acc {
+                0.0
+            }
+            #[inline(always)]
+            fn apply(acc: $acc, x: $ty) -> $acc {
+                acc + x.to_acc()
+            }
+            #[inline(always)]
+            fn finish(acc: $acc, len: usize) -> $ty {
+                <$ty>::from_acc(acc / len as $acc)
+            }
+        }
+        impl ReduceOp<$ty> for ProdOp {
+            const NAME: &'static str = "prod";
+            #[inline(always)]
+            fn init() -> $acc {
+                1.0
+            }
+            #[inline(always)]
+            fn apply(acc: $acc, x: $ty) -> $acc {
+                acc * x.to_acc()
+            }
+            #[inline(always)]
+            fn finish(acc: $acc, _len: usize) -> $ty {
+                <$ty>::from_acc(acc)
+            }
+        }
+        impl ReduceOp<$ty> for MaxOp {
+            const NAME: &'static str = "max";
+            #[inline(always)]
+            fn init() -> $acc {
+                <$acc>::NEG_INFINITY
+            }
+            #[inline(always)]
+            fn apply(acc: $acc, x: $ty) -> $acc {
+                acc.max(x.to_acc())
+            }
+            #[inline(always)]
+            fn finish(acc: $acc, _len: usize) -> $ty {
+                <$ty>::from_acc(acc)
+            }
+        }
+        impl ReduceOp<$ty> for MinOp {
+            const NAME: &'static str = "min";
+            #[inline(always)]
+            fn init() -> $acc {
+                <$acc>::INFINITY
+            }
+            #[inline(always)]
+            fn apply(acc: $acc, x: $ty) -> $acc {
+                acc.min(x.to_acc())
+            }
+            #[inline(always)]
+            fn finish(acc: $acc, _len: usize) -> $ty {
+                <$ty>::from_acc(acc)
+            }
+        }
+    };
+}
+
+float_reduce_ops!(f32, f32);
+float_reduce_ops!(f64, f64);
+float_reduce_ops!(half::f16, f32);
+float_reduce_ops!(half::bf16, f32);
+
+pub struct AllOp;
+pub struct AnyOp;
+
+impl ReduceOp<bool> for AllOp {
+    const NAME: &'static str = "all";
+    #[inline(always)]
+    fn init() -> bool {
+        true
+    }
+    #[inline(always)]
+    fn apply(acc: bool, x: bool) -> bool {
+        acc & x
+    }
+    #[inline(always)]
+    fn finish(acc: bool, _len: usize) -> bool {
+        acc
+    }
+}
+impl ReduceOp<bool> for AnyOp {
+    const NAME: &'static str = "any";
+    #[inline(always)]
+    fn init() -> bool {
+        false
+    }
+    #[inline(always)]
+    fn apply(acc: bool, x: bool) -> bool {
+        acc | x
+    }
+    #[inline(always)]
+    fn finish(acc: bool, _len: usize) -> bool {
+        acc
+    }
+}
+
+/// A reduction selecting one element along an axis, the backend returns its position.
+///
+/// `better(x, best)` tells whether `x` replaces the current `best`, ties keep the first position.
+pub trait ArgReduceOp<T: DType> {
+    const NAME: &'static str;
+    fn better(x: T, best: T) -> bool;
+}
+
+pub struct ArgMaxOp;
+pub struct ArgMinOp;
+
+impl<T: DType + PartialOrd> ArgReduceOp<T> for ArgMaxOp {
+    const NAME: &'static str = "argmax";
+    #[inline(always)]
+    fn better(x: T, best: T) -> bool {
+        x > best
+    }
+}
+impl<T: DType + PartialOrd> ArgReduceOp<T> for ArgMinOp {
+    const NAME: &'static str = "argmin";
+    #[inline(always)]
+    fn better(x: T, best: T) -> bool {
+        x < best
+    }
+}
+
+/// An elementwise unary operation.
+///
+/// `param` is a scalar passed to every invocation, e.g. the exponent of [`PowfOp`], operations
+/// without one return the default value. `NAME` is the kernel family like for [`BinaryOp`], the
+/// backend appends the element type, e.g. `exp_f32`.
+pub trait UnaryOp<T: DType> {
+    type Output: DType;
+    const NAME: &'static str;
+    fn param(&self) -> T {
+        T::default()
+    }
+    fn apply(&self, x: T) -> Self::Output;
+}
+
+macro_rules! unary_op {
+    ($op:ident, $name:literal, $bound:path, |$x:ident| $body:expr) => {
+        pub struct $op;
+        impl<T: DType + $bound> UnaryOp<T> for $op {
+            type Output = T;
+            const NAME: &'static str = $name;
+            #[inline(always)]
+            fn apply(&self, $x: T) -> T {
+                $body
+            }
+        }
+    };
+}
+
+unary_op!(ExpOp, "exp", num_traits::Float, |x| x.exp());
+unary_op!(LnOp, "ln", num_traits::Float, |x| x.ln());
+unary_op!(SqrtOp, "sqrt", num_traits::Float, |x| x.sqrt());
+unary_op!(RsqrtOp, "rsqrt", num_traits::Float, |x| x.sqrt().recip());
+unary_op!(SinOp, "sin", num_traits::Float, |x| x.sin());
+unary_op!(CosOp, "cos", num_traits::Float, |x| x.cos());
+unary_op!(TanhOp, "tanh", num_traits::Float, |x| x.tanh());
+unary_op!(SigmoidOp, "sigmoid", num_traits::Float, |x| (T::one() + (-x).exp()).recip());
+unary_op!(GeluOp, "gelu", num_traits::Float, |x| gelu(x));
+unary_op!(AbsOp, "abs", Abs, |x| Abs::abs(x));
+unary_op!(NegOp, "neg", Neg, |x| Neg::neg(x));
+
+unary_op!(NotOp, "not", std::ops::Not<Output = T>, |x| !x);
+
+pub struct ReluOp;
+impl<T: DType + num_traits::Zero + PartialOrd> UnaryOp<T> for ReluOp {
+    type Output = T;
+    const NAME: &'static str = "relu";
+    #[inline(always)]
+    fn apply(&self, x: T) -> T {
+        if x > T::zero() { x } else { T::zero() }
+    }
+}
+
+/// `x` to the power of the wrapped exponent.
+pub struct PowfOp<T>(pub T);
+impl<T: DType + num_traits::Float> UnaryOp<T> for PowfOp<T> {
+    type Output = T;
+    const NAME: &'static str = "powf";
+    fn param(&self) -> T {
+        self.0
+    }
+    #[inline(always)]
+    fn apply(&self, x: T) -> T {
+        x.powf(self.0)
+    }
+}
+
+macro_rules! scalar_op {
+    ($op:ident, $name:literal, |$x:ident, $s:ident| $body:expr) => {
+        /// Combines every element `x` with the wrapped scalar `s`.
+        pub struct $op<T>(pub T);
+        impl<T: DType + Arith> UnaryOp<T> for $op<T> {
+            type Output = T;
+            const NAME: &'static str = $name;
+            fn param(&self) -> T {
+                self.0
+            }
+            #[inline(always)]
+            fn apply(&self, $x: T) -> T {
+                let $s = self.0;
+                $body
+            }
+        }
+    };
+}
+
+scalar_op!(AddScalarOp, "add_scalar", |x, s| Arith::add(x, s));
+scalar_op!(SubScalarOp, "sub_scalar", |x, s| Arith::sub(x, s));
+scalar_op!(RSubScalarOp, "rsub_scalar", |x, s| Arith::sub(s, x));
+scalar_op!(MulScalarOp, "mul_scalar", |x, s| Arith::mul(x, s));
+scalar_op!(DivScalarOp, "div_scalar", |x, s| Arith::div(x, s));
+scalar_op!(RDivScalarOp, "rdiv_scalar", |x, s| Arith::div(s, x));
+
+/// The tanh approximation of GELU, `0.5 x (1 + tanh(sqrt(2 / pi) (x + 0.044715 x^3)))`.
+#[inline(always)]
+fn gelu<T: num_traits::Float>(x: T) -> T {
+    let c = |v: f64| T::from(v).unwrap();
+    let inner = c(0.7978845608028654) * (x + c(0.044715) * x * x * x);
+
+    c(0.5) * x * (T::one() + inner.tanh())
+}
+
+/// Converts every element to `U` with [`Cast`].
+pub struct CastOp<U>(pub std::marker::PhantomData<U>);
+impl<T: DType + Cast<U>, U: DType> UnaryOp<T> for CastOp<U> {
+    type Output = U;
+    const NAME: &'static str = <T as Cast<U>>::NAME;
+    #[inline(always)]
+    fn apply(&self, x: T) -> U {
+        x.cast()
+    }
+}
@@ -0,0 +1,265 @@
+//! Rules for single elements which have to give the same result on every backend.
+//!
+//! The kernels in the `cuda` crate include this file with `#[path]`, so it only uses `core` and
+//! takes the half precision types from the including module.
+
+use super::{bf16, f16};
+
+/// Arithmetic of the elementwise operations.
+///
+/// Floats use the IEEE operators. Integers wrap around on overflow, including `MIN / -1`.
+/// Division of an integer by zero gives zero.
+pub trait Arith: Copy {
+    fn add(self, rhs: Self) -> Self;
+    fn sub(self, rhs: Self) -> Self;
+    fn mul(self, rhs: Self) -> Self;
+    fn div(self, rhs: Self) -> Self;
+}
+
+/// Operations only defined for integers.
+///
+/// The remainder by zero gives the dividend, so `a == (a / b) * b + a % b` holds for every `b`.
+/// Shifts take the shift amount modulo the bit width of the type.
+pub trait Integer: Arith {
+    fn rem(self, rhs: Self) -> Self;
+    fn shl(self, rhs: Self) -> Self;
+    fn shr(self, rhs: Self) -> Self;
+}
+
+/// Absolute value of signed elements. Floats clear the sign bit, integers wrap around so the
+/// absolute value of `MIN` is `MIN`.
+pub trait Abs: Copy {
+    fn abs(self) -> Self;
+}
+
+/// Negation of signed elements. Integers wrap around so the negation of `MIN` is `MIN`.
+pub trait Neg: Copy {
+    fn neg(self) -> Self;
+}
+
+macro_rules! float_abs {
+    ($($ty:ty, $mask:literal),*) => {
+        $(
+            impl Abs for $ty {
+                #[inline(always)]
+                fn abs(self) -> $ty {
+                    <$ty>::from_bits(self.to_bits() & $mask)
+                }
+            }
+        )*
+    };
+}
+
+float_abs!(f32, 0x7fff_ffff, f64, 0x7fff_ffff_ffff_ffff, f16, 0x7fff, bf16, 0x7fff);
+
+macro_rules! float_neg {
+    ($($ty:ty),*) => {
+        $(
+            impl Neg for $ty {
+                #[inline(always)]
+                fn neg(self) -> $ty {
+                    -self
+                }
+            }
+        )*
+    };
+}
+
+float_neg!(f32, f64, f16, bf16);
+
+macro_rules! int_signed {
+    ($($ty:ty),*) => {
+        $(
+            impl Abs for $ty {
+                #[inline(always)]
+                fn abs(self) -> $ty {
+                    self.wrapping_abs()
+                }
+            }
+            impl Neg for $ty {
+                #[inline(always)]
+                fn neg(self) -> $ty {
+                    self.wrapping_neg()
+                }
+            }
+        )*
+    };
+}
+
+int_signed!(i8, i16, i32, i64);
+
+macro_rules! float_arith {
+    ($($ty:ty),*) => {
+        $(
+            impl Arith for $ty {
+                #[inline(always)]
+                fn add(self, rhs: $ty) -> $ty {
+                    self + rhs
+                }
+                #[inline(always)]
+                fn sub(self, rhs: $ty) -> $ty {
+                    self - rhs
+                }
+                #[inline(always)]
+                fn mul(self, rhs: $ty) -> $ty {
+                    self * rhs
+                }
+                #[inline(always)]
+                fn div(self, rhs: $ty) -> $ty {
+                    self / rhs
+                }
+            }
+        )*
+    };
+}
+
+float_arith!(f32, f64, f16, bf16);
+
+macro_rules! int_arith {
+    ($($ty:ty),*) => {
+        $(
+            impl Arith for $ty {
+                #[inline(always)]
+                fn add(self, rhs: $ty) -> $ty {
+                    self.wrapping_add(rhs)
+                }
+                #[inline(always)]
+                fn sub(self, rhs: $ty) -> $ty {
+                    self.wrapping_sub(rhs)
+                }
+                #[inline(always)]
+                fn mul(self, rhs: $ty) -> $ty {
+                    self.wrapping_mul(rhs)
+                }
+                #[inline(always)]
+                fn div(self, rhs: $ty) -> $ty {
+                    if rhs == 0 { 0 } else { self.wrapping_div(rhs) }
+                }
+            }
+            impl Integer for $ty {
+                #[inline(always)]
+                fn rem(self, rhs: $ty) -> $ty {
+                    if rhs == 0 { self } else { self.wrapping_rem(rhs) }
+                }
+                #[inline(always)]
+                fn shl(self, rhs: $ty) -> $ty {
+                    self.wrapping_shl(rhs as u32)
+                }
+                #[inline(always)]
+                fn shr(self, rhs: $ty) -> $ty {
+                    self.wrapping_shr(rhs as u32)
+                }
+            }
+        )*
+    };
+}
+
+int_arith!(i8, i16, i32, i64, u8, u32, u64);
+
+/// Conversion of an element to `U`.
+///
+/// Floats convert to integers rounding towards zero and saturating at the bounds of `U`, NaN
+/// becomes zero. Integers saturate when converted to a narrower integer. Conversions to a float
+/// type round once to nearest, ties to even. `true` becomes one and every non-zero value,
+/// including NaN, becomes `true`. `NAME` is the kernel family, the backend appends the source
+/// type, e.g. `cast_f64_f32` converts from `f32` to `f64`.
+pub trait Cast<U>: Copy {
+    const NAME: &'static str;
+    fn cast(self) -> U;
+}
+
+// The conversions to the half precision types go through `f32` with rounding to odd, keeping
+// track of any lost bits in the last mantissa bit. `from_f32` then rounds only once, which the
+// `from_f64` of `half` does not do, it drops the low 32 bits of the mantissa first.
+
+/// `x` as `f64`, rounding to odd instead of to nearest if it does not fit into the mantissa.
+#[inline(always)]
+fn u64_to_f64_odd(x: u64) -> f64 {
+    let bits = 64 - x.leading_zeros();
+    if bits <= f64::MANTISSA_DIGITS {
+        return x as f64;
+    }
+    let shift = bits - f64::MANTISSA_DIGITS;
+    let sticky = x & ((1 << shift) - 1) != 0;
+    ((x >> shift) | sticky as u64) as f64 * (1u64 << shift) as f64
+}
+
+/// Like [`u64_to_f64_odd`], for signed integers.
+#[inline(always)]
+fn i64_to_f64_odd(x: i64) -> f64 {
+    let abs = u64_to_f64_odd(x.unsigned_abs());
+    if x < 0 { -abs } else { abs }
+}
+
+/// `x` as `f32`, rounding to odd instead of to nearest.
+#[inline(always)]
+fn f64_to_f32_odd(x: f64) -> f32 {
+    let y = x as f32;
+    if y as f64 == x || x.is_nan() {
+        return y;
+    }
+    // `y` is the nearest value, step back towards zero if it lies beyond `x`.
+    let beyond = if x > 0.0 { y as f64 > x } else { (y as f64) < x };
+    let bits = if beyond { y.to_bits() - 1 } else { y.to_bits() };
+    f32::from_bits(bits | 1)
+}
+
+macro_rules! cast {
+    ($($from:ty),* => $to:ty, |$x:ident| $body:expr) => {
+        $(
+            impl Cast<$to> for $from {
+                const NAME: &'static str = concat!("cast_", stringify!($to));
+                #[inline(always)]
+                #[allow(clippy::unnecessary_cast)]
+                fn cast(self) -> $to {
+                    let $x = self;
+                    $body
+                }
+            }
+        )*
+    };
+}
+
+macro_rules! cast_to_int {
+    ($($to:ty),*) => {
+        $(
+            cast!(f32, f64 => $to, |x| x as $to);
+            cast!(f16, bf16 => $to, |x| x.to_f32() as $to);
+            cast!(i8, i16, i32, i64, u8, u32, u64 => $to, |x| {
+                <$to>::try_from(x).unwrap_or(if x > 0 { <$to>::MAX } else { <$to>::MIN })
+            });
+            cast!(bool => $to, |x| x as $to);
+        )*
+    };
+}
+
+macro_rules! cast_to_float {
+    ($($to:ty),*) => {
+        $(
+            cast!(f32, f64, i8, i16, i32, i64, u8, u32, u64 => $to, |x| x as $to);
+            cast!(f16, bf16 => $to, |x| x.to_f32() as $to);
+            cast!(bool => $to, |x| x as u8 as $to);
+        )*
+    };
+}
+
+macro_rules! cast_to_half {
+    ($($to:ident),*) => {
+        $(
+            cast!(f32 => $to, |x| $to::from_f32(x));
+            cast!(f64, i8, i16, i32, u8, u32 => $to, |x| $to::from_f32(f64_to_f32_odd(x as f64)));
+            cast!(i64 => $to, |x| $to::from_f32(f64_to_f32_odd(i64_to_f64_odd(x))));
+            cast!(u64 => $to, |x| $to::from_f32(f64_to_f32_odd(u64_to_f64_odd(x))));
+            cast!(f16, bf16 => $to, |x| $to::from_f32(x.to_f32()));
+            cast!(bool => $to, |x| if x { $to::ONE } else { $to::ZERO });
+        )*
+    };
+}
+
+cast_to_int!(i8, i16, i32, i64, u8, u32, u64);
+cast_to_float!(f32, f64);
+cast_to_half!(f16, bf16);
+cast!(f32, f64 => bool, |x| x != 0.0);
+cast!(f16, bf16 => bool, |x| x.to_f32() != 0.0);
+cast!(i8, i16, i32, i64, u8, u32, u64 => bool, |x| x != 0);
+cast!(bool => bool, |x| x);
@@ -0,0 +1,59 @@
+use crate::shape::ShapeError;
+
+/// Error reported by the device driver.
+///
+/// Without the `cuda` feature no device is involved, so this can not be constructed.
+#[cfg(feature = "cuda")]
+pub type DeviceError = cust::error::CudaError;
+#[cfg(not(feature = "cuda"))]
+pub type DeviceError = std::convert::Infallible;
+
+/// Any error reported by the fallible `try_*` methods.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum DeeperError {
+    /// The shapes of the operands are not compatible.
+    Shape(ShapeError),
+    /// The device could not be initialized or failed to run a kernel.
+    Device(DeviceError),
+    /// Storage for `size` elements could not be allocated.
+    Allocation { size: usize, source: DeviceError },
+    /// The loaded module has no kernel with this name, usually the element type or rank is not
+    /// supported by the kernels.
+    KernelNotFound(String),
+    /// Copying data between the host and the device failed.
+    Transfer(DeviceError),
+}
+
+impl std::fmt::Display for DeeperError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            DeeperError::Shape(err) => write!(f, "{}", err),
+            DeeperError::Device(err) => write!(f, "device error: {}", err),
+            DeeperError::Allocation { size, source } => write!(
+                f,
+                "can not allocate {} elements: {}",
+                size, source,
+            ),
+            DeeperError::KernelNotFound(name) => write!(f, "kernel {} not found", name),
+            DeeperError::Transfer(err) => write!(f, "transfer failed: {}", err),
+        }
+    }
+}
+
+impl std::error::Error for DeeperError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            DeeperError::Shape(err) => Some(err),
+            DeeperError::Device(err) => Some(err),
+            DeeperError::Allocation { source, .. } => Some(source),
+            DeeperError::KernelNotFound(_) => None,
+            DeeperError::Transfer(err) => Some(err),
+        }
+    }
+}
+
+impl From<ShapeError> for DeeperError {
+    fn from(err: ShapeError) -> Self {
+        DeeperError::Shape(err)
+    }
+}
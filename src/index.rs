@@ -0,0 +1,227 @@
+use std::ops::{Index, IndexMut};
+use generic_array::{ArrayLength, GenericArray};
+use typenum::{IsLess, True, Unsigned};
+use crate::backend::DType;
+use crate::shape::{Cst, Shape};
+use crate::tensor::CpuTensor;
+
+/// Multi-indices into a tensor with `N` axes.
+///
+/// Implemented for `GenericArray`s, arrays and tuples of `usize`.
+pub trait TensorIndex<N: ArrayLength<usize>> {
+    fn into_index(self) -> GenericArray<usize, N>;
+}
+
+impl<N: ArrayLength<usize>> TensorIndex<N> for GenericArray<usize, N> {
+    fn into_index(self) -> GenericArray<usize, N> {
+        self
+    }
+}
+impl TensorIndex<typenum::U0> for () {
+    fn into_index(self) -> GenericArray<usize, typenum::U0> {
+        GenericArray::default()
+    }
+}
+impl TensorIndex<typenum::U1> for usize {
+    fn into_index(self) -> GenericArray<usize, typenum::U1> {
+        GenericArray::from([self])
+    }
+}
+
+impl TensorIndex<typenum::U1> for (usize,) {
+    fn into_index(self) -> GenericArray<usize, typenum::U1> {
+        let (i0,) = self;
+        GenericArray::from([i0])
+    }
+}
+impl TensorIndex<typenum::U1> for [usize; 1] {
+    fn into_index(self) -> GenericArray<usize, typenum::U1> {
+        GenericArray::from(self)
+    }
+}
+
+impl TensorIndex<typenum::U2> for (usize, usize,) {
+    fn into_index(self) -> GenericArray<usize, typenum::U2> {
+        let (i0, i1,) = self;
+        GenericArray::from([i0, i1])
+    }
+}
+impl TensorIndex<typenum::U2> for [usize; 2] {
+    fn into_index(self) -> GenericArray<usize, typenum::U2> {
+        GenericArray::from(self)
+    }
+}
+
+impl TensorIndex<typenum::U3> for (usize, usize, usize,) {
+    fn into_index(self) -> GenericArray<usize, typenum::U3> {
+        let (i0, i1, i2,) = self;
+        GenericArray::from([i0, i1, i2])
+    }
+}
+impl TensorIndex<typenum::U3> for [usize; 3] {
+    fn into_index(self) -> GenericArray<usize, typenum::U3> {
+        GenericArray::from(self)
+    }
+}
+
+impl TensorIndex<typenum::U4> for (usize, usize, usize, usize,) {
+    fn into_index(self) -> GenericArray<usize, typenum::U4> {
+        let (i0, i1, i2, i3,) = self;
+        GenericArray::from([i0, i1, i2, i3])
+    }
+}
+impl TensorIndex<typenum::U4> for [usize; 4] {
+    fn into_index(self) -> GenericArray<usize, typenum::U4> {
+        GenericArray::from(self)
+    }
+}
+
+impl TensorIndex<typenum::U5> for (usize, usize, usize, usize, usize,) {
+    fn into_index(self) -> GenericArray<usize, typenum::U5> {
+        let (i0, i1, i2, i3, i4,) = self;
+        GenericArray::from([i0, i1, i2, i3, i4])
+    }
+}
+impl TensorIndex<typenum::U5> for [usize; 5] {
+    fn into_index(self) -> GenericArray<usize, typenum::U5> {
+        GenericArray::from(self)
+    }
+}
+
+impl TensorIndex<typenum::U6> for (usize, usize, usize, usize, usize, usize,) {
+    fn into_index(self) -> GenericArray<usize, typenum::U6> {
+        let (i0, i1, i2, i3, i4, i5,) = self;
+        GenericArray::from([i0, i1, i2, i3, i4, i5])
+    }
+}
+impl TensorIndex<typenum::U6> for [usize; 6] {
+    fn into_index(self) -> GenericArray<usize, typenum::U6> {
+        GenericArray::from(self)
+    }
+}
+
+impl<T: DType, S: Shape> CpuTensor<T, S> {
+    /// The element at `index`, `None` if it is out of bounds.
+    ///
+    /// ```
+    /// use deeper::prelude::*;
+    ///
+    /// let tensor = CpuTensor::from_vec((Dyn::new(2), Dyn::new(2)), vec![1.0, 2.0, 3.0, 4.0]);
+    /// assert_eq!(tensor.get((1, 0)), Some(&3.0));
+    /// assert_eq!(tensor.get([0, 2]), None);
+    /// assert_eq!(tensor[(0, 1)], 2.0);
+    /// ```
+    pub fn get(&self, index: impl TensorIndex<S::Dims>) -> Option<&T> {
+        let offset = self.shape().offset(&index.into_index())?;
+        self.as_slice().get(offset)
+    }
+
+    /// The element at `index` for writing, `None` if it is out of bounds.
+    pub fn get_mut(&mut self, index: impl TensorIndex<S::Dims>) -> Option<&mut T> {
+        let offset = self.shape().offset(&index.into_index())?;
+        self.as_mut_slice().get_mut(offset)
+    }
+}
+
+impl<T: DType, S: Shape, I: TensorIndex<S::Dims>> Index<I> for CpuTensor<T, S> {
+    type Output = T;
+
+    fn index(&self, index: I) -> &T {
+        let index = index.into_index();
+        match self.shape().offset(&index) {
+            Some(offset) => &self.as_slice()[offset],
+            None => panic!(
+                "index {:?} is out of bounds for dimensions {:?}",
+                index.as_slice(),
+                self.shape().dimensions().as_slice(),
+            ),
+        }
+    }
+}
+
+impl<T: DType, S: Shape, I: TensorIndex<S::Dims>> IndexMut<I> for CpuTensor<T, S> {
+    fn index_mut(&mut self, index: I) -> &mut T {
+        let index = index.into_index();
+        match self.shape().offset(&index) {
+            Some(offset) => &mut self.as_mut_slice()[offset],
+            None => panic!(
+                "index {:?} is out of bounds for dimensions {:?}",
+                index.as_slice(),
+                self.shape().dimensions().as_slice(),
+            ),
+        }
+    }
+}
+
+/// `at` and `at_mut` for constant shapes, the index is given as `typenum` constants and checked
+/// against the shape at compile time.
+macro_rules! impl_at {
+    ($(($size:ident, $idx:ident)),+) => {
+        impl<T: DType, $($size: Unsigned),+> CpuTensor<T, ($(Cst<$size>,)+)> {
+            /// The element at the index given as type parameters, checked at compile time.
+            pub fn at<$($idx: Unsigned + IsLess<$size, Output = True>),+>(&self) -> &T {
+                &self[($($idx::USIZE,)+)]
+            }
+
+            /// The element at the index given as type parameters for writing, checked at compile
+            /// time.
+            pub fn at_mut<$($idx: Unsigned + IsLess<$size, Output = True>),+>(&mut self) -> &mut T {
+                &mut self[($($idx::USIZE,)+)]
+            }
+        }
+    };
+}
+
+impl_at!((Size0, I0));
+impl_at!((Size0, I0), (Size1, I1));
+impl_at!((Size0, I0), (Size1, I1), (Size2, I2));
+impl_at!((Size0, I0), (Size1, I1), (Size2, I2), (Size3, I3));
+impl_at!((Size0, I0), (Size1, I1), (Size2, I2), (Size3, I3), (Size4, I4));
+impl_at!((Size0, I0), (Size1, I1), (Size2, I2), (Size3, I3), (Size4, I4), (Size5, I5));
+
+#[cfg(test)]
+mod tests {
+    use crate::shape::{Cst, Dyn};
+    use crate::tensor::CpuTensor;
+    use typenum::{U0, U1, U2, U3};
+
+    #[test]
+    fn test_index() {
+        let mut tensor = CpuTensor::from_fn((Dyn::new(2), Dyn::new(3), Dyn::new(4)), |idx| {
+            (idx[0] * 100 + idx[1] * 10 + idx[2]) as f32
+        });
+        assert_eq!(tensor[(1, 2, 3)], 123.0);
+        assert_eq!(tensor[[0, 1, 2]], 12.0);
+        assert_eq!(tensor.get((2, 0, 0)), None);
+        assert_eq!(tensor.get((0, 0, 4)), None);
+
+        tensor[(1, 0, 0)] = -1.0;
+        *tensor.get_mut((0, 0, 1)).unwrap() = -2.0;
+        assert_eq!(&tensor.as_slice()[..2], &[0.0, -2.0]);
+        assert_eq!(tensor.as_slice()[12], -1.0);
+
+        let scalar = CpuTensor::of((), 5.0);
+        assert_eq!(scalar[()], 5.0);
+        let vector = CpuTensor::from_vec((Dyn::new(2),), vec![1.0, 2.0]);
+        assert_eq!(vector[1], 2.0);
+    }
+
+    #[test]
+    #[should_panic(expected = "index [0, 3] is out of bounds for dimensions [2, 3]")]
+    fn test_index_out_of_bounds() {
+        let tensor = CpuTensor::of((Dyn::new(2), Dyn::new(3)), 0.0);
+        let _ = tensor[(0, 3)];
+    }
+
+    #[test]
+    fn test_at() {
+        let mut tensor = CpuTensor::<f32, (Cst<U2>, Cst<U3>)>::from_fn(Default::default(), |idx| {
+            (idx[0] * 10 + idx[1]) as f32
+        });
+        assert_eq!(*tensor.at::<U1, U2>(), 12.0);
+        assert_eq!(*tensor.at::<U0, U0>(), 0.0);
+
+        *tensor.at_mut::<U1, U0>() = 42.0;
+        assert_eq!(tensor[(1, 0)], 42.0);
+    }
+}
@@ -1,8 +1,56 @@
+//! Compile time shape checked linear algebra.
+//!
+//! Tensors carry their [`Shape`] in their type, each axis is either a [`Cst`] known at compile
+//! time or a [`Dyn`] known at runtime. Elementwise operators broadcast their operands like numpy
+//! does, for constant axes the compiler rejects shapes that do not fit.
+//!
+//! ```
+//! use deeper::prelude::*;
+//! use typenum::{U2, U3};
+//!
+//! let weights = CpuTensor::<f32, (Cst<U2>, Cst<U3>)>::of((Cst::new(), Cst::new()), 0.5);
+//! let inputs = CpuTensor::<f32, (Dyn,)>::of((Dyn::new(3),), 2.0);
+//!
+//! let outputs = &weights * &inputs;
+//! assert_eq!(outputs.shape().dimensions().as_slice(), &[2, 3]);
+//! assert_eq!(outputs.as_slice(), &[1.0; 6]);
+//!
+//! let mismatch = CpuTensor::<f32, (Dyn,)>::of((Dyn::new(4),), 2.0);
+//! assert!(weights.try_mul(&mismatch).is_err());
+//! ```
+//!
+//! Tensors live on a [`Backend`](backend::Backend). The [`Cpu`](backend::Cpu) backend is always
+//! available, the `cuda` feature adds the [`Cuda`](backend::Cuda) backend.
+
+pub mod backend;
+mod error;
+mod index;
+mod literal;
+mod reduce;
 mod tensor;
 mod shape;
+mod unary;
+mod view;
+pub mod prelude;
+
+pub use error::{DeeperError, DeviceError};
+pub use half::{bf16, f16};
+pub use index::TensorIndex;
+pub use literal::ArrayLiteral;
+pub use shape::{
+    broadcast_strides, AppendMatrix, Axis, AxisRange, BroadcastShape, ConstShape, Cst, CstRange, Dim,
+    Dyn, MatMulShape, MatrixShape, MinSizeShape, PermuteShape, RemoveAxis, ReplaceAxis, SameDim,
+    Shape, ShapeError, SliceShape, SwapAxes,
+};
+#[cfg(feature = "cuda")]
+pub use tensor::CudaTensor;
+pub use tensor::{CpuTensor, Tensor};
+pub use view::{Narrowed, TensorView, TensorViewMut};
 
+// Initialization errors are kept and reported by the first operation that needs the device.
+#[cfg(feature = "cuda")]
 thread_local! {
-    pub(crate) static STREAM: cust::stream::Stream = cust::stream::Stream::new(cust::stream::StreamFlags::NON_BLOCKING, None).unwrap();
-    pub(crate) static CTX: cust::context::Context = cust::quick_init().unwrap();
-    pub(crate) static MODULE: cust::module::Module = cust::module::Module::from_ptx(include_str!("../resources/cuda.ptx"), &[]).unwrap();
+    pub(crate) static STREAM: cust::error::CudaResult<cust::stream::Stream> = cust::stream::Stream::new(cust::stream::StreamFlags::NON_BLOCKING, None);
+    pub(crate) static CTX: cust::error::CudaResult<cust::context::Context> = cust::quick_init();
+    pub(crate) static MODULE: cust::error::CudaResult<cust::module::Module> = cust::module::Module::from_ptx(include_str!("../resources/cuda.ptx"), &[]);
 }
@@ -0,0 +1,187 @@
+use typenum::{Const, ToUInt, Unsigned, U};
+use crate::backend::{Backend, DType};
+use crate::shape::{ConstShape, Cst};
+use crate::tensor::Tensor;
+
+/// Builds a [`CpuTensor`](crate::CpuTensor) from nested array literals, the nesting gives the
+/// constant shape.
+///
+/// ```
+/// use deeper::prelude::*;
+/// use typenum::{U2, U3};
+///
+/// let tensor: CpuTensor<f32, (Cst<U2>, Cst<U3>)> = deeper::tensor![
+///     [1.0, 2.0, 3.0],
+///     [4.0, 5.0, 6.0],
+/// ];
+/// assert_eq!(tensor.as_slice(), &[1.0, 2.0, 3.0, 4.0, 5.0, 6.0]);
+/// ```
+#[macro_export]
+macro_rules! tensor {
+    ($($elems:tt)*) => {
+        $crate::CpuTensor::from_array([$($elems)*])
+    };
+}
+
+/// Nested arrays of up to six levels, used by [`tensor!`](crate::tensor).
+pub trait ArrayLiteral {
+    type Elem: DType;
+    type Shape: ConstShape;
+    /// Appends the elements in row-major order.
+    fn flatten(self, data: &mut Vec<Self::Elem>);
+}
+
+impl<T: DType, S: ConstShape, B: Backend> Tensor<T, S, B> {
+    pub fn from_array<A: ArrayLiteral<Elem = T, Shape = S>>(array: A) -> Self {
+        let shape = S::default();
+        let mut data = Vec::with_capacity(shape.size());
+        array.flatten(&mut data);
+
+        Self::from_vec(shape, data)
+    }
+}
+
+impl<
+    T: DType,
+    const N0: usize,
+> ArrayLiteral for [T; N0]
+    where
+        Const<N0>: ToUInt,
+        U<N0>: Unsigned,
+{
+    type Elem = T;
+    type Shape = (Cst<U<N0>>,);
+    fn flatten(self, data: &mut Vec<T>) {
+        data.extend(self);
+    }
+}
+
+impl<
+    T: DType,
+    const N0: usize,
+    const N1: usize,
+> ArrayLiteral for [[T; N1]; N0]
+    where
+        Const<N0>: ToUInt,
+        U<N0>: Unsigned,
+        Const<N1>: ToUInt,
+        U<N1>: Unsigned,
+{
+    type Elem = T;
+    type Shape = (Cst<U<N0>>, Cst<U<N1>>,);
+    fn flatten(self, data: &mut Vec<T>) {
+        for row in self {
+            row.flatten(data);
+        }
+    }
+}
+
+impl<
+    T: DType,
+    const N0: usize,
+    const N1: usize,
+    const N2: usize,
+> ArrayLiteral for [[[T; N2]; N1]; N0]
+    where
+        Const<N0>: ToUInt,
+        U<N0>: Unsigned,
+        Const<N1>: ToUInt,
+        U<N1>: Unsigned,
+        Const<N2>: ToUInt,
+        U<N2>: Unsigned,
+{
+    type Elem = T;
+    type Shape = (Cst<U<N0>>, Cst<U<N1>>, Cst<U<N2>>,);
+    fn flatten(self, data: &mut Vec<T>) {
+        for row in self {
+            row.flatten(data);
+        }
+    }
+}
+
+impl<
+    T: DType,
+    const N0: usize,
+    const N1: usize,
+    const N2: usize,
+    const N3: usize,
+> ArrayLiteral for [[[[T; N3]; N2]; N1]; N0]
+    where
+        Const<N0>: ToUInt,
+        U<N0>: Unsigned,
+        Const<N1>: ToUInt,
+        U<N1>: Unsigned,
+        Const<N2>: ToUInt,
+        U<N2>: Unsigned,
+        Const<N3>: ToUInt,
+        U<N3>: Unsigned,
+{
+    type Elem = T;
+    type Shape = (Cst<U<N0>>, Cst<U<N1>>, Cst<U<N2>>, Cst<U<N3>>,);
+    fn flatten(self, data: &mut Vec<T>) {
+        for row in self {
+            row.flatten(data);
+        }
+    }
+}
+
+impl<
+    T: DType,
+    const N0: usize,
+    const N1: usize,
+    const N2: usize,
+    const N3: usize,
+    const N4: usize,
+> ArrayLiteral for [[[[[T; N4]; N3]; N2]; N1]; N0]
+    where
+        Const<N0>: ToUInt,
+        U<N0>: Unsigned,
+        Const<N1>: ToUInt,
+        U<N1>: Unsigned,
+        Const<N2>: ToUInt,
+        U<N2>: Unsigned,
+        Const<N3>: ToUInt,
+        U<N3>: Unsigned,
+        Const<N4>: ToUInt,
+        U<N4>: Unsigned,
+{
+    type Elem = T;
+    type Shape = (Cst<U<N0>>, Cst<U<N1>>, Cst<U<N2>>, Cst<U<N3>>, Cst<U<N4>>,);
+    fn flatten(self, data: &mut Vec<T>) {
+        for row in self {
+            row.flatten(data);
+        }
+    }
+}
+
+impl<
+    T: DType,
+    const N0: usize,
+    const N1: usize,
+    const N2: usize,
+    const N3: usize,
+    const N4: usize,
+    const N5: usize,
+> ArrayLiteral for [[[[[[T; N5]; N4]; N3]; N2]; N1]; N0]
+    where
+        Const<N0>: ToUInt,
+        U<N0>: Unsigned,
+        Const<N1>: ToUInt,
+        U<N1>: Unsigned,
+        Const<N2>: ToUInt,
+        U<N2>: Unsigned,
+        Const<N3>: ToUInt,
+        U<N3>: Unsigned,
+        Const<N4>: ToUInt,
+        U<N4>: Unsigned,
+        Const<N5>: ToUInt,
+        U<N5>: Unsigned,
+{
+    type Elem = T;
+    type Shape = (Cst<U<N0>>, Cst<U<N1>>, Cst<U<N2>>, Cst<U<N3>>, Cst<U<N4>>, Cst<U<N5>>,);
+    fn flatten(self, data: &mut Vec<T>) {
+        for row in self {
+            row.flatten(data);
+        }
+    }
+}
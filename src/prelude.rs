@@ -0,0 +1,10 @@
+//! The types needed to work with tensors, `use deeper::prelude::*;` brings them into scope.
+
+pub use crate::backend::{Backend, Cpu, DType};
+#[cfg(feature = "cuda")]
+pub use crate::backend::Cuda;
+pub use crate::{BroadcastShape, ConstShape, Cst, CstRange, Dim, Dyn, MatMulShape, Shape};
+pub use crate::{bf16, f16, DeeperError, ShapeError};
+#[cfg(feature = "cuda")]
+pub use crate::CudaTensor;
+pub use crate::{CpuTensor, Tensor, TensorView, TensorViewMut};
@@ -0,0 +1,334 @@
+use crate::backend::{Backend, DType};
+use crate::backend::ops::{
+    AllOp, AnyOp, ArgMaxOp, ArgMinOp, ArgReduceOp, MaxOp, MeanOp, MinOp, ProdOp, ReduceOp, SumOp,
+};
+use crate::error::DeeperError;
+use crate::shape::{Cst, Dyn, RemoveAxis, ReplaceAxis, Shape, ShapeError};
+use crate::tensor::{expect, Tensor};
+
+impl<T: DType, S: Shape, B: Backend> Tensor<T, S, B> {
+    /// Reduces the axis `axis`, or all elements if it is `None`, into a tensor of `shape`.
+    fn try_reduce<Op: ReduceOp<T>, SO: Shape>(
+        &self,
+        shape: SO,
+        axis: Option<usize>,
+    ) -> Result<Tensor<T, SO, B>, DeeperError> {
+        let dims = self.shape.dimensions();
+        let (len, inner) = match axis {
+            Some(axis) => (dims[axis], dims[axis + 1..].iter().product()),
+            None => (self.shape.size(), 1),
+        };
+        let mut data = B::alloc(shape.size())?;
+        B::reduce::<Op, T>(&self.data, &mut data, len, inner)?;
+
+        Ok(Tensor { data, shape })
+    }
+
+    /// Positions of the selected elements along the axis `axis` in a tensor of `shape`.
+    fn try_arg_reduce<Op: ArgReduceOp<T>, SO: Shape>(
+        &self,
+        shape: SO,
+        axis: usize,
+    ) -> Result<Tensor<u32, SO, B>, DeeperError> {
+        let dims = self.shape.dimensions();
+        let (len, inner) = (dims[axis], dims[axis + 1..].iter().product());
+        if len == 0 && shape.size() > 0 {
+            return Err(ShapeError::Range { axis, start: 0, end: 1, size: 0 }.into());
+        }
+        let mut data = B::alloc(shape.size())?;
+        B::arg_reduce::<Op, T>(&self.data, &mut data, len, inner)?;
+
+        Ok(Tensor { data, shape })
+    }
+
+    /// The `k` largest elements along the last axis in descending order, and their positions.
+    ///
+    /// ```
+    /// use deeper::prelude::*;
+    ///
+    /// let tensor = CpuTensor::from_vec((Dyn::new(2), Dyn::new(3)), vec![0.1, 0.7, 0.2, 0.5, 0.3, 0.2]);
+    /// let (values, indices) = tensor.topk(2);
+    /// assert_eq!(values.as_slice(), &[0.7, 0.2, 0.5, 0.3]);
+    /// assert_eq!(indices.as_slice(), &[1, 2, 0, 1]);
+    /// ```
+    #[allow(clippy::type_complexity)]
+    pub fn topk(
+        &self,
+        k: usize,
+    ) -> (
+        Tensor<T, <S as ReplaceAxis<typenum::Sub1<<S as Shape>::Dims>, Dyn>>::Output, B>,
+        Tensor<u32, <S as ReplaceAxis<typenum::Sub1<<S as Shape>::Dims>, Dyn>>::Output, B>,
+    )
+        where
+            T: PartialOrd,
+            <S as Shape>::Dims: core::ops::Sub<typenum::B1>,
+            S: ReplaceAxis<typenum::Sub1<<S as Shape>::Dims>, Dyn>,
+    {
+        expect(self.try_topk(k))
+    }
+
+    /// Like [`Tensor::topk`], but returns an error instead of panicking.
+    #[allow(clippy::type_complexity)]
+    pub fn try_topk(
+        &self,
+        k: usize,
+    ) -> Result<(
+        Tensor<T, <S as ReplaceAxis<typenum::Sub1<<S as Shape>::Dims>, Dyn>>::Output, B>,
+        Tensor<u32, <S as ReplaceAxis<typenum::Sub1<<S as Shape>::Dims>, Dyn>>::Output, B>,
+    ), DeeperError>
+        where
+            T: PartialOrd,
+            <S as Shape>::Dims: core::ops::Sub<typenum::B1>,
+            S: ReplaceAxis<typenum::Sub1<<S as Shape>::Dims>, Dyn>,
+    {
+        let dims = self.shape.dimensions();
+        let axis = dims.len() - 1;
+        if k > dims[axis] {
+            return Err(ShapeError::Range { axis, start: 0, end: k, size: dims[axis] }.into());
+        }
+        let shape = self.shape.replace_axis(Dyn::new(k));
+        let mut values = B::alloc(shape.size())?;
+        let mut indices = B::alloc(shape.size())?;
+        B::topk::<T>(&self.data, &mut values, &mut indices, dims[axis], k)?;
+
+        Ok((Tensor { data: values, shape }, Tensor { data: indices, shape }))
+    }
+}
+
+macro_rules! impl_arg_reduce {
+    ($kernel:ty, $fn_id:ident, $try_fn_id:ident) => {
+        impl<T: DType, S: Shape, B: Backend> Tensor<T, S, B>
+            where
+                $kernel: ArgReduceOp<T>,
+        {
+            /// Positions of the selected elements along the axis `A`, given as a `typenum` index,
+            /// which is removed from the shape. Ties select the first position.
+            pub fn $fn_id<A>(&self) -> Tensor<u32, <S as RemoveAxis<A>>::Output, B>
+                where
+                    A: typenum::Unsigned,
+                    S: RemoveAxis<A>,
+            {
+                expect(self.$try_fn_id::<A>())
+            }
+
+            /// Like the method without `try_`, but returns an error instead of panicking.
+            pub fn $try_fn_id<A>(
+                &self,
+            ) -> Result<Tensor<u32, <S as RemoveAxis<A>>::Output, B>, DeeperError>
+                where
+                    A: typenum::Unsigned,
+                    S: RemoveAxis<A>,
+            {
+                self.try_arg_reduce::<$kernel, _>(self.shape.remove_axis(), A::USIZE)
+            }
+        }
+    };
+}
+
+impl_arg_reduce!(ArgMaxOp, argmax_axis, try_argmax_axis);
+impl_arg_reduce!(ArgMinOp, argmin_axis, try_argmin_axis);
+
+macro_rules! impl_reduce {
+    (
+        $kernel:ty,
+        $fn_id:ident,
+        $try_fn_id:ident,
+        $axis_fn_id:ident,
+        $try_axis_fn_id:ident,
+        $keepdim_fn_id:ident,
+        $try_keepdim_fn_id:ident
+    ) => {
+        impl<T: DType, S: Shape, B: Backend> Tensor<T, S, B>
+            where
+                $kernel: ReduceOp<T>,
+        {
+            /// Reduces all elements into a scalar.
+            pub fn $fn_id(&self) -> Tensor<T, (), B> {
+                expect(self.$try_fn_id())
+            }
+
+            /// Like the method without `try_`, but returns an error instead of panicking.
+            pub fn $try_fn_id(&self) -> Result<Tensor<T, (), B>, DeeperError> {
+                self.try_reduce::<$kernel, ()>((), None)
+            }
+
+            /// Reduces the axis `A`, given as a `typenum` index, and removes it from the shape.
+            pub fn $axis_fn_id<A>(&self) -> Tensor<T, <S as RemoveAxis<A>>::Output, B>
+                where
+                    A: typenum::Unsigned,
+                    S: RemoveAxis<A>,
+            {
+                expect(self.$try_axis_fn_id::<A>())
+            }
+
+            /// Like the method without `try_`, but returns an error instead of panicking.
+            pub fn $try_axis_fn_id<A>(
+                &self,
+            ) -> Result<Tensor<T, <S as RemoveAxis<A>>::Output, B>, DeeperError>
+                where
+                    A: typenum::Unsigned,
+                    S: RemoveAxis<A>,
+            {
+                self.try_reduce::<$kernel, _>(self.shape.remove_axis(), Some(A::USIZE))
+            }
+
+            /// Reduces the axis `A`, given as a `typenum` index, and keeps it with size 1.
+            pub fn $keepdim_fn_id<A>(
+                &self,
+            ) -> Tensor<T, <S as ReplaceAxis<A, Cst<typenum::U1>>>::Output, B>
+                where
+                    A: typenum::Unsigned,
+                    S: ReplaceAxis<A, Cst<typenum::U1>>,
+            {
+                expect(self.$try_keepdim_fn_id::<A>())
+            }
+
+            /// Like the method without `try_`, but returns an error instead of panicking.
+            #[allow(clippy::type_complexity)]
+            pub fn $try_keepdim_fn_id<A>(
+                &self,
+            ) -> Result<Tensor<T, <S as ReplaceAxis<A, Cst<typenum::U1>>>::Output, B>, DeeperError>
+                where
+                    A: typenum::Unsigned,
+                    S: ReplaceAxis<A, Cst<typenum::U1>>,
+            {
+                self.try_reduce::<$kernel, _>(self.shape.replace_axis(Cst::new()), Some(A::USIZE))
+            }
+        }
+    };
+}
+
+impl_reduce!(SumOp, sum, try_sum, sum_axis, try_sum_axis, sum_axis_keepdim, try_sum_axis_keepdim);
+impl_reduce!(MeanOp, mean, try_mean, mean_axis, try_mean_axis, mean_axis_keepdim, try_mean_axis_keepdim);
+impl_reduce!(ProdOp, prod, try_prod, prod_axis, try_prod_axis, prod_axis_keepdim, try_prod_axis_keepdim);
+impl_reduce!(MaxOp, max, try_max, max_axis, try_max_axis, max_axis_keepdim, try_max_axis_keepdim);
+impl_reduce!(MinOp, min, try_min, min_axis, try_min_axis, min_axis_keepdim, try_min_axis_keepdim);
+impl_reduce!(AllOp, all, try_all, all_axis, try_all_axis, all_axis_keepdim, try_all_axis_keepdim);
+impl_reduce!(AnyOp, any, try_any, any_axis, try_any_axis, any_axis_keepdim, try_any_axis_keepdim);
+
+#[cfg(test)]
+mod tests {
+    use crate::shape::{Cst, Dyn, ShapeError};
+    use crate::tensor::CpuTensor;
+    use crate::DeeperError;
+
+    fn tensor() -> CpuTensor<f32, (Dyn, Cst<typenum::U3>, Dyn)> {
+        CpuTensor::from_fn(
+            (Dyn::new(2), Cst::new(), Dyn::new(2)),
+            |idx| (idx[0] * 6 + idx[1] * 2 + idx[2] + 1) as f32,
+        )
+    }
+
+    #[test]
+    fn test_sum_axis() {
+        let tensor_o = tensor().sum_axis::<typenum::U1>();
+        let _: &CpuTensor<f32, (Dyn, Dyn)> = &tensor_o;
+        assert_eq!(tensor_o.as_slice(), &[9.0, 12.0, 27.0, 30.0]);
+
+        let tensor_o = tensor().sum_axis_keepdim::<typenum::U0>();
+        let _: &CpuTensor<f32, (Cst<typenum::U1>, Cst<typenum::U3>, Dyn)> = &tensor_o;
+        assert_eq!(tensor_o.as_slice(), &[8.0, 10.0, 12.0, 14.0, 16.0, 18.0]);
+
+        assert_eq!(tensor().sum().as_slice(), &[78.0]);
+    }
+
+    #[test]
+    fn test_reduce_ops() {
+        let tensor = tensor();
+        assert_eq!(tensor.mean_axis::<typenum::U2>().as_slice(), &[1.5, 3.5, 5.5, 7.5, 9.5, 11.5]);
+        assert_eq!(tensor.max_axis::<typenum::U1>().as_slice(), &[5.0, 6.0, 11.0, 12.0]);
+        assert_eq!(tensor.min_axis_keepdim::<typenum::U1>().as_slice(), &[1.0, 2.0, 7.0, 8.0]);
+        assert_eq!(tensor.prod_axis::<typenum::U0>().as_slice(), &[7.0, 16.0, 27.0, 40.0, 55.0, 72.0]);
+        assert_eq!(tensor.mean().as_slice(), &[6.5]);
+        assert_eq!(tensor.max().as_slice(), &[12.0]);
+        assert_eq!(tensor.min().as_slice(), &[1.0]);
+
+        let empty = CpuTensor::<f64, (Dyn,)>::zero((Dyn::new(0),));
+        assert_eq!(empty.sum().as_slice(), &[0.0]);
+        assert_eq!(empty.max().as_slice(), &[f64::NEG_INFINITY]);
+    }
+
+    #[test]
+    fn test_reduce_half() {
+        // 2048 + 1 rounds back to 2048 in f16, an f16 accumulator would stop there
+        let tensor = CpuTensor::<half::f16, (Dyn,)>::of((Dyn::new(4096),), half::f16::ONE);
+        assert_eq!(tensor.sum().as_slice(), &[half::f16::from_f32(4096.0)]);
+        assert_eq!(tensor.mean().as_slice(), &[half::f16::ONE]);
+
+        let tensor = CpuTensor::from_vec((Dyn::new(3),), vec![1.0, -2.0, 0.5].into_iter().map(half::bf16::from_f32).collect());
+        assert_eq!(tensor.max().as_slice(), &[half::bf16::ONE]);
+        assert_eq!(tensor.argmin_axis::<typenum::U0>().as_slice(), &[1]);
+    }
+
+    #[test]
+    fn test_all_any() {
+        let tensor = CpuTensor::from_vec((Dyn::new(2), Dyn::new(2)), vec![true, false, true, true]);
+
+        assert_eq!(tensor.all().as_slice(), &[false]);
+        assert_eq!(tensor.any().as_slice(), &[true]);
+        assert_eq!(tensor.all_axis::<typenum::U1>().as_slice(), &[false, true]);
+        assert_eq!(tensor.any_axis::<typenum::U0>().as_slice(), &[true, true]);
+
+        let empty = CpuTensor::<bool, (Dyn,)>::from_vec((Dyn::new(0),), vec![]);
+        assert_eq!(empty.all().as_slice(), &[true]);
+        assert_eq!(empty.any().as_slice(), &[false]);
+    }
+
+    #[test]
+    fn test_arg_reduce() {
+        let tensor = CpuTensor::from_vec((Dyn::new(2), Cst::<typenum::U3>::new()), vec![1.0, 3.0, 3.0, 2.0, 0.0, 2.0]);
+
+        let indices = tensor.argmax_axis::<typenum::U1>();
+        let _: &CpuTensor<u32, (Dyn,)> = &indices;
+        assert_eq!(indices.as_slice(), &[1, 0]);
+        assert_eq!(tensor.argmin_axis::<typenum::U1>().as_slice(), &[0, 1]);
+        assert_eq!(tensor.argmax_axis::<typenum::U0>().as_slice(), &[1, 0, 0]);
+
+        let empty = CpuTensor::<f32, (Dyn, Dyn)>::zero((Dyn::new(2), Dyn::new(0)));
+        assert_eq!(
+            empty.try_argmax_axis::<typenum::U1>().err(),
+            Some(DeeperError::Shape(ShapeError::Range { axis: 1, start: 0, end: 1, size: 0 })),
+        );
+        assert_eq!(empty.argmax_axis::<typenum::U0>().as_slice(), &[] as &[u32]);
+    }
+
+    #[test]
+    fn test_topk() {
+        let tensor = CpuTensor::from_vec((Cst::<typenum::U2>::new(), Cst::<typenum::U4>::new()), vec![3.0, 1.0, 4.0, 1.0, 5.0, 9.0, 2.0, 6.0]);
+
+        let (values, indices) = tensor.topk(3);
+        let _: &CpuTensor<f32, (Cst<typenum::U2>, Dyn)> = &values;
+        assert_eq!(values.as_slice(), &[4.0, 3.0, 1.0, 9.0, 6.0, 5.0]);
+        assert_eq!(indices.as_slice(), &[2, 0, 1, 1, 3, 0]);
+
+        assert_eq!(
+            tensor.try_topk(5).err(),
+            Some(DeeperError::Shape(ShapeError::Range { axis: 1, start: 0, end: 5, size: 4 })),
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "cuda")]
+    fn test_reduce_cuda() {
+        use crate::shape::Shape;
+
+        let tensor = CpuTensor::from_fn((Dyn::new(3), Dyn::new(1000)), |idx| (idx[0] + 1) as f32);
+        let tensor = tensor.cuda();
+
+        assert_eq!(tensor.sum_axis::<typenum::U1>().cpu().as_slice(), &[1000.0, 2000.0, 3000.0]);
+        assert_eq!(tensor.max_axis::<typenum::U0>().cpu().as_slice(), &[3.0; 1000][..]);
+        assert_eq!(tensor.mean().cpu().as_slice(), &[2.0]);
+        assert_eq!(tensor.shape().dimensions().as_slice(), &[3, 1000]);
+
+        let tensor = CpuTensor::from_fn((Dyn::new(2), Dyn::new(600)), |idx| ((idx[1] * 7 + idx[0]) % 600) as f32);
+        let tensor = tensor.cuda();
+        assert_eq!(tensor.argmax_axis::<typenum::U1>().cpu().as_slice(), &[257, 514]);
+        let (values, indices) = tensor.topk(2);
+        assert_eq!(values.cpu().as_slice(), &[599.0, 598.0, 599.0, 598.0]);
+        assert_eq!(indices.cpu().as_slice(), &[257, 514, 514, 171]);
+
+        let mask = tensor.ge(&CpuTensor::of((), 599.0).cuda());
+        assert_eq!(mask.any_axis::<typenum::U1>().cpu().as_slice(), &[true, true]);
+        assert_eq!(mask.all().cpu().as_slice(), &[false]);
+    }
+}
@@ -1,20 +1,130 @@
-use std::cmp::max;
-
+/// The shape of a tensor, a tuple of up to six [`Dim`]s or `()` for scalars.
 pub trait Shape: Copy {
     type Dims: generic_array::ArrayLength<usize>;
     fn size(&self) -> usize;
     fn dimensions(&self) -> generic_array::GenericArray<usize, Self::Dims>;
     fn strides(&self) -> generic_array::GenericArray<usize, Self::Dims>;
+    /// Position of the element at `index` in row-major storage, `None` if it is out of bounds.
+    fn offset(&self, index: &generic_array::GenericArray<usize, Self::Dims>) -> Option<usize> {
+        let dims = self.dimensions();
+        let strides = self.strides();
+        let mut offset = 0;
+        for i in 0..dims.len() {
+            if index[i] >= dims[i] {
+                return None;
+            }
+            offset += index[i] * strides[i];
+        }
+
+        Some(offset)
+    }
 }
+/// The number of elements of a shape, as a `typenum` constant. Axes of size [`Dyn`] count as 1.
 pub trait MinSizeShape {
     type Value: typenum::Unsigned;
 }
-pub trait BroadcastShape<Rhs> {
+/// Shapes which can be broadcast with `Rhs`.
+///
+/// The shapes line up with their trailing axes. Two axes are compatible if they are equal or one
+/// of them is 1, for [`Cst`] axes this is checked at compile time, for [`Dyn`] axes at runtime.
+///
+/// ```
+/// use deeper::{BroadcastShape, Cst, Dyn, Shape};
+/// use typenum::{U1, U3};
+///
+/// let shape = (Cst::<U3>::new(), Cst::<U1>::new()).broadcast((Dyn::new(4),));
+/// assert_eq!(shape.dimensions().as_slice(), &[3, 4]);
+/// assert!((Dyn::new(2),).try_broadcast((Dyn::new(4),)).is_err());
+/// ```
+pub trait BroadcastShape<Rhs>: Sized {
     type Output;
-    fn broadcast(self, rhs: Rhs) -> Self::Output;
+    fn try_broadcast(self, rhs: Rhs) -> Result<Self::Output, ShapeError>;
+    /// Like `try_broadcast`, but panics if the shapes are not compatible.
+    fn broadcast(self, rhs: Rhs) -> Self::Output {
+        match self.try_broadcast(rhs) {
+            Ok(shape) => shape,
+            Err(err) => panic!("{}", err),
+        }
+    }
+}
+
+/// Shapes which are not compatible, detected at runtime.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum ShapeError {
+    /// An axis where neither size is 1 and the sizes differ, `axis` counts from the first axis
+    /// of the broadcast output.
+    Broadcast { axis: usize, lhs: usize, rhs: usize },
+    /// The number of elements does not match the size of the shape.
+    Size { expected: usize, actual: usize },
+    /// The inner axes of a matrix product differ.
+    MatMul { lhs: usize, rhs: usize },
+    /// The range `start..end` does not fit into the axis `axis` of size `size`.
+    Range { axis: usize, start: usize, end: usize, size: usize },
+}
+impl ShapeError {
+    pub(crate) fn at_axis(self, axis: usize) -> Self {
+        match self {
+            ShapeError::Broadcast { lhs, rhs, .. } => ShapeError::Broadcast { axis, lhs, rhs },
+            ShapeError::Range { start, end, size, .. } => ShapeError::Range {
+                axis,
+                start,
+                end,
+                size,
+            },
+            err => err,
+        }
+    }
+}
+impl std::fmt::Display for ShapeError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ShapeError::Broadcast { axis, lhs, rhs } => write!(
+                f,
+                "can not broadcast axis {} of size {} with size {}",
+                axis, lhs, rhs,
+            ),
+            ShapeError::Size { expected, actual } => write!(
+                f,
+                "expected {} elements for the shape, got {}",
+                expected, actual,
+            ),
+            ShapeError::MatMul { lhs, rhs } => write!(
+                f,
+                "can not multiply matrices with {} columns by matrices with {} rows",
+                lhs, rhs,
+            ),
+            ShapeError::Range { axis, start, end, size } => write!(
+                f,
+                "range {}..{} is out of bounds for axis {} of size {}",
+                start, end, axis, size,
+            ),
+        }
+    }
+}
+impl std::error::Error for ShapeError {}
+
+/// Strides to read an operand with `dims` and `strides` as if it had the dimensions `o_dims`.
+///
+/// The operand lines up with the trailing axes of the output. Axes it lacks, and axes where it
+/// has size 1 while the output does not, get stride 0 so the same element is read along them.
+pub fn broadcast_strides<N: generic_array::ArrayLength<usize>>(
+    dims: &[usize],
+    strides: &[usize],
+    o_dims: &generic_array::GenericArray<usize, N>,
+) -> generic_array::GenericArray<usize, N> {
+    let skip = o_dims.len() - dims.len();
+    let mut o_strides = generic_array::GenericArray::default();
+    for i in skip..o_dims.len() {
+        if dims[i - skip] == o_dims[i] {
+            o_strides[i] = strides[i - skip];
+        }
+    }
+
+    o_strides
 }
 
-#[derive(Copy, Clone)]
+/// An axis whose size is only known at runtime.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
 pub struct Dyn {
     size: usize,
 }
@@ -23,18 +133,34 @@ impl Dyn {
         Self { size }
     }
 }
-#[derive(Copy, Clone)]
+/// An axis whose size is the `typenum` constant `Size`.
+///
+/// ```
+/// use deeper::{Cst, Dim};
+///
+/// assert_eq!(Cst::<typenum::U4>::new().size(), 4);
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
 pub struct Cst<Size: typenum::Unsigned> {
     _phantom: std::marker::PhantomData<Size>,
 }
 impl<Size: typenum::Unsigned> Cst<Size> {
-    pub(crate) fn new() -> Self {
+    pub fn new() -> Self {
         Self {
             _phantom: std::marker::PhantomData,
         }
     }
 }
+impl<Size: typenum::Unsigned> Default for Cst<Size> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Shapes made only of [`Cst`] axes, `Default` builds them without any runtime information.
+pub trait ConstShape: Shape + Default {}
 
+/// A single axis of a [`Shape`].
 pub trait Dim: Copy {
     fn size(&self) -> usize;
 }
@@ -83,30 +209,40 @@ impl<
         >,
 {
     type Output = Cst<SizeO>;
-    fn broadcast(self, _: Cst<SizeR>) -> Self::Output {
-        Cst::new()
+    fn try_broadcast(self, _: Cst<SizeR>) -> Result<Self::Output, ShapeError> {
+        Ok(Cst::new())
     }
 }
 impl<
     SizeL: typenum::Unsigned
 > BroadcastShape<Dyn> for Cst<SizeL> {
     type Output = Dyn;
-    fn broadcast(self, rhs: Dyn) -> Self::Output {
-        Dyn { size: max(SizeL::USIZE, rhs.size) }
+    fn try_broadcast(self, rhs: Dyn) -> Result<Self::Output, ShapeError> {
+        broadcast_size(SizeL::USIZE, rhs.size)
     }
 }
 impl<
     SizeR: typenum::Unsigned
 > BroadcastShape<Cst<SizeR>> for Dyn {
     type Output = Dyn;
-    fn broadcast(self, _: Cst<SizeR>) -> Self::Output {
-        Dyn { size: max(SizeR::USIZE, self.size) }
+    fn try_broadcast(self, _: Cst<SizeR>) -> Result<Self::Output, ShapeError> {
+        broadcast_size(self.size, SizeR::USIZE)
     }
 }
 impl BroadcastShape<Dyn> for Dyn {
     type Output = Dyn;
-    fn broadcast(self, rhs: Dyn) -> Self::Output {
-        Dyn { size: max(self.size, rhs.size) }
+    fn try_broadcast(self, rhs: Dyn) -> Result<Self::Output, ShapeError> {
+        broadcast_size(self.size, rhs.size)
+    }
+}
+/// Broadcasts two sizes known only at runtime, they have to be equal or one of them has to be 1.
+fn broadcast_size(lhs: usize, rhs: usize) -> Result<Dyn, ShapeError> {
+    if lhs == rhs || rhs == 1 {
+        Ok(Dyn { size: lhs })
+    } else if lhs == 1 {
+        Ok(Dyn { size: rhs })
+    } else {
+        Err(ShapeError::Broadcast { axis: 0, lhs, rhs })
     }
 }
 impl MinSizeShape for Dyn {
@@ -133,48 +269,35 @@ impl Shape for () {
         generic_array::GenericArray::default()
     }
 }
+impl ConstShape for () {}
 impl MinSizeShape for () {
     type Value = typenum::U1;
 }
 impl<S: Shape> BroadcastShape<S> for () {
     type Output = S;
     #[inline(always)]
-    fn broadcast(self, rhs: S) -> Self::Output {
-        rhs
+    fn try_broadcast(self, rhs: S) -> Result<Self::Output, ShapeError> {
+        Ok(rhs)
     }
 }
 
 // Vectors
-impl<Size0: typenum::Unsigned> Shape for (Cst<Size0>,) {
-    type Dims = typenum::U1;
-    #[inline(always)]
-    fn size(&self) -> usize {
-        Size0::USIZE
-    }
-    #[inline(always)]
-    fn dimensions(&self) -> generic_array::GenericArray<usize, Self::Dims> {
-        generic_array::GenericArray::from([Size0::USIZE])
-    }
-    #[inline(always)]
-    fn strides(&self) -> generic_array::GenericArray<usize, Self::Dims> {
-        generic_array::GenericArray::from([1])
-    }
-}
-impl Shape for (Dyn,) {
+impl<D0: Dim> Shape for (D0,) {
     type Dims = typenum::U1;
     #[inline(always)]
     fn size(&self) -> usize {
-        self.0.size
+        self.0.size()
     }
     #[inline(always)]
     fn dimensions(&self) -> generic_array::GenericArray<usize, Self::Dims> {
-        generic_array::GenericArray::from([self.0.size])
+        generic_array::GenericArray::from([self.0.size()])
     }
     #[inline(always)]
     fn strides(&self) -> generic_array::GenericArray<usize, Self::Dims> {
         generic_array::GenericArray::from([1])
     }
 }
+impl<Size0: typenum::Unsigned> ConstShape for (Cst<Size0>,) {}
 impl<Size: typenum::Unsigned> MinSizeShape for (Cst<Size>,)
 {
     type Value = Size;
@@ -185,15 +308,15 @@ impl MinSizeShape for (Dyn,) {
 impl<L: Dim + BroadcastShape<R, Output = O>, R: Dim, O: Dim> BroadcastShape<(R,)> for (L,) {
     type Output = (O,);
     #[inline(always)]
-    fn broadcast(self, rhs: (R,)) -> Self::Output {
-        (self.0.broadcast(rhs.0),)
+    fn try_broadcast(self, rhs: (R,)) -> Result<Self::Output, ShapeError> {
+        Ok((self.0.try_broadcast(rhs.0).map_err(|e| e.at_axis(0))?,))
     }
 }
 impl<L: Dim> BroadcastShape<()> for (L,) {
     type Output = (L,);
     #[inline(always)]
-    fn broadcast(self, _: ()) -> Self::Output {
-        self
+    fn try_broadcast(self, _: ()) -> Result<Self::Output, ShapeError> {
+        Ok(self)
     }
 }
 
@@ -213,6 +336,7 @@ impl<D0: Dim, D1: Dim> Shape for (D0, D1,) {
         generic_array::GenericArray::from([self.1.size(), 1])
     }
 }
+impl<Size0: typenum::Unsigned, Size1: typenum::Unsigned> ConstShape for (Cst<Size0>, Cst<Size1>,) {}
 impl<
     D0: Dim + MinSizeShape,
     D1: Dim + MinSizeShape,
@@ -236,8 +360,11 @@ impl<
 {
     type Output = (O0, O1,);
     #[inline(always)]
-    fn broadcast(self, rhs: (R0, R1,)) -> Self::Output {
-        (self.0.broadcast(rhs.0), self.1.broadcast(rhs.1))
+    fn try_broadcast(self, rhs: (R0, R1,)) -> Result<Self::Output, ShapeError> {
+        Ok((
+            self.0.try_broadcast(rhs.0).map_err(|e| e.at_axis(0))?,
+            self.1.try_broadcast(rhs.1).map_err(|e| e.at_axis(1))?,
+        ))
     }
 }
 impl<
@@ -251,8 +378,8 @@ impl<
 {
     type Output = (D0, O0,);
     #[inline(always)]
-    fn broadcast(self, rhs: (R0,)) -> Self::Output {
-        (self.0, self.1.broadcast(rhs.0))
+    fn try_broadcast(self, rhs: (R0,)) -> Result<Self::Output, ShapeError> {
+        Ok((self.0, self.1.try_broadcast(rhs.0).map_err(|e| e.at_axis(1))?))
     }
 }
 impl<
@@ -266,8 +393,16 @@ impl<
 {
     type Output = (R0, O0,);
     #[inline(always)]
-    fn broadcast(self, rhs: (R0, R1,)) -> Self::Output {
-        (rhs.0, self.0.broadcast(rhs.1))
+    fn try_broadcast(self, rhs: (R0, R1,)) -> Result<Self::Output, ShapeError> {
+        Ok((rhs.0, self.0.try_broadcast(rhs.1).map_err(|e| e.at_axis(1))?))
+    }
+}
+
+impl<D0: Dim, D1: Dim> BroadcastShape<()> for (D0, D1,) {
+    type Output = (D0, D1,);
+    #[inline(always)]
+    fn try_broadcast(self, _: ()) -> Result<Self::Output, ShapeError> {
+        Ok(self)
     }
 }
 
@@ -287,6 +422,11 @@ impl<D0: Dim, D1: Dim, D2: Dim> Shape for (D0, D1, D2,) {
         generic_array::GenericArray::from([self.1.size() * self.2.size(), self.2.size(), 1])
     }
 }
+impl<
+    Size0: typenum::Unsigned,
+    Size1: typenum::Unsigned,
+    Size2: typenum::Unsigned,
+> ConstShape for (Cst<Size0>, Cst<Size1>, Cst<Size2>,) {}
 impl<
     D0: Dim + MinSizeShape,
     D1: Dim + MinSizeShape,
@@ -316,8 +456,12 @@ impl<
 {
     type Output = (O0, O1, O2,);
     #[inline(always)]
-    fn broadcast(self, rhs: (R0, R1, R2,)) -> Self::Output {
-        (self.0.broadcast(rhs.0), self.1.broadcast(rhs.1), self.2.broadcast(rhs.2))
+    fn try_broadcast(self, rhs: (R0, R1, R2,)) -> Result<Self::Output, ShapeError> {
+        Ok((
+            self.0.try_broadcast(rhs.0).map_err(|e| e.at_axis(0))?,
+            self.1.try_broadcast(rhs.1).map_err(|e| e.at_axis(1))?,
+            self.2.try_broadcast(rhs.2).map_err(|e| e.at_axis(2))?,
+        ))
     }
 }
 impl<
@@ -334,8 +478,12 @@ impl<
 {
     type Output = (D0, O1, O2,);
     #[inline(always)]
-    fn broadcast(self, rhs: (R0, R1,)) -> Self::Output {
-        (self.0, self.1.broadcast(rhs.0), self.2.broadcast(rhs.1))
+    fn try_broadcast(self, rhs: (R0, R1,)) -> Result<Self::Output, ShapeError> {
+        Ok((
+            self.0,
+            self.1.try_broadcast(rhs.0).map_err(|e| e.at_axis(1))?,
+            self.2.try_broadcast(rhs.1).map_err(|e| e.at_axis(2))?,
+        ))
     }
 }
 impl<
@@ -350,8 +498,58 @@ impl<
 {
     type Output = (D0, D1, O2,);
     #[inline(always)]
-    fn broadcast(self, rhs: (R0,)) -> Self::Output {
-        (self.0, self.1, self.2.broadcast(rhs.0))
+    fn try_broadcast(self, rhs: (R0,)) -> Result<Self::Output, ShapeError> {
+        Ok((self.0, self.1, self.2.try_broadcast(rhs.0).map_err(|e| e.at_axis(2))?))
+    }
+}
+
+impl<
+    D0: Dim + BroadcastShape<R2, Output = O2>,
+    R0: Dim,
+    R1: Dim,
+    R2: Dim,
+    O2: Dim,
+> BroadcastShape<(R0, R1, R2,)> for (D0,)
+    where
+        (R0, R1, O2,): Shape,
+{
+    type Output = (R0, R1, O2,);
+    #[inline(always)]
+    fn try_broadcast(self, rhs: (R0, R1, R2,)) -> Result<Self::Output, ShapeError> {
+        Ok((
+            rhs.0,
+            rhs.1,
+            self.0.try_broadcast(rhs.2).map_err(|e| e.at_axis(2))?,
+        ))
+    }
+}
+impl<
+    D0: Dim + BroadcastShape<R1, Output = O1>,
+    D1: Dim + BroadcastShape<R2, Output = O2>,
+    R0: Dim,
+    R1: Dim,
+    R2: Dim,
+    O1: Dim,
+    O2: Dim,
+> BroadcastShape<(R0, R1, R2,)> for (D0, D1,)
+    where
+        (R0, O1, O2,): Shape,
+{
+    type Output = (R0, O1, O2,);
+    #[inline(always)]
+    fn try_broadcast(self, rhs: (R0, R1, R2,)) -> Result<Self::Output, ShapeError> {
+        Ok((
+            rhs.0,
+            self.0.try_broadcast(rhs.1).map_err(|e| e.at_axis(1))?,
+            self.1.try_broadcast(rhs.2).map_err(|e| e.at_axis(2))?,
+        ))
+    }
+}
+impl<D0: Dim, D1: Dim, D2: Dim> BroadcastShape<()> for (D0, D1, D2,) {
+    type Output = (D0, D1, D2,);
+    #[inline(always)]
+    fn try_broadcast(self, _: ()) -> Result<Self::Output, ShapeError> {
+        Ok(self)
     }
 }
 
@@ -381,6 +579,12 @@ impl<D0: Dim, D1: Dim, D2: Dim, D3: Dim> Shape for (D0, D1, D2, D3,) {
         ])
     }
 }
+impl<
+    Size0: typenum::Unsigned,
+    Size1: typenum::Unsigned,
+    Size2: typenum::Unsigned,
+    Size3: typenum::Unsigned,
+> ConstShape for (Cst<Size0>, Cst<Size1>, Cst<Size2>, Cst<Size3>,) {}
 impl<
     D0: Dim + MinSizeShape,
     D1: Dim + MinSizeShape,
@@ -416,13 +620,13 @@ impl<
 {
     type Output = (O0, O1, O2, O3,);
     #[inline(always)]
-    fn broadcast(self, rhs: (R0, R1, R2, R3,)) -> Self::Output {
-        (
-            self.0.broadcast(rhs.0),
-            self.1.broadcast(rhs.1),
-            self.2.broadcast(rhs.2),
-            self.3.broadcast(rhs.3),
-        )
+    fn try_broadcast(self, rhs: (R0, R1, R2, R3,)) -> Result<Self::Output, ShapeError> {
+        Ok((
+            self.0.try_broadcast(rhs.0).map_err(|e| e.at_axis(0))?,
+            self.1.try_broadcast(rhs.1).map_err(|e| e.at_axis(1))?,
+            self.2.try_broadcast(rhs.2).map_err(|e| e.at_axis(2))?,
+            self.3.try_broadcast(rhs.3).map_err(|e| e.at_axis(3))?,
+        ))
     }
 }
 impl<
@@ -442,13 +646,13 @@ impl<
 {
     type Output = (D0, O1, O2, O3,);
     #[inline(always)]
-    fn broadcast(self, rhs: (R0, R1, R2,)) -> Self::Output {
-        (
+    fn try_broadcast(self, rhs: (R0, R1, R2,)) -> Result<Self::Output, ShapeError> {
+        Ok((
             self.0,
-            self.1.broadcast(rhs.0),
-            self.2.broadcast(rhs.1),
-            self.3.broadcast(rhs.2),
-        )
+            self.1.try_broadcast(rhs.0).map_err(|e| e.at_axis(1))?,
+            self.2.try_broadcast(rhs.1).map_err(|e| e.at_axis(2))?,
+            self.3.try_broadcast(rhs.2).map_err(|e| e.at_axis(3))?,
+        ))
     }
 }
 impl<
@@ -466,8 +670,13 @@ impl<
 {
     type Output = (D0, D1, O2, O3,);
     #[inline(always)]
-    fn broadcast(self, rhs: (R0, R1,)) -> Self::Output {
-        (self.0, self.1, self.2.broadcast(rhs.0), self.3.broadcast(rhs.1))
+    fn try_broadcast(self, rhs: (R0, R1,)) -> Result<Self::Output, ShapeError> {
+        Ok((
+            self.0,
+            self.1,
+            self.2.try_broadcast(rhs.0).map_err(|e| e.at_axis(2))?,
+            self.3.try_broadcast(rhs.1).map_err(|e| e.at_axis(3))?,
+        ))
     }
 }
 impl<
@@ -483,8 +692,88 @@ impl<
 {
     type Output = (D0, D1, D2, O3,);
     #[inline(always)]
-    fn broadcast(self, rhs: (R0,)) -> Self::Output {
-        (self.0, self.1, self.2, self.3.broadcast(rhs.0))
+    fn try_broadcast(self, rhs: (R0,)) -> Result<Self::Output, ShapeError> {
+        Ok((self.0, self.1, self.2, self.3.try_broadcast(rhs.0).map_err(|e| e.at_axis(3))?))
+    }
+}
+
+impl<
+    D0: Dim + BroadcastShape<R3, Output = O3>,
+    R0: Dim,
+    R1: Dim,
+    R2: Dim,
+    R3: Dim,
+    O3: Dim,
+> BroadcastShape<(R0, R1, R2, R3,)> for (D0,)
+    where
+        (R0, R1, R2, O3,): Shape,
+{
+    type Output = (R0, R1, R2, O3,);
+    #[inline(always)]
+    fn try_broadcast(self, rhs: (R0, R1, R2, R3,)) -> Result<Self::Output, ShapeError> {
+        Ok((
+            rhs.0,
+            rhs.1,
+            rhs.2,
+            self.0.try_broadcast(rhs.3).map_err(|e| e.at_axis(3))?,
+        ))
+    }
+}
+impl<
+    D0: Dim + BroadcastShape<R2, Output = O2>,
+    D1: Dim + BroadcastShape<R3, Output = O3>,
+    R0: Dim,
+    R1: Dim,
+    R2: Dim,
+    R3: Dim,
+    O2: Dim,
+    O3: Dim,
+> BroadcastShape<(R0, R1, R2, R3,)> for (D0, D1,)
+    where
+        (R0, R1, O2, O3,): Shape,
+{
+    type Output = (R0, R1, O2, O3,);
+    #[inline(always)]
+    fn try_broadcast(self, rhs: (R0, R1, R2, R3,)) -> Result<Self::Output, ShapeError> {
+        Ok((
+            rhs.0,
+            rhs.1,
+            self.0.try_broadcast(rhs.2).map_err(|e| e.at_axis(2))?,
+            self.1.try_broadcast(rhs.3).map_err(|e| e.at_axis(3))?,
+        ))
+    }
+}
+impl<
+    D0: Dim + BroadcastShape<R1, Output = O1>,
+    D1: Dim + BroadcastShape<R2, Output = O2>,
+    D2: Dim + BroadcastShape<R3, Output = O3>,
+    R0: Dim,
+    R1: Dim,
+    R2: Dim,
+    R3: Dim,
+    O1: Dim,
+    O2: Dim,
+    O3: Dim,
+> BroadcastShape<(R0, R1, R2, R3,)> for (D0, D1, D2,)
+    where
+        (R0, O1, O2, O3,): Shape,
+{
+    type Output = (R0, O1, O2, O3,);
+    #[inline(always)]
+    fn try_broadcast(self, rhs: (R0, R1, R2, R3,)) -> Result<Self::Output, ShapeError> {
+        Ok((
+            rhs.0,
+            self.0.try_broadcast(rhs.1).map_err(|e| e.at_axis(1))?,
+            self.1.try_broadcast(rhs.2).map_err(|e| e.at_axis(2))?,
+            self.2.try_broadcast(rhs.3).map_err(|e| e.at_axis(3))?,
+        ))
+    }
+}
+impl<D0: Dim, D1: Dim, D2: Dim, D3: Dim> BroadcastShape<()> for (D0, D1, D2, D3,) {
+    type Output = (D0, D1, D2, D3,);
+    #[inline(always)]
+    fn try_broadcast(self, _: ()) -> Result<Self::Output, ShapeError> {
+        Ok(self)
     }
 }
 
@@ -516,6 +805,13 @@ impl<D0: Dim, D1: Dim, D2: Dim, D3: Dim, D4: Dim> Shape for (D0, D1, D2, D3, D4,
         ])
     }
 }
+impl<
+    Size0: typenum::Unsigned,
+    Size1: typenum::Unsigned,
+    Size2: typenum::Unsigned,
+    Size3: typenum::Unsigned,
+    Size4: typenum::Unsigned,
+> ConstShape for (Cst<Size0>, Cst<Size1>, Cst<Size2>, Cst<Size3>, Cst<Size4>,) {}
 impl<
     D0: Dim + MinSizeShape,
     D1: Dim + MinSizeShape,
@@ -557,14 +853,14 @@ impl<
 {
     type Output = (O0, O1, O2, O3, O4,);
     #[inline(always)]
-    fn broadcast(self, rhs: (R0, R1, R2, R3, R4,)) -> Self::Output {
-        (
-            self.0.broadcast(rhs.0),
-            self.1.broadcast(rhs.1),
-            self.2.broadcast(rhs.2),
-            self.3.broadcast(rhs.3),
-            self.4.broadcast(rhs.4),
-        )
+    fn try_broadcast(self, rhs: (R0, R1, R2, R3, R4,)) -> Result<Self::Output, ShapeError> {
+        Ok((
+            self.0.try_broadcast(rhs.0).map_err(|e| e.at_axis(0))?,
+            self.1.try_broadcast(rhs.1).map_err(|e| e.at_axis(1))?,
+            self.2.try_broadcast(rhs.2).map_err(|e| e.at_axis(2))?,
+            self.3.try_broadcast(rhs.3).map_err(|e| e.at_axis(3))?,
+            self.4.try_broadcast(rhs.4).map_err(|e| e.at_axis(4))?,
+        ))
     }
 }
 impl<
@@ -587,14 +883,14 @@ impl<
 {
     type Output = (D0, O1, O2, O3, O4,);
     #[inline(always)]
-    fn broadcast(self, rhs: (R0, R1, R2, R3,)) -> Self::Output {
-        (
+    fn try_broadcast(self, rhs: (R0, R1, R2, R3,)) -> Result<Self::Output, ShapeError> {
+        Ok((
             self.0,
-            self.1.broadcast(rhs.0),
-            self.2.broadcast(rhs.1),
-            self.3.broadcast(rhs.2),
-            self.4.broadcast(rhs.3),
-        )
+            self.1.try_broadcast(rhs.0).map_err(|e| e.at_axis(1))?,
+            self.2.try_broadcast(rhs.1).map_err(|e| e.at_axis(2))?,
+            self.3.try_broadcast(rhs.2).map_err(|e| e.at_axis(3))?,
+            self.4.try_broadcast(rhs.3).map_err(|e| e.at_axis(4))?,
+        ))
     }
 }
 impl<
@@ -615,14 +911,14 @@ impl<
 {
     type Output = (D0, D1, O2, O3, O4,);
     #[inline(always)]
-    fn broadcast(self, rhs: (R0, R1, R2,)) -> Self::Output {
-        (
+    fn try_broadcast(self, rhs: (R0, R1, R2,)) -> Result<Self::Output, ShapeError> {
+        Ok((
             self.0,
             self.1,
-            self.2.broadcast(rhs.0),
-            self.3.broadcast(rhs.1),
-            self.4.broadcast(rhs.2),
-        )
+            self.2.try_broadcast(rhs.0).map_err(|e| e.at_axis(2))?,
+            self.3.try_broadcast(rhs.1).map_err(|e| e.at_axis(3))?,
+            self.4.try_broadcast(rhs.2).map_err(|e| e.at_axis(4))?,
+        ))
     }
 }
 impl<
@@ -641,14 +937,14 @@ impl<
 {
     type Output = (D0, D1, D2, O3, O4,);
     #[inline(always)]
-    fn broadcast(self, rhs: (R0, R1,)) -> Self::Output {
-        (
+    fn try_broadcast(self, rhs: (R0, R1,)) -> Result<Self::Output, ShapeError> {
+        Ok((
             self.0,
             self.1,
             self.2,
-            self.3.broadcast(rhs.0),
-            self.4.broadcast(rhs.1),
-        )
+            self.3.try_broadcast(rhs.0).map_err(|e| e.at_axis(3))?,
+            self.4.try_broadcast(rhs.1).map_err(|e| e.at_axis(4))?,
+        ))
     }
 }
 impl<
@@ -665,14 +961,130 @@ impl<
 {
     type Output = (D0, D1, D2, D3, O4,);
     #[inline(always)]
-    fn broadcast(self, rhs: (R0,)) -> Self::Output {
-        (
+    fn try_broadcast(self, rhs: (R0,)) -> Result<Self::Output, ShapeError> {
+        Ok((
             self.0,
             self.1,
             self.2,
             self.3,
-            self.4.broadcast(rhs.0),
-        )
+            self.4.try_broadcast(rhs.0).map_err(|e| e.at_axis(4))?,
+        ))
+    }
+}
+
+impl<
+    D0: Dim + BroadcastShape<R4, Output = O4>,
+    R0: Dim,
+    R1: Dim,
+    R2: Dim,
+    R3: Dim,
+    R4: Dim,
+    O4: Dim,
+> BroadcastShape<(R0, R1, R2, R3, R4,)> for (D0,)
+    where
+        (R0, R1, R2, R3, O4,): Shape,
+{
+    type Output = (R0, R1, R2, R3, O4,);
+    #[inline(always)]
+    fn try_broadcast(self, rhs: (R0, R1, R2, R3, R4,)) -> Result<Self::Output, ShapeError> {
+        Ok((
+            rhs.0,
+            rhs.1,
+            rhs.2,
+            rhs.3,
+            self.0.try_broadcast(rhs.4).map_err(|e| e.at_axis(4))?,
+        ))
+    }
+}
+impl<
+    D0: Dim + BroadcastShape<R3, Output = O3>,
+    D1: Dim + BroadcastShape<R4, Output = O4>,
+    R0: Dim,
+    R1: Dim,
+    R2: Dim,
+    R3: Dim,
+    R4: Dim,
+    O3: Dim,
+    O4: Dim,
+> BroadcastShape<(R0, R1, R2, R3, R4,)> for (D0, D1,)
+    where
+        (R0, R1, R2, O3, O4,): Shape,
+{
+    type Output = (R0, R1, R2, O3, O4,);
+    #[inline(always)]
+    fn try_broadcast(self, rhs: (R0, R1, R2, R3, R4,)) -> Result<Self::Output, ShapeError> {
+        Ok((
+            rhs.0,
+            rhs.1,
+            rhs.2,
+            self.0.try_broadcast(rhs.3).map_err(|e| e.at_axis(3))?,
+            self.1.try_broadcast(rhs.4).map_err(|e| e.at_axis(4))?,
+        ))
+    }
+}
+impl<
+    D0: Dim + BroadcastShape<R2, Output = O2>,
+    D1: Dim + BroadcastShape<R3, Output = O3>,
+    D2: Dim + BroadcastShape<R4, Output = O4>,
+    R0: Dim,
+    R1: Dim,
+    R2: Dim,
+    R3: Dim,
+    R4: Dim,
+    O2: Dim,
+    O3: Dim,
+    O4: Dim,
+> BroadcastShape<(R0, R1, R2, R3, R4,)> for (D0, D1, D2,)
+    where
+        (R0, R1, O2, O3, O4,): Shape,
+{
+    type Output = (R0, R1, O2, O3, O4,);
+    #[inline(always)]
+    fn try_broadcast(self, rhs: (R0, R1, R2, R3, R4,)) -> Result<Self::Output, ShapeError> {
+        Ok((
+            rhs.0,
+            rhs.1,
+            self.0.try_broadcast(rhs.2).map_err(|e| e.at_axis(2))?,
+            self.1.try_broadcast(rhs.3).map_err(|e| e.at_axis(3))?,
+            self.2.try_broadcast(rhs.4).map_err(|e| e.at_axis(4))?,
+        ))
+    }
+}
+impl<
+    D0: Dim + BroadcastShape<R1, Output = O1>,
+    D1: Dim + BroadcastShape<R2, Output = O2>,
+    D2: Dim + BroadcastShape<R3, Output = O3>,
+    D3: Dim + BroadcastShape<R4, Output = O4>,
+    R0: Dim,
+    R1: Dim,
+    R2: Dim,
+    R3: Dim,
+    R4: Dim,
+    O1: Dim,
+    O2: Dim,
+    O3: Dim,
+    O4: Dim,
+> BroadcastShape<(R0, R1, R2, R3, R4,)> for (D0, D1, D2, D3,)
+    where
+        (R0, O1, O2, O3, O4,): Shape,
+{
+    type Output = (R0, O1, O2, O3, O4,);
+    #[inline(always)]
+    fn try_broadcast(self, rhs: (R0, R1, R2, R3, R4,)) -> Result<Self::Output, ShapeError> {
+        Ok((
+            rhs.0,
+            self.0.try_broadcast(rhs.1).map_err(|e| e.at_axis(1))?,
+            self.1.try_broadcast(rhs.2).map_err(|e| e.at_axis(2))?,
+            self.2.try_broadcast(rhs.3).map_err(|e| e.at_axis(3))?,
+            self.3.try_broadcast(rhs.4).map_err(|e| e.at_axis(4))?,
+        ))
+    }
+}
+impl<D0: Dim, D1: Dim, D2: Dim, D3: Dim, D4: Dim> BroadcastShape<()> for (D0, D1, D2, D3, D4,) {
+    type Output = (D0, D1, D2, D3, D4,);
+    #[inline(always)]
+    fn try_broadcast(self, _: ()) -> Result<Self::Output, ShapeError> {
+        Ok(self)
     }
 }
 
@@ -706,6 +1118,14 @@ impl<D0: Dim, D1: Dim, D2: Dim, D3: Dim, D4: Dim, D5: Dim> Shape for (D0, D1, D2
         ])
     }
 }
+impl<
+    Size0: typenum::Unsigned,
+    Size1: typenum::Unsigned,
+    Size2: typenum::Unsigned,
+    Size3: typenum::Unsigned,
+    Size4: typenum::Unsigned,
+    Size5: typenum::Unsigned,
+> ConstShape for (Cst<Size0>, Cst<Size1>, Cst<Size2>, Cst<Size3>, Cst<Size4>, Cst<Size5>,) {}
 impl<
     D0: Dim + MinSizeShape,
     D1: Dim + MinSizeShape,
@@ -753,15 +1173,15 @@ impl<
 {
     type Output = (O0, O1, O2, O3, O4, O5,);
     #[inline(always)]
-    fn broadcast(self, rhs: (R0, R1, R2, R3, R4, R5,)) -> Self::Output {
-        (
-            self.0.broadcast(rhs.0),
-            self.1.broadcast(rhs.1),
-            self.2.broadcast(rhs.2),
-            self.3.broadcast(rhs.3),
-            self.4.broadcast(rhs.4),
-            self.5.broadcast(rhs.5),
-        )
+    fn try_broadcast(self, rhs: (R0, R1, R2, R3, R4, R5,)) -> Result<Self::Output, ShapeError> {
+        Ok((
+            self.0.try_broadcast(rhs.0).map_err(|e| e.at_axis(0))?,
+            self.1.try_broadcast(rhs.1).map_err(|e| e.at_axis(1))?,
+            self.2.try_broadcast(rhs.2).map_err(|e| e.at_axis(2))?,
+            self.3.try_broadcast(rhs.3).map_err(|e| e.at_axis(3))?,
+            self.4.try_broadcast(rhs.4).map_err(|e| e.at_axis(4))?,
+            self.5.try_broadcast(rhs.5).map_err(|e| e.at_axis(5))?,
+        ))
     }
 }
 impl<
@@ -787,15 +1207,15 @@ impl<
 {
     type Output = (D0, O1, O2, O3, O4, O5,);
     #[inline(always)]
-    fn broadcast(self, rhs: (R0, R1, R2, R3, R4,)) -> Self::Output {
-        (
+    fn try_broadcast(self, rhs: (R0, R1, R2, R3, R4,)) -> Result<Self::Output, ShapeError> {
+        Ok((
             self.0,
-            self.1.broadcast(rhs.0),
-            self.2.broadcast(rhs.1),
-            self.3.broadcast(rhs.2),
-            self.4.broadcast(rhs.3),
-            self.5.broadcast(rhs.4),
-        )
+            self.1.try_broadcast(rhs.0).map_err(|e| e.at_axis(1))?,
+            self.2.try_broadcast(rhs.1).map_err(|e| e.at_axis(2))?,
+            self.3.try_broadcast(rhs.2).map_err(|e| e.at_axis(3))?,
+            self.4.try_broadcast(rhs.3).map_err(|e| e.at_axis(4))?,
+            self.5.try_broadcast(rhs.4).map_err(|e| e.at_axis(5))?,
+        ))
     }
 }
 impl<
@@ -819,15 +1239,15 @@ impl<
 {
     type Output = (D0, D1, O2, O3, O4, O5,);
     #[inline(always)]
-    fn broadcast(self, rhs: (R0, R1, R2, R3,)) -> Self::Output {
-        (
+    fn try_broadcast(self, rhs: (R0, R1, R2, R3,)) -> Result<Self::Output, ShapeError> {
+        Ok((
             self.0,
             self.1,
-            self.2.broadcast(rhs.0),
-            self.3.broadcast(rhs.1),
-            self.4.broadcast(rhs.2),
-            self.5.broadcast(rhs.3),
-        )
+            self.2.try_broadcast(rhs.0).map_err(|e| e.at_axis(2))?,
+            self.3.try_broadcast(rhs.1).map_err(|e| e.at_axis(3))?,
+            self.4.try_broadcast(rhs.2).map_err(|e| e.at_axis(4))?,
+            self.5.try_broadcast(rhs.3).map_err(|e| e.at_axis(5))?,
+        ))
     }
 }
 impl<
@@ -849,15 +1269,15 @@ impl<
 {
     type Output = (D0, D1, D2, O3, O4, O5,);
     #[inline(always)]
-    fn broadcast(self, rhs: (R0, R1, R2,)) -> Self::Output {
-        (
+    fn try_broadcast(self, rhs: (R0, R1, R2,)) -> Result<Self::Output, ShapeError> {
+        Ok((
             self.0,
             self.1,
             self.2,
-            self.3.broadcast(rhs.0),
-            self.4.broadcast(rhs.1),
-            self.5.broadcast(rhs.2),
-        )
+            self.3.try_broadcast(rhs.0).map_err(|e| e.at_axis(3))?,
+            self.4.try_broadcast(rhs.1).map_err(|e| e.at_axis(4))?,
+            self.5.try_broadcast(rhs.2).map_err(|e| e.at_axis(5))?,
+        ))
     }
 }
 impl<
@@ -877,15 +1297,15 @@ impl<
 {
     type Output = (D0, D1, D2, D3, O4, O5,);
     #[inline(always)]
-    fn broadcast(self, rhs: (R0, R1,)) -> Self::Output {
-        (
+    fn try_broadcast(self, rhs: (R0, R1,)) -> Result<Self::Output, ShapeError> {
+        Ok((
             self.0,
             self.1,
             self.2,
             self.3,
-            self.4.broadcast(rhs.0),
-            self.5.broadcast(rhs.1),
-        )
+            self.4.try_broadcast(rhs.0).map_err(|e| e.at_axis(4))?,
+            self.5.try_broadcast(rhs.1).map_err(|e| e.at_axis(5))?,
+        ))
     }
 }
 impl<
@@ -903,14 +1323,1458 @@ impl<
 {
     type Output = (D0, D1, D2, D3, D4, O5,);
     #[inline(always)]
-    fn broadcast(self, rhs: (R0,)) -> Self::Output {
-        (
+    fn try_broadcast(self, rhs: (R0,)) -> Result<Self::Output, ShapeError> {
+        Ok((
             self.0,
             self.1,
             self.2,
             self.3,
             self.4,
-            self.5.broadcast(rhs.0),
-        )
+            self.5.try_broadcast(rhs.0).map_err(|e| e.at_axis(5))?,
+        ))
+    }
+}
+impl<
+    D0: Dim + BroadcastShape<R5, Output = O5>,
+    R0: Dim,
+    R1: Dim,
+    R2: Dim,
+    R3: Dim,
+    R4: Dim,
+    R5: Dim,
+    O5: Dim,
+> BroadcastShape<(R0, R1, R2, R3, R4, R5,)> for (D0,)
+    where
+        (R0, R1, R2, R3, R4, O5,): Shape,
+{
+    type Output = (R0, R1, R2, R3, R4, O5,);
+    #[inline(always)]
+    fn try_broadcast(self, rhs: (R0, R1, R2, R3, R4, R5,)) -> Result<Self::Output, ShapeError> {
+        Ok((
+            rhs.0,
+            rhs.1,
+            rhs.2,
+            rhs.3,
+            rhs.4,
+            self.0.try_broadcast(rhs.5).map_err(|e| e.at_axis(5))?,
+        ))
+    }
+}
+impl<
+    D0: Dim + BroadcastShape<R4, Output = O4>,
+    D1: Dim + BroadcastShape<R5, Output = O5>,
+    R0: Dim,
+    R1: Dim,
+    R2: Dim,
+    R3: Dim,
+    R4: Dim,
+    R5: Dim,
+    O4: Dim,
+    O5: Dim,
+> BroadcastShape<(R0, R1, R2, R3, R4, R5,)> for (D0, D1,)
+    where
+        (R0, R1, R2, R3, O4, O5,): Shape,
+{
+    type Output = (R0, R1, R2, R3, O4, O5,);
+    #[inline(always)]
+    fn try_broadcast(self, rhs: (R0, R1, R2, R3, R4, R5,)) -> Result<Self::Output, ShapeError> {
+        Ok((
+            rhs.0,
+            rhs.1,
+            rhs.2,
+            rhs.3,
+            self.0.try_broadcast(rhs.4).map_err(|e| e.at_axis(4))?,
+            self.1.try_broadcast(rhs.5).map_err(|e| e.at_axis(5))?,
+        ))
+    }
+}
+impl<
+    D0: Dim + BroadcastShape<R3, Output = O3>,
+    D1: Dim + BroadcastShape<R4, Output = O4>,
+    D2: Dim + BroadcastShape<R5, Output = O5>,
+    R0: Dim,
+    R1: Dim,
+    R2: Dim,
+    R3: Dim,
+    R4: Dim,
+    R5: Dim,
+    O3: Dim,
+    O4: Dim,
+    O5: Dim,
+> BroadcastShape<(R0, R1, R2, R3, R4, R5,)> for (D0, D1, D2,)
+    where
+        (R0, R1, R2, O3, O4, O5,): Shape,
+{
+    type Output = (R0, R1, R2, O3, O4, O5,);
+    #[inline(always)]
+    fn try_broadcast(self, rhs: (R0, R1, R2, R3, R4, R5,)) -> Result<Self::Output, ShapeError> {
+        Ok((
+            rhs.0,
+            rhs.1,
+            rhs.2,
+            self.0.try_broadcast(rhs.3).map_err(|e| e.at_axis(3))?,
+            self.1.try_broadcast(rhs.4).map_err(|e| e.at_axis(4))?,
+            self.2.try_broadcast(rhs.5).map_err(|e| e.at_axis(5))?,
+        ))
+    }
+}
+impl<
+    D0: Dim + BroadcastShape<R2, Output = O2>,
+    D1: Dim + BroadcastShape<R3, Output = O3>,
+    D2: Dim + BroadcastShape<R4, Output = O4>,
+    D3: Dim + BroadcastShape<R5, Output = O5>,
+    R0: Dim,
+    R1: Dim,
+    R2: Dim,
+    R3: Dim,
+    R4: Dim,
+    R5: Dim,
+    O2: Dim,
+    O3: Dim,
+    O4: Dim,
+    O5: Dim,
+> BroadcastShape<(R0, R1, R2, R3, R4, R5,)> for (D0, D1, D2, D3,)
+    where
+        (R0, R1, O2, O3, O4, O5,): Shape,
+{
+    type Output = (R0, R1, O2, O3, O4, O5,);
+    #[inline(always)]
+    fn try_broadcast(self, rhs: (R0, R1, R2, R3, R4, R5,)) -> Result<Self::Output, ShapeError> {
+        Ok((
+            rhs.0,
+            rhs.1,
+            self.0.try_broadcast(rhs.2).map_err(|e| e.at_axis(2))?,
+            self.1.try_broadcast(rhs.3).map_err(|e| e.at_axis(3))?,
+            self.2.try_broadcast(rhs.4).map_err(|e| e.at_axis(4))?,
+            self.3.try_broadcast(rhs.5).map_err(|e| e.at_axis(5))?,
+        ))
     }
-}
\ No newline at end of file
+}
+impl<
+    D0: Dim + BroadcastShape<R1, Output = O1>,
+    D1: Dim + BroadcastShape<R2, Output = O2>,
+    D2: Dim + BroadcastShape<R3, Output = O3>,
+    D3: Dim + BroadcastShape<R4, Output = O4>,
+    D4: Dim + BroadcastShape<R5, Output = O5>,
+    R0: Dim,
+    R1: Dim,
+    R2: Dim,
+    R3: Dim,
+    R4: Dim,
+    R5: Dim,
+    O1: Dim,
+    O2: Dim,
+    O3: Dim,
+    O4: Dim,
+    O5: Dim,
+> BroadcastShape<(R0, R1, R2, R3, R4, R5,)> for (D0, D1, D2, D3, D4,)
+    where
+        (R0, O1, O2, O3, O4, O5,): Shape,
+{
+    type Output = (R0, O1, O2, O3, O4, O5,);
+    #[inline(always)]
+    fn try_broadcast(self, rhs: (R0, R1, R2, R3, R4, R5,)) -> Result<Self::Output, ShapeError> {
+        Ok((
+            rhs.0,
+            self.0.try_broadcast(rhs.1).map_err(|e| e.at_axis(1))?,
+            self.1.try_broadcast(rhs.2).map_err(|e| e.at_axis(2))?,
+            self.2.try_broadcast(rhs.3).map_err(|e| e.at_axis(3))?,
+            self.3.try_broadcast(rhs.4).map_err(|e| e.at_axis(4))?,
+            self.4.try_broadcast(rhs.5).map_err(|e| e.at_axis(5))?,
+        ))
+    }
+}
+impl<D0: Dim, D1: Dim, D2: Dim, D3: Dim, D4: Dim, D5: Dim> BroadcastShape<()> for (D0, D1, D2, D3, D4, D5,) {
+    type Output = (D0, D1, D2, D3, D4, D5,);
+    #[inline(always)]
+    fn try_broadcast(self, _: ()) -> Result<Self::Output, ShapeError> {
+        Ok(self)
+    }
+}
+
+// Axis permutations
+
+/// Shapes with an axis at the `typenum` index `I`.
+pub trait Axis<I>: Shape {
+    type Output: Dim;
+    fn axis(&self) -> Self::Output;
+}
+/// Shapes whose axis at the `typenum` index `I` can be replaced by `D`.
+pub trait ReplaceAxis<I, D: Dim>: Shape {
+    type Output: Shape;
+    fn replace_axis(self, dim: D) -> Self::Output;
+}
+/// Shapes which can be permuted by `P`, a tuple of `typenum` axis indices.
+///
+/// Axis `i` of the output is axis `P.i` of the input, every axis has to appear exactly once which
+/// is checked at compile time.
+///
+/// ```
+/// use deeper::{Cst, Dyn, PermuteShape, Shape};
+/// use typenum::{U0, U1, U2, U3};
+///
+/// let shape = PermuteShape::<(U2, U0, U1)>::permute((Dyn::new(2), Cst::<U3>::new(), Dyn::new(4)));
+/// assert_eq!(shape.dimensions().as_slice(), &[4, 2, 3]);
+/// ```
+///
+/// ```compile_fail
+/// use deeper::{Dyn, PermuteShape};
+/// use typenum::U0;
+///
+/// PermuteShape::<(U0, U0)>::permute((Dyn::new(2), Dyn::new(3)));
+/// ```
+pub trait PermuteShape<P>: Shape {
+    type Output: Shape;
+    fn permute(self) -> Self::Output;
+    /// The input axis of every output axis.
+    fn axes() -> generic_array::GenericArray<usize, Self::Dims>;
+}
+/// Shapes whose axes `A` and `B` can be swapped.
+pub trait SwapAxes<A, B>: Shape {
+    type Output: Shape;
+    fn swap_axes(self) -> Self::Output;
+}
+impl<S, A: typenum::Unsigned, B: typenum::Unsigned> SwapAxes<A, B> for S
+    where
+        S: Axis<A> + Axis<B> + ReplaceAxis<A, <S as Axis<B>>::Output>,
+        <S as ReplaceAxis<A, <S as Axis<B>>::Output>>::Output:
+            ReplaceAxis<B, <S as Axis<A>>::Output>,
+{
+    type Output = <<S as ReplaceAxis<A, <S as Axis<B>>::Output>>::Output as ReplaceAxis<
+        B,
+        <S as Axis<A>>::Output,
+    >>::Output;
+    fn swap_axes(self) -> Self::Output {
+        let a = Axis::<A>::axis(&self);
+        let b = Axis::<B>::axis(&self);
+
+        self.replace_axis(b).replace_axis(a)
+    }
+}
+
+impl<D0: Dim> Axis<typenum::U0> for (D0,) {
+    type Output = D0;
+    #[inline(always)]
+    fn axis(&self) -> Self::Output {
+        self.0
+    }
+}
+impl<D0: Dim, D: Dim> ReplaceAxis<typenum::U0, D> for (D0,) {
+    type Output = (D,);
+    #[inline(always)]
+    fn replace_axis(self, dim: D) -> Self::Output {
+        (dim,)
+    }
+}
+impl<
+    D0: Dim,
+    P0: typenum::Unsigned,
+> PermuteShape<(P0,)> for (D0,)
+    where
+        Self: Axis<P0>,
+{
+    type Output = (<Self as Axis<P0>>::Output,);
+    #[inline(always)]
+    fn permute(self) -> Self::Output {
+        (Axis::<P0>::axis(&self),)
+    }
+    #[inline(always)]
+    fn axes() -> generic_array::GenericArray<usize, Self::Dims> {
+        let mut axes = generic_array::GenericArray::default();
+        axes[0] = P0::USIZE;
+
+        axes
+    }
+}
+impl<D0: Dim, D1: Dim> Axis<typenum::U0> for (D0, D1,) {
+    type Output = D0;
+    #[inline(always)]
+    fn axis(&self) -> Self::Output {
+        self.0
+    }
+}
+impl<D0: Dim, D1: Dim> Axis<typenum::U1> for (D0, D1,) {
+    type Output = D1;
+    #[inline(always)]
+    fn axis(&self) -> Self::Output {
+        self.1
+    }
+}
+impl<D0: Dim, D1: Dim, D: Dim> ReplaceAxis<typenum::U0, D> for (D0, D1,) {
+    type Output = (D, D1,);
+    #[inline(always)]
+    fn replace_axis(self, dim: D) -> Self::Output {
+        (dim, self.1,)
+    }
+}
+impl<D0: Dim, D1: Dim, D: Dim> ReplaceAxis<typenum::U1, D> for (D0, D1,) {
+    type Output = (D0, D,);
+    #[inline(always)]
+    fn replace_axis(self, dim: D) -> Self::Output {
+        (self.0, dim,)
+    }
+}
+impl<
+    D0: Dim,
+    D1: Dim,
+    P0: typenum::Unsigned,
+    P1: typenum::Unsigned,
+> PermuteShape<(P0, P1,)> for (D0, D1,)
+    where
+        Self: Axis<P0> + Axis<P1>,
+        P0: typenum::IsNotEqual<P1, Output = typenum::True>,
+{
+    type Output = (<Self as Axis<P0>>::Output, <Self as Axis<P1>>::Output,);
+    #[inline(always)]
+    fn permute(self) -> Self::Output {
+        (Axis::<P0>::axis(&self), Axis::<P1>::axis(&self),)
+    }
+    #[inline(always)]
+    fn axes() -> generic_array::GenericArray<usize, Self::Dims> {
+        let mut axes = generic_array::GenericArray::default();
+        axes[0] = P0::USIZE;
+        axes[1] = P1::USIZE;
+
+        axes
+    }
+}
+impl<D0: Dim, D1: Dim, D2: Dim> Axis<typenum::U0> for (D0, D1, D2,) {
+    type Output = D0;
+    #[inline(always)]
+    fn axis(&self) -> Self::Output {
+        self.0
+    }
+}
+impl<D0: Dim, D1: Dim, D2: Dim> Axis<typenum::U1> for (D0, D1, D2,) {
+    type Output = D1;
+    #[inline(always)]
+    fn axis(&self) -> Self::Output {
+        self.1
+    }
+}
+impl<D0: Dim, D1: Dim, D2: Dim> Axis<typenum::U2> for (D0, D1, D2,) {
+    type Output = D2;
+    #[inline(always)]
+    fn axis(&self) -> Self::Output {
+        self.2
+    }
+}
+impl<D0: Dim, D1: Dim, D2: Dim, D: Dim> ReplaceAxis<typenum::U0, D> for (D0, D1, D2,) {
+    type Output = (D, D1, D2,);
+    #[inline(always)]
+    fn replace_axis(self, dim: D) -> Self::Output {
+        (dim, self.1, self.2,)
+    }
+}
+impl<D0: Dim, D1: Dim, D2: Dim, D: Dim> ReplaceAxis<typenum::U1, D> for (D0, D1, D2,) {
+    type Output = (D0, D, D2,);
+    #[inline(always)]
+    fn replace_axis(self, dim: D) -> Self::Output {
+        (self.0, dim, self.2,)
+    }
+}
+impl<D0: Dim, D1: Dim, D2: Dim, D: Dim> ReplaceAxis<typenum::U2, D> for (D0, D1, D2,) {
+    type Output = (D0, D1, D,);
+    #[inline(always)]
+    fn replace_axis(self, dim: D) -> Self::Output {
+        (self.0, self.1, dim,)
+    }
+}
+impl<
+    D0: Dim,
+    D1: Dim,
+    D2: Dim,
+    P0: typenum::Unsigned,
+    P1: typenum::Unsigned,
+    P2: typenum::Unsigned,
+> PermuteShape<(P0, P1, P2,)> for (D0, D1, D2,)
+    where
+        Self: Axis<P0> + Axis<P1> + Axis<P2>,
+        P0: typenum::IsNotEqual<P1, Output = typenum::True>,
+        P0: typenum::IsNotEqual<P2, Output = typenum::True>,
+        P1: typenum::IsNotEqual<P2, Output = typenum::True>,
+{
+    type Output = (<Self as Axis<P0>>::Output, <Self as Axis<P1>>::Output, <Self as Axis<P2>>::Output,);
+    #[inline(always)]
+    fn permute(self) -> Self::Output {
+        (Axis::<P0>::axis(&self), Axis::<P1>::axis(&self), Axis::<P2>::axis(&self),)
+    }
+    #[inline(always)]
+    fn axes() -> generic_array::GenericArray<usize, Self::Dims> {
+        let mut axes = generic_array::GenericArray::default();
+        axes[0] = P0::USIZE;
+        axes[1] = P1::USIZE;
+        axes[2] = P2::USIZE;
+
+        axes
+    }
+}
+impl<D0: Dim, D1: Dim, D2: Dim, D3: Dim> Axis<typenum::U0> for (D0, D1, D2, D3,) {
+    type Output = D0;
+    #[inline(always)]
+    fn axis(&self) -> Self::Output {
+        self.0
+    }
+}
+impl<D0: Dim, D1: Dim, D2: Dim, D3: Dim> Axis<typenum::U1> for (D0, D1, D2, D3,) {
+    type Output = D1;
+    #[inline(always)]
+    fn axis(&self) -> Self::Output {
+        self.1
+    }
+}
+impl<D0: Dim, D1: Dim, D2: Dim, D3: Dim> Axis<typenum::U2> for (D0, D1, D2, D3,) {
+    type Output = D2;
+    #[inline(always)]
+    fn axis(&self) -> Self::Output {
+        self.2
+    }
+}
+impl<D0: Dim, D1: Dim, D2: Dim, D3: Dim> Axis<typenum::U3> for (D0, D1, D2, D3,) {
+    type Output = D3;
+    #[inline(always)]
+    fn axis(&self) -> Self::Output {
+        self.3
+    }
+}
+impl<D0: Dim, D1: Dim, D2: Dim, D3: Dim, D: Dim> ReplaceAxis<typenum::U0, D> for (D0, D1, D2, D3,) {
+    type Output = (D, D1, D2, D3,);
+    #[inline(always)]
+    fn replace_axis(self, dim: D) -> Self::Output {
+        (dim, self.1, self.2, self.3,)
+    }
+}
+impl<D0: Dim, D1: Dim, D2: Dim, D3: Dim, D: Dim> ReplaceAxis<typenum::U1, D> for (D0, D1, D2, D3,) {
+    type Output = (D0, D, D2, D3,);
+    #[inline(always)]
+    fn replace_axis(self, dim: D) -> Self::Output {
+        (self.0, dim, self.2, self.3,)
+    }
+}
+impl<D0: Dim, D1: Dim, D2: Dim, D3: Dim, D: Dim> ReplaceAxis<typenum::U2, D> for (D0, D1, D2, D3,) {
+    type Output = (D0, D1, D, D3,);
+    #[inline(always)]
+    fn replace_axis(self, dim: D) -> Self::Output {
+        (self.0, self.1, dim, self.3,)
+    }
+}
+impl<D0: Dim, D1: Dim, D2: Dim, D3: Dim, D: Dim> ReplaceAxis<typenum::U3, D> for (D0, D1, D2, D3,) {
+    type Output = (D0, D1, D2, D,);
+    #[inline(always)]
+    fn replace_axis(self, dim: D) -> Self::Output {
+        (self.0, self.1, self.2, dim,)
+    }
+}
+impl<
+    D0: Dim,
+    D1: Dim,
+    D2: Dim,
+    D3: Dim,
+    P0: typenum::Unsigned,
+    P1: typenum::Unsigned,
+    P2: typenum::Unsigned,
+    P3: typenum::Unsigned,
+> PermuteShape<(P0, P1, P2, P3,)> for (D0, D1, D2, D3,)
+    where
+        Self: Axis<P0> + Axis<P1> + Axis<P2> + Axis<P3>,
+        P0: typenum::IsNotEqual<P1, Output = typenum::True>,
+        P0: typenum::IsNotEqual<P2, Output = typenum::True>,
+        P0: typenum::IsNotEqual<P3, Output = typenum::True>,
+        P1: typenum::IsNotEqual<P2, Output = typenum::True>,
+        P1: typenum::IsNotEqual<P3, Output = typenum::True>,
+        P2: typenum::IsNotEqual<P3, Output = typenum::True>,
+{
+    type Output = (<Self as Axis<P0>>::Output, <Self as Axis<P1>>::Output, <Self as Axis<P2>>::Output, <Self as Axis<P3>>::Output,);
+    #[inline(always)]
+    fn permute(self) -> Self::Output {
+        (Axis::<P0>::axis(&self), Axis::<P1>::axis(&self), Axis::<P2>::axis(&self), Axis::<P3>::axis(&self),)
+    }
+    #[inline(always)]
+    fn axes() -> generic_array::GenericArray<usize, Self::Dims> {
+        let mut axes = generic_array::GenericArray::default();
+        axes[0] = P0::USIZE;
+        axes[1] = P1::USIZE;
+        axes[2] = P2::USIZE;
+        axes[3] = P3::USIZE;
+
+        axes
+    }
+}
+impl<D0: Dim, D1: Dim, D2: Dim, D3: Dim, D4: Dim> Axis<typenum::U0> for (D0, D1, D2, D3, D4,) {
+    type Output = D0;
+    #[inline(always)]
+    fn axis(&self) -> Self::Output {
+        self.0
+    }
+}
+impl<D0: Dim, D1: Dim, D2: Dim, D3: Dim, D4: Dim> Axis<typenum::U1> for (D0, D1, D2, D3, D4,) {
+    type Output = D1;
+    #[inline(always)]
+    fn axis(&self) -> Self::Output {
+        self.1
+    }
+}
+impl<D0: Dim, D1: Dim, D2: Dim, D3: Dim, D4: Dim> Axis<typenum::U2> for (D0, D1, D2, D3, D4,) {
+    type Output = D2;
+    #[inline(always)]
+    fn axis(&self) -> Self::Output {
+        self.2
+    }
+}
+impl<D0: Dim, D1: Dim, D2: Dim, D3: Dim, D4: Dim> Axis<typenum::U3> for (D0, D1, D2, D3, D4,) {
+    type Output = D3;
+    #[inline(always)]
+    fn axis(&self) -> Self::Output {
+        self.3
+    }
+}
+impl<D0: Dim, D1: Dim, D2: Dim, D3: Dim, D4: Dim> Axis<typenum::U4> for (D0, D1, D2, D3, D4,) {
+    type Output = D4;
+    #[inline(always)]
+    fn axis(&self) -> Self::Output {
+        self.4
+    }
+}
+impl<D0: Dim, D1: Dim, D2: Dim, D3: Dim, D4: Dim, D: Dim> ReplaceAxis<typenum::U0, D> for (D0, D1, D2, D3, D4,) {
+    type Output = (D, D1, D2, D3, D4,);
+    #[inline(always)]
+    fn replace_axis(self, dim: D) -> Self::Output {
+        (dim, self.1, self.2, self.3, self.4,)
+    }
+}
+impl<D0: Dim, D1: Dim, D2: Dim, D3: Dim, D4: Dim, D: Dim> ReplaceAxis<typenum::U1, D> for (D0, D1, D2, D3, D4,) {
+    type Output = (D0, D, D2, D3, D4,);
+    #[inline(always)]
+    fn replace_axis(self, dim: D) -> Self::Output {
+        (self.0, dim, self.2, self.3, self.4,)
+    }
+}
+impl<D0: Dim, D1: Dim, D2: Dim, D3: Dim, D4: Dim, D: Dim> ReplaceAxis<typenum::U2, D> for (D0, D1, D2, D3, D4,) {
+    type Output = (D0, D1, D, D3, D4,);
+    #[inline(always)]
+    fn replace_axis(self, dim: D) -> Self::Output {
+        (self.0, self.1, dim, self.3, self.4,)
+    }
+}
+impl<D0: Dim, D1: Dim, D2: Dim, D3: Dim, D4: Dim, D: Dim> ReplaceAxis<typenum::U3, D> for (D0, D1, D2, D3, D4,) {
+    type Output = (D0, D1, D2, D, D4,);
+    #[inline(always)]
+    fn replace_axis(self, dim: D) -> Self::Output {
+        (self.0, self.1, self.2, dim, self.4,)
+    }
+}
+impl<D0: Dim, D1: Dim, D2: Dim, D3: Dim, D4: Dim, D: Dim> ReplaceAxis<typenum::U4, D> for (D0, D1, D2, D3, D4,) {
+    type Output = (D0, D1, D2, D3, D,);
+    #[inline(always)]
+    fn replace_axis(self, dim: D) -> Self::Output {
+        (self.0, self.1, self.2, self.3, dim,)
+    }
+}
+impl<
+    D0: Dim,
+    D1: Dim,
+    D2: Dim,
+    D3: Dim,
+    D4: Dim,
+    P0: typenum::Unsigned,
+    P1: typenum::Unsigned,
+    P2: typenum::Unsigned,
+    P3: typenum::Unsigned,
+    P4: typenum::Unsigned,
+> PermuteShape<(P0, P1, P2, P3, P4,)> for (D0, D1, D2, D3, D4,)
+    where
+        Self: Axis<P0> + Axis<P1> + Axis<P2> + Axis<P3> + Axis<P4>,
+        P0: typenum::IsNotEqual<P1, Output = typenum::True>,
+        P0: typenum::IsNotEqual<P2, Output = typenum::True>,
+        P0: typenum::IsNotEqual<P3, Output = typenum::True>,
+        P0: typenum::IsNotEqual<P4, Output = typenum::True>,
+        P1: typenum::IsNotEqual<P2, Output = typenum::True>,
+        P1: typenum::IsNotEqual<P3, Output = typenum::True>,
+        P1: typenum::IsNotEqual<P4, Output = typenum::True>,
+        P2: typenum::IsNotEqual<P3, Output = typenum::True>,
+        P2: typenum::IsNotEqual<P4, Output = typenum::True>,
+        P3: typenum::IsNotEqual<P4, Output = typenum::True>,
+{
+    type Output = (<Self as Axis<P0>>::Output, <Self as Axis<P1>>::Output, <Self as Axis<P2>>::Output, <Self as Axis<P3>>::Output, <Self as Axis<P4>>::Output,);
+    #[inline(always)]
+    fn permute(self) -> Self::Output {
+        (Axis::<P0>::axis(&self), Axis::<P1>::axis(&self), Axis::<P2>::axis(&self), Axis::<P3>::axis(&self), Axis::<P4>::axis(&self),)
+    }
+    #[inline(always)]
+    fn axes() -> generic_array::GenericArray<usize, Self::Dims> {
+        let mut axes = generic_array::GenericArray::default();
+        axes[0] = P0::USIZE;
+        axes[1] = P1::USIZE;
+        axes[2] = P2::USIZE;
+        axes[3] = P3::USIZE;
+        axes[4] = P4::USIZE;
+
+        axes
+    }
+}
+impl<D0: Dim, D1: Dim, D2: Dim, D3: Dim, D4: Dim, D5: Dim> Axis<typenum::U0> for (D0, D1, D2, D3, D4, D5,) {
+    type Output = D0;
+    #[inline(always)]
+    fn axis(&self) -> Self::Output {
+        self.0
+    }
+}
+impl<D0: Dim, D1: Dim, D2: Dim, D3: Dim, D4: Dim, D5: Dim> Axis<typenum::U1> for (D0, D1, D2, D3, D4, D5,) {
+    type Output = D1;
+    #[inline(always)]
+    fn axis(&self) -> Self::Output {
+        self.1
+    }
+}
+impl<D0: Dim, D1: Dim, D2: Dim, D3: Dim, D4: Dim, D5: Dim> Axis<typenum::U2> for (D0, D1, D2, D3, D4, D5,) {
+    type Output = D2;
+    #[inline(always)]
+    fn axis(&self) -> Self::Output {
+        self.2
+    }
+}
+impl<D0: Dim, D1: Dim, D2: Dim, D3: Dim, D4: Dim, D5: Dim> Axis<typenum::U3> for (D0, D1, D2, D3, D4, D5,) {
+    type Output = D3;
+    #[inline(always)]
+    fn axis(&self) -> Self::Output {
+        self.3
+    }
+}
+impl<D0: Dim, D1: Dim, D2: Dim, D3: Dim, D4: Dim, D5: Dim> Axis<typenum::U4> for (D0, D1, D2, D3, D4, D5,) {
+    type Output = D4;
+    #[inline(always)]
+    fn axis(&self) -> Self::Output {
+        self.4
+    }
+}
+impl<D0: Dim, D1: Dim, D2: Dim, D3: Dim, D4: Dim, D5: Dim> Axis<typenum::U5> for (D0, D1, D2, D3, D4, D5,) {
+    type Output = D5;
+    #[inline(always)]
+    fn axis(&self) -> Self::Output {
+        self.5
+    }
+}
+impl<D0: Dim, D1: Dim, D2: Dim, D3: Dim, D4: Dim, D5: Dim, D: Dim> ReplaceAxis<typenum::U0, D> for (D0, D1, D2, D3, D4, D5,) {
+    type Output = (D, D1, D2, D3, D4, D5,);
+    #[inline(always)]
+    fn replace_axis(self, dim: D) -> Self::Output {
+        (dim, self.1, self.2, self.3, self.4, self.5,)
+    }
+}
+impl<D0: Dim, D1: Dim, D2: Dim, D3: Dim, D4: Dim, D5: Dim, D: Dim> ReplaceAxis<typenum::U1, D> for (D0, D1, D2, D3, D4, D5,) {
+    type Output = (D0, D, D2, D3, D4, D5,);
+    #[inline(always)]
+    fn replace_axis(self, dim: D) -> Self::Output {
+        (self.0, dim, self.2, self.3, self.4, self.5,)
+    }
+}
+impl<D0: Dim, D1: Dim, D2: Dim, D3: Dim, D4: Dim, D5: Dim, D: Dim> ReplaceAxis<typenum::U2, D> for (D0, D1, D2, D3, D4, D5,) {
+    type Output = (D0, D1, D, D3, D4, D5,);
+    #[inline(always)]
+    fn replace_axis(self, dim: D) -> Self::Output {
+        (self.0, self.1, dim, self.3, self.4, self.5,)
+    }
+}
+impl<D0: Dim, D1: Dim, D2: Dim, D3: Dim, D4: Dim, D5: Dim, D: Dim> ReplaceAxis<typenum::U3, D> for (D0, D1, D2, D3, D4, D5,) {
+    type Output = (D0, D1, D2, D, D4, D5,);
+    #[inline(always)]
+    fn replace_axis(self, dim: D) -> Self::Output {
+        (self.0, self.1, self.2, dim, self.4, self.5,)
+    }
+}
+impl<D0: Dim, D1: Dim, D2: Dim, D3: Dim, D4: Dim, D5: Dim, D: Dim> ReplaceAxis<typenum::U4, D> for (D0, D1, D2, D3, D4, D5,) {
+    type Output = (D0, D1, D2, D3, D, D5,);
+    #[inline(always)]
+    fn replace_axis(self, dim: D) -> Self::Output {
+        (self.0, self.1, self.2, self.3, dim, self.5,)
+    }
+}
+impl<D0: Dim, D1: Dim, D2: Dim, D3: Dim, D4: Dim, D5: Dim, D: Dim> ReplaceAxis<typenum::U5, D> for (D0, D1, D2, D3, D4, D5,) {
+    type Output = (D0, D1, D2, D3, D4, D,);
+    #[inline(always)]
+    fn replace_axis(self, dim: D) -> Self::Output {
+        (self.0, self.1, self.2, self.3, self.4, dim,)
+    }
+}
+impl<
+    D0: Dim,
+    D1: Dim,
+    D2: Dim,
+    D3: Dim,
+    D4: Dim,
+    D5: Dim,
+    P0: typenum::Unsigned,
+    P1: typenum::Unsigned,
+    P2: typenum::Unsigned,
+    P3: typenum::Unsigned,
+    P4: typenum::Unsigned,
+    P5: typenum::Unsigned,
+> PermuteShape<(P0, P1, P2, P3, P4, P5,)> for (D0, D1, D2, D3, D4, D5,)
+    where
+        Self: Axis<P0> + Axis<P1> + Axis<P2> + Axis<P3> + Axis<P4> + Axis<P5>,
+        P0: typenum::IsNotEqual<P1, Output = typenum::True>,
+        P0: typenum::IsNotEqual<P2, Output = typenum::True>,
+        P0: typenum::IsNotEqual<P3, Output = typenum::True>,
+        P0: typenum::IsNotEqual<P4, Output = typenum::True>,
+        P0: typenum::IsNotEqual<P5, Output = typenum::True>,
+        P1: typenum::IsNotEqual<P2, Output = typenum::True>,
+        P1: typenum::IsNotEqual<P3, Output = typenum::True>,
+        P1: typenum::IsNotEqual<P4, Output = typenum::True>,
+        P1: typenum::IsNotEqual<P5, Output = typenum::True>,
+        P2: typenum::IsNotEqual<P3, Output = typenum::True>,
+        P2: typenum::IsNotEqual<P4, Output = typenum::True>,
+        P2: typenum::IsNotEqual<P5, Output = typenum::True>,
+        P3: typenum::IsNotEqual<P4, Output = typenum::True>,
+        P3: typenum::IsNotEqual<P5, Output = typenum::True>,
+        P4: typenum::IsNotEqual<P5, Output = typenum::True>,
+{
+    type Output = (<Self as Axis<P0>>::Output, <Self as Axis<P1>>::Output, <Self as Axis<P2>>::Output, <Self as Axis<P3>>::Output, <Self as Axis<P4>>::Output, <Self as Axis<P5>>::Output,);
+    #[inline(always)]
+    fn permute(self) -> Self::Output {
+        (Axis::<P0>::axis(&self), Axis::<P1>::axis(&self), Axis::<P2>::axis(&self), Axis::<P3>::axis(&self), Axis::<P4>::axis(&self), Axis::<P5>::axis(&self),)
+    }
+    #[inline(always)]
+    fn axes() -> generic_array::GenericArray<usize, Self::Dims> {
+        let mut axes = generic_array::GenericArray::default();
+        axes[0] = P0::USIZE;
+        axes[1] = P1::USIZE;
+        axes[2] = P2::USIZE;
+        axes[3] = P3::USIZE;
+        axes[4] = P4::USIZE;
+        axes[5] = P5::USIZE;
+
+        axes
+    }
+}
+
+// Axis removal
+
+/// Shapes whose axis at the `typenum` index `I` can be removed.
+pub trait RemoveAxis<I>: Shape {
+    type Output: Shape;
+    fn remove_axis(self) -> Self::Output;
+}
+impl<D0: Dim> RemoveAxis<typenum::U0> for (D0,) {
+    type Output = ();
+    #[inline(always)]
+    fn remove_axis(self) -> Self::Output {}
+}
+impl<D0: Dim, D1: Dim> RemoveAxis<typenum::U0> for (D0, D1,) {
+    type Output = (D1,);
+    #[inline(always)]
+    fn remove_axis(self) -> Self::Output {
+        (self.1,)
+    }
+}
+impl<D0: Dim, D1: Dim> RemoveAxis<typenum::U1> for (D0, D1,) {
+    type Output = (D0,);
+    #[inline(always)]
+    fn remove_axis(self) -> Self::Output {
+        (self.0,)
+    }
+}
+impl<D0: Dim, D1: Dim, D2: Dim> RemoveAxis<typenum::U0> for (D0, D1, D2,) {
+    type Output = (D1, D2,);
+    #[inline(always)]
+    fn remove_axis(self) -> Self::Output {
+        (self.1, self.2,)
+    }
+}
+impl<D0: Dim, D1: Dim, D2: Dim> RemoveAxis<typenum::U1> for (D0, D1, D2,) {
+    type Output = (D0, D2,);
+    #[inline(always)]
+    fn remove_axis(self) -> Self::Output {
+        (self.0, self.2,)
+    }
+}
+impl<D0: Dim, D1: Dim, D2: Dim> RemoveAxis<typenum::U2> for (D0, D1, D2,) {
+    type Output = (D0, D1,);
+    #[inline(always)]
+    fn remove_axis(self) -> Self::Output {
+        (self.0, self.1,)
+    }
+}
+impl<D0: Dim, D1: Dim, D2: Dim, D3: Dim> RemoveAxis<typenum::U0> for (D0, D1, D2, D3,) {
+    type Output = (D1, D2, D3,);
+    #[inline(always)]
+    fn remove_axis(self) -> Self::Output {
+        (self.1, self.2, self.3,)
+    }
+}
+impl<D0: Dim, D1: Dim, D2: Dim, D3: Dim> RemoveAxis<typenum::U1> for (D0, D1, D2, D3,) {
+    type Output = (D0, D2, D3,);
+    #[inline(always)]
+    fn remove_axis(self) -> Self::Output {
+        (self.0, self.2, self.3,)
+    }
+}
+impl<D0: Dim, D1: Dim, D2: Dim, D3: Dim> RemoveAxis<typenum::U2> for (D0, D1, D2, D3,) {
+    type Output = (D0, D1, D3,);
+    #[inline(always)]
+    fn remove_axis(self) -> Self::Output {
+        (self.0, self.1, self.3,)
+    }
+}
+impl<D0: Dim, D1: Dim, D2: Dim, D3: Dim> RemoveAxis<typenum::U3> for (D0, D1, D2, D3,) {
+    type Output = (D0, D1, D2,);
+    #[inline(always)]
+    fn remove_axis(self) -> Self::Output {
+        (self.0, self.1, self.2,)
+    }
+}
+impl<D0: Dim, D1: Dim, D2: Dim, D3: Dim, D4: Dim> RemoveAxis<typenum::U0> for (D0, D1, D2, D3, D4,) {
+    type Output = (D1, D2, D3, D4,);
+    #[inline(always)]
+    fn remove_axis(self) -> Self::Output {
+        (self.1, self.2, self.3, self.4,)
+    }
+}
+impl<D0: Dim, D1: Dim, D2: Dim, D3: Dim, D4: Dim> RemoveAxis<typenum::U1> for (D0, D1, D2, D3, D4,) {
+    type Output = (D0, D2, D3, D4,);
+    #[inline(always)]
+    fn remove_axis(self) -> Self::Output {
+        (self.0, self.2, self.3, self.4,)
+    }
+}
+impl<D0: Dim, D1: Dim, D2: Dim, D3: Dim, D4: Dim> RemoveAxis<typenum::U2> for (D0, D1, D2, D3, D4,) {
+    type Output = (D0, D1, D3, D4,);
+    #[inline(always)]
+    fn remove_axis(self) -> Self::Output {
+        (self.0, self.1, self.3, self.4,)
+    }
+}
+impl<D0: Dim, D1: Dim, D2: Dim, D3: Dim, D4: Dim> RemoveAxis<typenum::U3> for (D0, D1, D2, D3, D4,) {
+    type Output = (D0, D1, D2, D4,);
+    #[inline(always)]
+    fn remove_axis(self) -> Self::Output {
+        (self.0, self.1, self.2, self.4,)
+    }
+}
+impl<D0: Dim, D1: Dim, D2: Dim, D3: Dim, D4: Dim> RemoveAxis<typenum::U4> for (D0, D1, D2, D3, D4,) {
+    type Output = (D0, D1, D2, D3,);
+    #[inline(always)]
+    fn remove_axis(self) -> Self::Output {
+        (self.0, self.1, self.2, self.3,)
+    }
+}
+impl<D0: Dim, D1: Dim, D2: Dim, D3: Dim, D4: Dim, D5: Dim> RemoveAxis<typenum::U0> for (D0, D1, D2, D3, D4, D5,) {
+    type Output = (D1, D2, D3, D4, D5,);
+    #[inline(always)]
+    fn remove_axis(self) -> Self::Output {
+        (self.1, self.2, self.3, self.4, self.5,)
+    }
+}
+impl<D0: Dim, D1: Dim, D2: Dim, D3: Dim, D4: Dim, D5: Dim> RemoveAxis<typenum::U1> for (D0, D1, D2, D3, D4, D5,) {
+    type Output = (D0, D2, D3, D4, D5,);
+    #[inline(always)]
+    fn remove_axis(self) -> Self::Output {
+        (self.0, self.2, self.3, self.4, self.5,)
+    }
+}
+impl<D0: Dim, D1: Dim, D2: Dim, D3: Dim, D4: Dim, D5: Dim> RemoveAxis<typenum::U2> for (D0, D1, D2, D3, D4, D5,) {
+    type Output = (D0, D1, D3, D4, D5,);
+    #[inline(always)]
+    fn remove_axis(self) -> Self::Output {
+        (self.0, self.1, self.3, self.4, self.5,)
+    }
+}
+impl<D0: Dim, D1: Dim, D2: Dim, D3: Dim, D4: Dim, D5: Dim> RemoveAxis<typenum::U3> for (D0, D1, D2, D3, D4, D5,) {
+    type Output = (D0, D1, D2, D4, D5,);
+    #[inline(always)]
+    fn remove_axis(self) -> Self::Output {
+        (self.0, self.1, self.2, self.4, self.5,)
+    }
+}
+impl<D0: Dim, D1: Dim, D2: Dim, D3: Dim, D4: Dim, D5: Dim> RemoveAxis<typenum::U4> for (D0, D1, D2, D3, D4, D5,) {
+    type Output = (D0, D1, D2, D3, D5,);
+    #[inline(always)]
+    fn remove_axis(self) -> Self::Output {
+        (self.0, self.1, self.2, self.3, self.5,)
+    }
+}
+impl<D0: Dim, D1: Dim, D2: Dim, D3: Dim, D4: Dim, D5: Dim> RemoveAxis<typenum::U5> for (D0, D1, D2, D3, D4, D5,) {
+    type Output = (D0, D1, D2, D3, D4,);
+    #[inline(always)]
+    fn remove_axis(self) -> Self::Output {
+        (self.0, self.1, self.2, self.3, self.4,)
+    }
+}
+
+// Slicing
+
+/// A range of the constant length `End - Start` along an axis, given as `typenum` constants.
+///
+/// ```
+/// use deeper::{AxisRange, Cst, CstRange, Dim};
+/// use typenum::{U1, U3, U4};
+///
+/// let (start, axis) = CstRange::<U1, U3>::new().select(Cst::<U4>::new()).unwrap();
+/// assert_eq!((start, axis.size()), (1, 2));
+/// ```
+///
+/// ```compile_fail
+/// use deeper::{AxisRange, Cst, CstRange};
+/// use typenum::{U1, U5, U4};
+///
+/// CstRange::<U1, U5>::new().select(Cst::<U4>::new());
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct CstRange<Start: typenum::Unsigned, End: typenum::Unsigned> {
+    _phantom: std::marker::PhantomData<(Start, End)>,
+}
+impl<Start: typenum::Unsigned, End: typenum::Unsigned> CstRange<Start, End> {
+    pub fn new() -> Self {
+        Self {
+            _phantom: std::marker::PhantomData,
+        }
+    }
+}
+impl<Start: typenum::Unsigned, End: typenum::Unsigned> Default for CstRange<Start, End> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+/// Ranges which select a part of the axis `D`.
+///
+/// `..` keeps the axis as it is, [`CstRange`] gives a [`Cst`] axis and the `usize` ranges give a
+/// [`Dyn`] axis. Ranges which do not fit into a [`Cst`] axis are rejected at compile time if both
+/// are constant, otherwise at runtime.
+pub trait AxisRange<D: Dim> {
+    type Output: Dim;
+    /// The first selected index and the selected axis.
+    fn select(self, dim: D) -> Result<(usize, Self::Output), ShapeError>;
+}
+/// Shapes which can be sliced by `R`, a tuple with an [`AxisRange`] for every axis.
+pub trait SliceShape<R>: Shape {
+    type Output: Shape;
+    /// The first selected index along every axis and the sliced shape.
+    #[allow(clippy::type_complexity)]
+    fn try_slice(
+        self,
+        ranges: R,
+    ) -> Result<(generic_array::GenericArray<usize, Self::Dims>, Self::Output), ShapeError>;
+}
+
+fn select_dyn(start: usize, end: usize, size: usize) -> Result<(usize, Dyn), ShapeError> {
+    if start <= end && end <= size {
+        Ok((start, Dyn::new(end - start)))
+    } else {
+        Err(ShapeError::Range { axis: 0, start, end, size })
+    }
+}
+
+/// Like [`select_dyn`] with `end` included, which can not be `usize::MAX` on any axis.
+fn select_dyn_inclusive(start: usize, end: usize, size: usize) -> Result<(usize, Dyn), ShapeError> {
+    match end.checked_add(1) {
+        Some(end) => select_dyn(start, end, size),
+        None => Err(ShapeError::Range { axis: 0, start, end, size }),
+    }
+}
+
+impl<D: Dim> AxisRange<D> for std::ops::RangeFull {
+    type Output = D;
+    #[inline(always)]
+    fn select(self, dim: D) -> Result<(usize, D), ShapeError> {
+        Ok((0, dim))
+    }
+}
+impl<D: Dim> AxisRange<D> for std::ops::Range<usize> {
+    type Output = Dyn;
+    fn select(self, dim: D) -> Result<(usize, Dyn), ShapeError> {
+        select_dyn(self.start, self.end, dim.size())
+    }
+}
+impl<D: Dim> AxisRange<D> for std::ops::RangeInclusive<usize> {
+    type Output = Dyn;
+    fn select(self, dim: D) -> Result<(usize, Dyn), ShapeError> {
+        select_dyn_inclusive(*self.start(), *self.end(), dim.size())
+    }
+}
+impl<D: Dim> AxisRange<D> for std::ops::RangeFrom<usize> {
+    type Output = Dyn;
+    fn select(self, dim: D) -> Result<(usize, Dyn), ShapeError> {
+        select_dyn(self.start, dim.size(), dim.size())
+    }
+}
+impl<D: Dim> AxisRange<D> for std::ops::RangeTo<usize> {
+    type Output = Dyn;
+    fn select(self, dim: D) -> Result<(usize, Dyn), ShapeError> {
+        select_dyn(0, self.end, dim.size())
+    }
+}
+impl<D: Dim> AxisRange<D> for std::ops::RangeToInclusive<usize> {
+    type Output = Dyn;
+    fn select(self, dim: D) -> Result<(usize, Dyn), ShapeError> {
+        select_dyn_inclusive(0, self.end, dim.size())
+    }
+}
+impl<
+    Start: typenum::Unsigned + typenum::IsLessOrEqual<End, Output = typenum::True>,
+    End: typenum::Unsigned
+        + typenum::IsLessOrEqual<Size, Output = typenum::True>
+        + core::ops::Sub<Start, Output = Len>,
+    Size: typenum::Unsigned,
+    Len: typenum::Unsigned,
+> AxisRange<Cst<Size>> for CstRange<Start, End> {
+    type Output = Cst<Len>;
+    #[inline(always)]
+    fn select(self, _: Cst<Size>) -> Result<(usize, Cst<Len>), ShapeError> {
+        Ok((Start::USIZE, Cst::new()))
+    }
+}
+impl<
+    Start: typenum::Unsigned + typenum::IsLessOrEqual<End, Output = typenum::True>,
+    End: typenum::Unsigned + core::ops::Sub<Start, Output = Len>,
+    Len: typenum::Unsigned,
+> AxisRange<Dyn> for CstRange<Start, End> {
+    type Output = Cst<Len>;
+    fn select(self, dim: Dyn) -> Result<(usize, Cst<Len>), ShapeError> {
+        select_dyn(Start::USIZE, End::USIZE, dim.size())?;
+
+        Ok((Start::USIZE, Cst::new()))
+    }
+}
+
+impl<
+    D0: Dim,
+    R0: AxisRange<D0>,
+> SliceShape<(R0,)> for (D0,) {
+    type Output = (R0::Output,);
+    fn try_slice(
+        self,
+        ranges: (R0,),
+    ) -> Result<(generic_array::GenericArray<usize, Self::Dims>, Self::Output), ShapeError> {
+        let (start0, d0) = ranges.0.select(self.0).map_err(|e| e.at_axis(0))?;
+        let mut starts = generic_array::GenericArray::default();
+        starts[0] = start0;
+
+        Ok((starts, (d0,)))
+    }
+}
+impl<
+    D0: Dim,
+    D1: Dim,
+    R0: AxisRange<D0>,
+    R1: AxisRange<D1>,
+> SliceShape<(R0, R1,)> for (D0, D1,) {
+    type Output = (R0::Output, R1::Output,);
+    fn try_slice(
+        self,
+        ranges: (R0, R1,),
+    ) -> Result<(generic_array::GenericArray<usize, Self::Dims>, Self::Output), ShapeError> {
+        let (start0, d0) = ranges.0.select(self.0).map_err(|e| e.at_axis(0))?;
+        let (start1, d1) = ranges.1.select(self.1).map_err(|e| e.at_axis(1))?;
+        let mut starts = generic_array::GenericArray::default();
+        starts[0] = start0;
+        starts[1] = start1;
+
+        Ok((starts, (d0, d1,)))
+    }
+}
+impl<
+    D0: Dim,
+    D1: Dim,
+    D2: Dim,
+    R0: AxisRange<D0>,
+    R1: AxisRange<D1>,
+    R2: AxisRange<D2>,
+> SliceShape<(R0, R1, R2,)> for (D0, D1, D2,) {
+    type Output = (R0::Output, R1::Output, R2::Output,);
+    fn try_slice(
+        self,
+        ranges: (R0, R1, R2,),
+    ) -> Result<(generic_array::GenericArray<usize, Self::Dims>, Self::Output), ShapeError> {
+        let (start0, d0) = ranges.0.select(self.0).map_err(|e| e.at_axis(0))?;
+        let (start1, d1) = ranges.1.select(self.1).map_err(|e| e.at_axis(1))?;
+        let (start2, d2) = ranges.2.select(self.2).map_err(|e| e.at_axis(2))?;
+        let mut starts = generic_array::GenericArray::default();
+        starts[0] = start0;
+        starts[1] = start1;
+        starts[2] = start2;
+
+        Ok((starts, (d0, d1, d2,)))
+    }
+}
+impl<
+    D0: Dim,
+    D1: Dim,
+    D2: Dim,
+    D3: Dim,
+    R0: AxisRange<D0>,
+    R1: AxisRange<D1>,
+    R2: AxisRange<D2>,
+    R3: AxisRange<D3>,
+> SliceShape<(R0, R1, R2, R3,)> for (D0, D1, D2, D3,) {
+    type Output = (R0::Output, R1::Output, R2::Output, R3::Output,);
+    fn try_slice(
+        self,
+        ranges: (R0, R1, R2, R3,),
+    ) -> Result<(generic_array::GenericArray<usize, Self::Dims>, Self::Output), ShapeError> {
+        let (start0, d0) = ranges.0.select(self.0).map_err(|e| e.at_axis(0))?;
+        let (start1, d1) = ranges.1.select(self.1).map_err(|e| e.at_axis(1))?;
+        let (start2, d2) = ranges.2.select(self.2).map_err(|e| e.at_axis(2))?;
+        let (start3, d3) = ranges.3.select(self.3).map_err(|e| e.at_axis(3))?;
+        let mut starts = generic_array::GenericArray::default();
+        starts[0] = start0;
+        starts[1] = start1;
+        starts[2] = start2;
+        starts[3] = start3;
+
+        Ok((starts, (d0, d1, d2, d3,)))
+    }
+}
+impl<
+    D0: Dim,
+    D1: Dim,
+    D2: Dim,
+    D3: Dim,
+    D4: Dim,
+    R0: AxisRange<D0>,
+    R1: AxisRange<D1>,
+    R2: AxisRange<D2>,
+    R3: AxisRange<D3>,
+    R4: AxisRange<D4>,
+> SliceShape<(R0, R1, R2, R3, R4,)> for (D0, D1, D2, D3, D4,) {
+    type Output = (R0::Output, R1::Output, R2::Output, R3::Output, R4::Output,);
+    fn try_slice(
+        self,
+        ranges: (R0, R1, R2, R3, R4,),
+    ) -> Result<(generic_array::GenericArray<usize, Self::Dims>, Self::Output), ShapeError> {
+        let (start0, d0) = ranges.0.select(self.0).map_err(|e| e.at_axis(0))?;
+        let (start1, d1) = ranges.1.select(self.1).map_err(|e| e.at_axis(1))?;
+        let (start2, d2) = ranges.2.select(self.2).map_err(|e| e.at_axis(2))?;
+        let (start3, d3) = ranges.3.select(self.3).map_err(|e| e.at_axis(3))?;
+        let (start4, d4) = ranges.4.select(self.4).map_err(|e| e.at_axis(4))?;
+        let mut starts = generic_array::GenericArray::default();
+        starts[0] = start0;
+        starts[1] = start1;
+        starts[2] = start2;
+        starts[3] = start3;
+        starts[4] = start4;
+
+        Ok((starts, (d0, d1, d2, d3, d4,)))
+    }
+}
+impl<
+    D0: Dim,
+    D1: Dim,
+    D2: Dim,
+    D3: Dim,
+    D4: Dim,
+    D5: Dim,
+    R0: AxisRange<D0>,
+    R1: AxisRange<D1>,
+    R2: AxisRange<D2>,
+    R3: AxisRange<D3>,
+    R4: AxisRange<D4>,
+    R5: AxisRange<D5>,
+> SliceShape<(R0, R1, R2, R3, R4, R5,)> for (D0, D1, D2, D3, D4, D5,) {
+    type Output = (R0::Output, R1::Output, R2::Output, R3::Output, R4::Output, R5::Output,);
+    fn try_slice(
+        self,
+        ranges: (R0, R1, R2, R3, R4, R5,),
+    ) -> Result<(generic_array::GenericArray<usize, Self::Dims>, Self::Output), ShapeError> {
+        let (start0, d0) = ranges.0.select(self.0).map_err(|e| e.at_axis(0))?;
+        let (start1, d1) = ranges.1.select(self.1).map_err(|e| e.at_axis(1))?;
+        let (start2, d2) = ranges.2.select(self.2).map_err(|e| e.at_axis(2))?;
+        let (start3, d3) = ranges.3.select(self.3).map_err(|e| e.at_axis(3))?;
+        let (start4, d4) = ranges.4.select(self.4).map_err(|e| e.at_axis(4))?;
+        let (start5, d5) = ranges.5.select(self.5).map_err(|e| e.at_axis(5))?;
+        let mut starts = generic_array::GenericArray::default();
+        starts[0] = start0;
+        starts[1] = start1;
+        starts[2] = start2;
+        starts[3] = start3;
+        starts[4] = start4;
+        starts[5] = start5;
+
+        Ok((starts, (d0, d1, d2, d3, d4, d5,)))
+    }
+}
+
+// Matrix products
+
+/// Shapes of rank 2 or higher, seen as a batch of matrices over the last two axes.
+pub trait MatrixShape: Shape {
+    type Batch: Shape;
+    type Rows: Dim;
+    type Cols: Dim;
+    fn split(self) -> (Self::Batch, Self::Rows, Self::Cols);
+}
+/// Batch shapes a matrix shape can be built from by appending rows and columns.
+pub trait AppendMatrix<Rows: Dim, Cols: Dim>: Shape {
+    type Output: MatrixShape;
+    fn append(self, rows: Rows, cols: Cols) -> Self::Output;
+}
+/// Axes which have to be equal, like the inner axes of a matrix product.
+///
+/// For two [`Cst`] axes this is checked at compile time, otherwise at runtime.
+pub trait SameDim<Rhs: Dim>: Dim {
+    fn check_same(self, rhs: Rhs) -> Result<(), ShapeError>;
+}
+/// Shapes that can be multiplied with `Rhs` as (batches of) matrices.
+///
+/// `(.., M, K) x (.., K, N)` gives `(.., M, N)`, the batch axes are broadcast.
+///
+/// ```
+/// use deeper::{Cst, Dyn, MatMulShape, Shape};
+/// use typenum::{U2, U3, U4};
+///
+/// let shape = (Cst::<U2>::new(), Cst::<U3>::new()).matmul((Dyn::new(5), Cst::<U3>::new(), Cst::<U4>::new()));
+/// assert_eq!(shape.dimensions().as_slice(), &[5, 2, 4]);
+/// ```
+///
+/// ```compile_fail
+/// use deeper::{Cst, MatMulShape};
+/// use typenum::{U2, U3, U4};
+///
+/// (Cst::<U2>::new(), Cst::<U3>::new()).matmul((Cst::<U4>::new(), Cst::<U2>::new()));
+/// ```
+pub trait MatMulShape<Rhs>: Sized {
+    type Output: MatrixShape;
+    fn try_matmul(self, rhs: Rhs) -> Result<Self::Output, ShapeError>;
+    /// Like `try_matmul`, but panics if the shapes are not compatible.
+    fn matmul(self, rhs: Rhs) -> Self::Output {
+        match self.try_matmul(rhs) {
+            Ok(shape) => shape,
+            Err(err) => panic!("{}", err),
+        }
+    }
+}
+impl<L: MatrixShape, R: MatrixShape> MatMulShape<R> for L
+    where
+        L::Cols: SameDim<R::Rows>,
+        L::Batch: BroadcastShape<R::Batch>,
+        <L::Batch as BroadcastShape<R::Batch>>::Output: AppendMatrix<L::Rows, R::Cols>,
+{
+    type Output = <<L::Batch as BroadcastShape<R::Batch>>::Output as AppendMatrix<
+        L::Rows,
+        R::Cols,
+    >>::Output;
+    fn try_matmul(self, rhs: R) -> Result<Self::Output, ShapeError> {
+        let (l_batch, rows, l_cols) = self.split();
+        let (r_batch, r_rows, cols) = rhs.split();
+        l_cols.check_same(r_rows)?;
+
+        Ok(l_batch.try_broadcast(r_batch)?.append(rows, cols))
+    }
+}
+
+impl<
+    SizeL: typenum::Unsigned + typenum::IsEqual<SizeR, Output = typenum::True>,
+    SizeR: typenum::Unsigned,
+> SameDim<Cst<SizeR>> for Cst<SizeL> {
+    #[inline(always)]
+    fn check_same(self, _: Cst<SizeR>) -> Result<(), ShapeError> {
+        Ok(())
+    }
+}
+impl<SizeL: typenum::Unsigned> SameDim<Dyn> for Cst<SizeL> {
+    fn check_same(self, rhs: Dyn) -> Result<(), ShapeError> {
+        check_same_size(SizeL::USIZE, rhs.size)
+    }
+}
+impl<SizeR: typenum::Unsigned> SameDim<Cst<SizeR>> for Dyn {
+    fn check_same(self, _: Cst<SizeR>) -> Result<(), ShapeError> {
+        check_same_size(self.size, SizeR::USIZE)
+    }
+}
+impl SameDim<Dyn> for Dyn {
+    fn check_same(self, rhs: Dyn) -> Result<(), ShapeError> {
+        check_same_size(self.size, rhs.size)
+    }
+}
+fn check_same_size(lhs: usize, rhs: usize) -> Result<(), ShapeError> {
+    if lhs == rhs {
+        Ok(())
+    } else {
+        Err(ShapeError::MatMul { lhs, rhs })
+    }
+}
+
+impl<D0: Dim, D1: Dim> MatrixShape for (D0, D1,) {
+    type Batch = ();
+    type Rows = D0;
+    type Cols = D1;
+    #[inline(always)]
+    fn split(self) -> (Self::Batch, Self::Rows, Self::Cols) {
+        ((), self.0, self.1)
+    }
+}
+
+impl<D0: Dim, D1: Dim, D2: Dim> MatrixShape for (D0, D1, D2,) {
+    type Batch = (D0,);
+    type Rows = D1;
+    type Cols = D2;
+    #[inline(always)]
+    fn split(self) -> (Self::Batch, Self::Rows, Self::Cols) {
+        ((self.0,), self.1, self.2)
+    }
+}
+
+impl<D0: Dim, D1: Dim, D2: Dim, D3: Dim> MatrixShape for (D0, D1, D2, D3,) {
+    type Batch = (D0, D1,);
+    type Rows = D2;
+    type Cols = D3;
+    #[inline(always)]
+    fn split(self) -> (Self::Batch, Self::Rows, Self::Cols) {
+        ((self.0, self.1,), self.2, self.3)
+    }
+}
+
+impl<D0: Dim, D1: Dim, D2: Dim, D3: Dim, D4: Dim> MatrixShape for (D0, D1, D2, D3, D4,) {
+    type Batch = (D0, D1, D2,);
+    type Rows = D3;
+    type Cols = D4;
+    #[inline(always)]
+    fn split(self) -> (Self::Batch, Self::Rows, Self::Cols) {
+        ((self.0, self.1, self.2,), self.3, self.4)
+    }
+}
+
+impl<D0: Dim, D1: Dim, D2: Dim, D3: Dim, D4: Dim, D5: Dim> MatrixShape for (D0, D1, D2, D3, D4, D5,) {
+    type Batch = (D0, D1, D2, D3,);
+    type Rows = D4;
+    type Cols = D5;
+    #[inline(always)]
+    fn split(self) -> (Self::Batch, Self::Rows, Self::Cols) {
+        ((self.0, self.1, self.2, self.3,), self.4, self.5)
+    }
+}
+
+impl<Rows: Dim, Cols: Dim> AppendMatrix<Rows, Cols> for () {
+    type Output = (Rows, Cols,);
+    #[inline(always)]
+    fn append(self, rows: Rows, cols: Cols) -> Self::Output {
+        (rows, cols)
+    }
+}
+
+impl<D0: Dim, Rows: Dim, Cols: Dim> AppendMatrix<Rows, Cols> for (D0,) {
+    type Output = (D0, Rows, Cols,);
+    #[inline(always)]
+    fn append(self, rows: Rows, cols: Cols) -> Self::Output {
+        (self.0, rows, cols)
+    }
+}
+
+impl<D0: Dim, D1: Dim, Rows: Dim, Cols: Dim> AppendMatrix<Rows, Cols> for (D0, D1,) {
+    type Output = (D0, D1, Rows, Cols,);
+    #[inline(always)]
+    fn append(self, rows: Rows, cols: Cols) -> Self::Output {
+        (self.0, self.1, rows, cols)
+    }
+}
+
+impl<D0: Dim, D1: Dim, D2: Dim, Rows: Dim, Cols: Dim> AppendMatrix<Rows, Cols> for (D0, D1, D2,) {
+    type Output = (D0, D1, D2, Rows, Cols,);
+    #[inline(always)]
+    fn append(self, rows: Rows, cols: Cols) -> Self::Output {
+        (self.0, self.1, self.2, rows, cols)
+    }
+}
+
+impl<D0: Dim, D1: Dim, D2: Dim, D3: Dim, Rows: Dim, Cols: Dim> AppendMatrix<Rows, Cols> for (D0, D1, D2, D3,) {
+    type Output = (D0, D1, D2, D3, Rows, Cols,);
+    #[inline(always)]
+    fn append(self, rows: Rows, cols: Cols) -> Self::Output {
+        (self.0, self.1, self.2, self.3, rows, cols)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn strides_of<S: Shape, SO: Shape>(shape: S, output: SO) -> Vec<usize> {
+        broadcast_strides(
+            shape.dimensions().as_slice(),
+            shape.strides().as_slice(),
+            &output.dimensions(),
+        ).to_vec()
+    }
+
+    #[test]
+    fn test_broadcast_strides_same_shape() {
+        let shape = (Cst::<typenum::U2>::new(), Cst::<typenum::U3>::new());
+        assert_eq!(strides_of(shape, shape), vec![3, 1]);
+    }
+
+    #[test]
+    fn test_broadcast_strides_unit_axis() {
+        let l = (Cst::<typenum::U2>::new(), Cst::<typenum::U3>::new());
+        let r = (Cst::<typenum::U1>::new(), Cst::<typenum::U3>::new());
+        let o = l.broadcast(r);
+        assert_eq!(strides_of(l, o), vec![3, 1]);
+        assert_eq!(strides_of(r, o), vec![0, 1]);
+
+        let l = (Dyn::new(3), Dyn::new(1));
+        let r = (Dyn::new(1), Dyn::new(3));
+        let o = l.broadcast(r);
+        assert_eq!(strides_of(l, o), vec![1, 0]);
+        assert_eq!(strides_of(r, o), vec![0, 1]);
+    }
+
+    #[test]
+    fn test_offset() {
+        let shape = (Dyn::new(2), Cst::<typenum::U3>::new(), Dyn::new(4));
+        let offset = |index: [usize; 3]| shape.offset(&generic_array::GenericArray::from(index));
+        assert_eq!(offset([0, 0, 0]), Some(0));
+        assert_eq!(offset([1, 2, 3]), Some(23));
+        assert_eq!(offset([0, 3, 0]), None);
+        assert_eq!(().offset(&generic_array::GenericArray::default()), Some(0));
+    }
+
+    #[test]
+    fn test_try_broadcast() {
+        assert_eq!(Dyn::new(3).try_broadcast(Dyn::new(1)).unwrap().size(), 3);
+        assert_eq!(Dyn::new(1).try_broadcast(Cst::<typenum::U4>::new()).unwrap().size(), 4);
+        assert_eq!(
+            Dyn::new(3).try_broadcast(Dyn::new(4)).err(),
+            Some(ShapeError::Broadcast { axis: 0, lhs: 3, rhs: 4 }),
+        );
+
+        let l = (Dyn::new(2), Cst::<typenum::U4>::new(), Dyn::new(3));
+        let r = (Dyn::new(5), Dyn::new(3));
+        assert_eq!(
+            l.try_broadcast(r).err(),
+            Some(ShapeError::Broadcast { axis: 1, lhs: 4, rhs: 5 }),
+        );
+        assert_eq!(
+            r.try_broadcast((Dyn::new(1), Dyn::new(2))).err(),
+            Some(ShapeError::Broadcast { axis: 1, lhs: 3, rhs: 2 }),
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "can not broadcast axis 0 of size 3 with size 4")]
+    fn test_broadcast_mismatch() {
+        let _ = (Dyn::new(3),).broadcast((Cst::<typenum::U4>::new(),));
+    }
+
+    #[test]
+    fn test_matmul_shape() {
+        let l = (Dyn::new(4), Cst::<typenum::U2>::new(), Cst::<typenum::U3>::new());
+        let r = (Cst::<typenum::U3>::new(), Dyn::new(5));
+        assert_eq!(l.matmul(r).dimensions().as_slice(), &[4, 2, 5]);
+        assert_eq!(r.try_matmul(l).err(), Some(ShapeError::MatMul { lhs: 5, rhs: 2 }));
+
+        let l = (Dyn::new(3), Dyn::new(1), Dyn::new(2), Dyn::new(2));
+        let r = (Dyn::new(4), Dyn::new(2), Dyn::new(6));
+        assert_eq!(l.matmul(r).dimensions().as_slice(), &[3, 4, 2, 6]);
+        assert_eq!(
+            l.try_matmul((Dyn::new(2), Dyn::new(5), Dyn::new(2), Dyn::new(2))).err(),
+            Some(ShapeError::Broadcast { axis: 0, lhs: 3, rhs: 2 }),
+        );
+    }
+
+    #[test]
+    fn test_broadcast_strides_lower_rank() {
+        let l = (Dyn::new(2), Dyn::new(4), Dyn::new(3));
+        let r = (Dyn::new(4), Dyn::new(1));
+        let o = l.broadcast(r);
+        assert_eq!(strides_of(l, o), vec![12, 3, 1]);
+        assert_eq!(strides_of(r, o), vec![0, 1, 0]);
+        assert_eq!(strides_of((), o), vec![0, 0, 0]);
+    }
+}
@@ -1,73 +1,587 @@
 use std::fmt::Display;
-use std::ops::{Add, Div, Mul, Sub, BitAnd, BitOr};
-use cust::memory::{CopyDestination, DeviceBuffer, DeviceCopy};
-use cust::util::SliceExt;
-use crate::shape::{BroadcastShape, Shape};
-
-struct CpuTensor<T, S: Shape> {
-    data: Vec<T>,
-    shape: S,
+use std::ops::{
+    Add, AddAssign, BitAnd, BitAndAssign, BitOr, BitOrAssign, BitXor, BitXorAssign, Div, DivAssign,
+    Mul, MulAssign, Rem, RemAssign, Shl, ShlAssign, Shr, ShrAssign, Sub, SubAssign,
+};
+use crate::backend::{Backend, Cpu, DType};
+#[cfg(feature = "cuda")]
+use crate::backend::Cuda;
+use crate::backend::ops::{
+    AddOp, AndOp, Arith, BinaryOp, DivOp, EqOp, GeOp, GtOp, LeOp, LtOp, MulOp, NeOp, OrOp, RemOp,
+    ShlOp, ShrOp, SubOp, XorOp,
+};
+use crate::error::DeeperError;
+use crate::shape::{
+    broadcast_strides, BroadcastShape, ConstShape, Dim, Dyn, MatMulShape, MatrixShape, MinSizeShape,
+    PermuteShape, Shape, ShapeError, SwapAxes,
+};
+
+/// A dense, row-major tensor with elements `T` and shape `S`, stored on the backend `B`.
+///
+/// Operators broadcast their operands, the shape of the result is computed by
+/// [`BroadcastShape`] so incompatible constant shapes are rejected at compile time.
+///
+/// ```
+/// use deeper::prelude::*;
+/// use typenum::{U1, U2, U3};
+///
+/// let a = CpuTensor::<f32, (Cst<U2>, Cst<U1>)>::of((Cst::new(), Cst::new()), 2.0);
+/// let b = CpuTensor::<f32, (Cst<U3>,)>::one((Cst::new(),));
+/// let c: CpuTensor<f32, (Cst<U2>, Cst<U3>)> = &a + &b;
+/// assert_eq!(c.as_slice(), &[3.0; 6]);
+/// ```
+pub struct Tensor<T: DType, S: Shape, B: Backend> {
+    pub(crate) data: B::Storage<T>,
+    pub(crate) shape: S,
 }
 
-impl<T, S: Shape> CpuTensor<T, S> {
-    fn zero(shape: S) -> Self
+/// A tensor in host memory.
+pub type CpuTensor<T, S> = Tensor<T, S, Cpu>;
+/// A tensor in the memory of the CUDA device.
+#[cfg(feature = "cuda")]
+pub type CudaTensor<T, S> = Tensor<T, S, Cuda>;
+
+impl<T: DType, S: Shape, B: Backend> Tensor<T, S, B> {
+    /// ```
+    /// use deeper::prelude::*;
+    ///
+    /// let tensor = CpuTensor::<f64, (Dyn,)>::zero((Dyn::new(3),));
+    /// assert_eq!(tensor.as_slice(), &[0.0; 3]);
+    /// ```
+    pub fn zero(shape: S) -> Self
         where T: num_traits::Zero
     {
-        let size = shape.size();
-        let mut data = Vec::with_capacity(size);
-        for _ in 0..size {
-            data.push(T::zero());
+        Self::of(shape, T::zero())
+    }
+
+    pub fn one(shape: S) -> Self
+        where T: num_traits::One
+    {
+        Self::of(shape, T::one())
+    }
+
+    /// Creates a tensor with every element set to `value`.
+    ///
+    /// ```
+    /// use deeper::prelude::*;
+    ///
+    /// let tensor = CpuTensor::<bool, (Dyn, Dyn)>::of((Dyn::new(2), Dyn::new(2)), true);
+    /// assert_eq!(tensor.as_slice(), &[true; 4]);
+    /// ```
+    pub fn of(shape: S, value: T) -> Self {
+        let data = vec![value; shape.size()];
+
+        Self {
+            data: expect(B::from_vec(data)),
+            shape,
         }
+    }
 
-        Self { data, shape }
+    /// Creates a tensor from row-major data, panics if the length does not match the shape.
+    ///
+    /// ```
+    /// use deeper::prelude::*;
+    ///
+    /// let tensor = CpuTensor::from_vec((Dyn::new(2), Dyn::new(2)), vec![1.0, 2.0, 3.0, 4.0]);
+    /// assert_eq!(tensor.as_slice(), &[1.0, 2.0, 3.0, 4.0]);
+    /// ```
+    pub fn from_vec(shape: S, data: Vec<T>) -> Self {
+        expect(Self::try_from_vec(shape, data))
     }
 
-    fn one(shape: S) -> Self
-        where T: num_traits::One
+    pub fn try_from_vec(shape: S, data: Vec<T>) -> Result<Self, DeeperError> {
+        if data.len() != shape.size() {
+            return Err(ShapeError::Size { expected: shape.size(), actual: data.len() }.into());
+        }
+
+        Ok(Self {
+            data: B::from_vec(data)?,
+            shape,
+        })
+    }
+
+    /// Creates a tensor from the elements of `iter` in row-major order, panics if it does not
+    /// yield exactly as many elements as the shape holds.
+    pub fn from_shape_iter(shape: S, iter: impl IntoIterator<Item = T>) -> Self {
+        expect(Self::try_from_shape_iter(shape, iter))
+    }
+
+    /// Like [`Tensor::from_shape_iter`], but returns an error instead of panicking. A too long
+    /// iterator is run to its end to report its length.
+    pub fn try_from_shape_iter(
+        shape: S,
+        iter: impl IntoIterator<Item = T>,
+    ) -> Result<Self, DeeperError> {
+        let mut iter = iter.into_iter();
+        let data: Vec<T> = iter.by_ref().take(shape.size()).collect();
+        let rest = iter.count();
+        if rest > 0 {
+            return Err(ShapeError::Size { expected: shape.size(), actual: data.len() + rest }.into());
+        }
+        Self::try_from_vec(shape, data)
+    }
+
+    /// Creates a tensor by calling `f` with the multi-index of every element.
+    ///
+    /// ```
+    /// use deeper::prelude::*;
+    ///
+    /// let tensor = CpuTensor::from_fn((Dyn::new(2), Dyn::new(3)), |idx| (idx[0] * 10 + idx[1]) as f32);
+    /// assert_eq!(tensor.as_slice(), &[0.0, 1.0, 2.0, 10.0, 11.0, 12.0]);
+    /// ```
+    pub fn from_fn(
+        shape: S,
+        mut f: impl FnMut(generic_array::GenericArray<usize, S::Dims>) -> T,
+    ) -> Self {
+        let dims = shape.dimensions();
+        let mut index = generic_array::GenericArray::<usize, S::Dims>::default();
+        let mut data = Vec::with_capacity(shape.size());
+        for _ in 0..shape.size() {
+            data.push(f(index.clone()));
+            // Advance the multi-index, the last axis moves fastest.
+            for axis in (0..dims.len()).rev() {
+                index[axis] += 1;
+                if index[axis] < dims[axis] {
+                    break;
+                }
+                index[axis] = 0;
+            }
+        }
+
+        Self {
+            data: expect(B::from_vec(data)),
+            shape,
+        }
+    }
+
+    /// Copies the tensor to another backend.
+    pub fn try_to_backend<BO: Backend>(&self) -> Result<Tensor<T, S, BO>, DeeperError> {
+        Ok(Tensor {
+            data: BO::from_vec(B::to_vec(&self.data)?)?,
+            shape: self.shape,
+        })
+    }
+
+    pub fn to_backend<BO: Backend>(&self) -> Tensor<T, S, BO> {
+        expect(self.try_to_backend())
+    }
+
+    #[cfg(feature = "cuda")]
+    pub fn try_cuda(&self) -> Result<CudaTensor<T, S>, DeeperError> {
+        self.try_to_backend()
+    }
+
+    #[cfg(feature = "cuda")]
+    pub fn cuda(&self) -> CudaTensor<T, S> {
+        self.to_backend()
+    }
+
+    pub fn try_cpu(&self) -> Result<CpuTensor<T, S>, DeeperError> {
+        self.try_to_backend()
+    }
+
+    pub fn cpu(&self) -> CpuTensor<T, S> {
+        self.to_backend()
+    }
+
+    pub fn shape(&self) -> S {
+        self.shape
+    }
+
+    /// Reinterprets the elements with the constant shape `SO`, without copying them.
+    ///
+    /// Both shapes have to hold the same number of elements, which is checked at compile time.
+    ///
+    /// ```
+    /// use deeper::prelude::*;
+    /// use typenum::{U2, U3, U6};
+    ///
+    /// let a = CpuTensor::<f32, (Cst<U2>, Cst<U3>)>::from_vec(
+    ///     (Cst::new(), Cst::new()),
+    ///     vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0],
+    /// );
+    /// let b = a.reshape::<(Cst<U3>, Cst<U2>)>();
+    /// assert_eq!(b.as_slice(), &[1.0, 2.0, 3.0, 4.0, 5.0, 6.0]);
+    /// ```
+    ///
+    /// ```compile_fail
+    /// use deeper::prelude::*;
+    /// use typenum::{U2, U3, U4};
+    ///
+    /// let a = CpuTensor::<f32, (Cst<U2>, Cst<U3>)>::zero((Cst::new(), Cst::new()));
+    /// let b = a.reshape::<(Cst<U2>, Cst<U4>)>();
+    /// ```
+    pub fn reshape<SO>(self) -> Tensor<T, SO, B>
+        where
+            S: ConstShape + MinSizeShape,
+            SO: ConstShape + MinSizeShape<Value = <S as MinSizeShape>::Value>,
     {
-        let size = shape.size();
-        let mut data = Vec::with_capacity(size);
-        for _ in 0..size {
-            data.push(T::one());
+        Tensor { data: self.data, shape: SO::default() }
+    }
+
+    /// Reinterprets the elements with `shape`, without copying them.
+    ///
+    /// Panics if `shape` does not hold as many elements as the tensor, use [`Tensor::reshape`]
+    /// to check this at compile time for constant shapes.
+    pub fn reshape_to<SO: Shape>(self, shape: SO) -> Tensor<T, SO, B> {
+        expect(self.try_reshape_to(shape))
+    }
+
+    /// Like [`Tensor::reshape_to`], but returns an error instead of panicking.
+    pub fn try_reshape_to<SO: Shape>(self, shape: SO) -> Result<Tensor<T, SO, B>, DeeperError> {
+        if shape.size() != self.shape.size() {
+            return Err(ShapeError::Size { expected: shape.size(), actual: self.shape.size() }.into());
         }
 
-        Self { data, shape }
+        Ok(Tensor { data: self.data, shape })
     }
 
-    fn of(shape: S, value: T) -> Self
-        where T: Clone
+    fn binary<Op, R, SR, SO>(&self, rhs: &Tensor<R, SR, B>) -> Tensor<Op::Output, SO, B>
+        where
+            Op: BinaryOp<T, R>,
+            R: DType,
+            SR: Shape,
+            SO: Shape,
+            S: BroadcastShape<SR, Output = SO>,
     {
-        let size = shape.size();
-        let mut data = Vec::with_capacity(size);
-        for _ in 0..size {
-            data.push(value.clone());
+        expect(self.try_binary::<Op, R, SR, SO>(rhs))
+    }
+
+    fn try_binary<Op, R, SR, SO>(
+        &self,
+        rhs: &Tensor<R, SR, B>,
+    ) -> Result<Tensor<Op::Output, SO, B>, DeeperError>
+        where
+            Op: BinaryOp<T, R>,
+            R: DType,
+            SR: Shape,
+            SO: Shape,
+            S: BroadcastShape<SR, Output = SO>,
+    {
+        let shape = self.shape.try_broadcast(rhs.shape)?;
+        let mut out = Tensor { data: B::alloc(shape.size())?, shape };
+        self.try_binary_into::<Op, R, SR, SO>(rhs, &mut out)?;
+
+        Ok(out)
+    }
+
+    /// Like `try_binary`, but writes into `out`, which has to have the broadcast shape.
+    fn try_binary_into<Op, R, SR, SO>(
+        &self,
+        rhs: &Tensor<R, SR, B>,
+        out: &mut Tensor<Op::Output, SO, B>,
+    ) -> Result<(), DeeperError>
+        where
+            Op: BinaryOp<T, R>,
+            R: DType,
+            SR: Shape,
+            SO: Shape,
+            S: BroadcastShape<SR, Output = SO>,
+    {
+        let shape = self.shape.try_broadcast(rhs.shape)?;
+        check_out_shape(out.shape, shape)?;
+        let o_dims = shape.dimensions();
+        let o_strides = shape.strides();
+        let a_strides = broadcast_strides(
+            self.shape.dimensions().as_slice(),
+            self.shape.strides().as_slice(),
+            &o_dims,
+        );
+        let b_strides = broadcast_strides(
+            rhs.shape.dimensions().as_slice(),
+            rhs.shape.strides().as_slice(),
+            &o_dims,
+        );
+
+        B::binary::<Op, T, R>(
+            &self.data,
+            &a_strides,
+            &rhs.data,
+            &b_strides,
+            &mut out.data,
+            &o_strides,
+        )
+    }
+
+    /// Like `try_binary`, but consumes `self` and writes the result into its buffer if it already
+    /// has the broadcast shape.
+    fn try_binary_lhs<Op, R, SR, SO>(self, rhs: &Tensor<R, SR, B>) -> Result<Tensor<T, SO, B>, DeeperError>
+        where
+            Op: BinaryOp<T, R, Output = T>,
+            R: DType,
+            SR: Shape,
+            SO: Shape,
+            S: BroadcastShape<SR, Output = SO>,
+    {
+        let shape = self.shape.try_broadcast(rhs.shape)?;
+        if shape.dimensions().as_slice() != self.shape.dimensions().as_slice() {
+            return self.try_binary::<Op, R, SR, SO>(rhs);
         }
+        let b_strides = broadcast_strides(
+            rhs.shape.dimensions().as_slice(),
+            rhs.shape.strides().as_slice(),
+            &shape.dimensions(),
+        );
+        let mut data = self.data;
+        B::binary_assign::<Op, T, R>(&mut data, &shape.strides(), &rhs.data, &b_strides)?;
 
-        Self { data, shape }
+        Ok(Tensor { data, shape })
     }
 
-    fn cuda(&self) -> CudaTensor<T, S>
-        where T: DeviceCopy
+    /// Like `try_binary`, but consumes `rhs` and writes the result into its buffer if it already
+    /// has the broadcast shape.
+    fn try_binary_rhs<Op, R, SR, SO>(&self, rhs: Tensor<R, SR, B>) -> Result<Tensor<R, SO, B>, DeeperError>
+        where
+            Op: BinaryOp<T, R, Output = R>,
+            R: DType,
+            SR: Shape,
+            SO: Shape,
+            S: BroadcastShape<SR, Output = SO>,
     {
-        // make sure the context and stream are set
-        crate::CTX.with(|ctx| {
-            crate::STREAM.with(|stream| {
-                let dbuf = self.data.as_slice().as_dbuf().unwrap();
-
-                CudaTensor {
-                    data: dbuf,
-                    shape: self.shape,
-                }
-            })
-        })
+        let shape = self.shape.try_broadcast(rhs.shape)?;
+        if shape.dimensions().as_slice() != rhs.shape.dimensions().as_slice() {
+            return self.try_binary::<Op, R, SR, SO>(&rhs);
+        }
+        let a_strides = broadcast_strides(
+            self.shape.dimensions().as_slice(),
+            self.shape.strides().as_slice(),
+            &shape.dimensions(),
+        );
+        let mut data = rhs.data;
+        B::binary_assign_rhs::<Op, T, R>(&self.data, &a_strides, &mut data, &shape.strides())?;
+
+        Ok(Tensor { data, shape })
+    }
+
+    /// Applies `Op` to `self` and `rhs` in place, `rhs` has to broadcast into the shape of `self`.
+    fn try_binary_assign<Op, R, SR>(&mut self, rhs: &Tensor<R, SR, B>) -> Result<(), DeeperError>
+        where
+            Op: BinaryOp<T, R, Output = T>,
+            R: DType,
+            SR: Shape,
+            S: BroadcastShape<SR, Output = S>,
+    {
+        let shape = self.shape.try_broadcast(rhs.shape)?;
+        check_out_shape(self.shape, shape)?;
+        let o_dims = shape.dimensions();
+        let b_strides = broadcast_strides(
+            rhs.shape.dimensions().as_slice(),
+            rhs.shape.strides().as_slice(),
+            &o_dims,
+        );
+
+        B::binary_assign::<Op, T, R>(&mut self.data, &shape.strides(), &rhs.data, &b_strides)
+    }
+
+    /// Reorders the axes, axis `i` of the result is axis `P.i` of `self`.
+    ///
+    /// `P` is a tuple of `typenum` indices which has to name every axis exactly once.
+    ///
+    /// ```
+    /// use deeper::prelude::*;
+    /// use typenum::{U0, U1, U2, U3};
+    ///
+    /// let nchw = CpuTensor::from_fn((Dyn::new(1), Cst::<U2>::new(), Dyn::new(2), Dyn::new(3)), |idx| idx[1] as f32);
+    /// let nhwc = nchw.permute::<(U0, U2, U3, U1)>();
+    /// assert_eq!(nhwc.shape().dimensions().as_slice(), &[1, 2, 3, 2]);
+    /// assert_eq!(&nhwc.as_slice()[..4], &[0.0, 1.0, 0.0, 1.0]);
+    /// ```
+    pub fn permute<P>(&self) -> Tensor<T, <S as PermuteShape<P>>::Output, B>
+        where
+            S: PermuteShape<P>,
+    {
+        expect(self.try_permute::<P>())
+    }
+
+    /// Like [`Tensor::permute`], but returns an error instead of panicking.
+    pub fn try_permute<P>(&self) -> Result<Tensor<T, <S as PermuteShape<P>>::Output, B>, DeeperError>
+        where
+            S: PermuteShape<P>,
+    {
+        self.view().permute::<P>().try_contiguous()
+    }
+
+    /// Swaps the axes `A0` and `A1`, given as `typenum` indices.
+    pub fn swap_axes<A0, A1>(&self) -> Tensor<T, <S as SwapAxes<A0, A1>>::Output, B>
+        where
+            A0: typenum::Unsigned,
+            A1: typenum::Unsigned,
+            S: SwapAxes<A0, A1>,
+    {
+        expect(self.try_swap_axes::<A0, A1>())
+    }
+
+    /// Like [`Tensor::swap_axes`], but returns an error instead of panicking.
+    #[allow(clippy::type_complexity)]
+    pub fn try_swap_axes<A0, A1>(
+        &self,
+    ) -> Result<Tensor<T, <S as SwapAxes<A0, A1>>::Output, B>, DeeperError>
+        where
+            A0: typenum::Unsigned,
+            A1: typenum::Unsigned,
+            S: SwapAxes<A0, A1>,
+    {
+        self.view().swap_axes::<A0, A1>().try_contiguous()
+    }
+
+    /// Matrix product over the last two axes, the leading axes are batch axes and broadcast.
+    ///
+    /// The inner axes have to match, for constant axes this is checked at compile time.
+    ///
+    /// ```
+    /// use deeper::prelude::*;
+    /// use typenum::{U2, U3};
+    ///
+    /// let a = CpuTensor::<f32, (Cst<U2>, Cst<U3>)>::one((Cst::new(), Cst::new()));
+    /// let b = CpuTensor::<f32, (Cst<U3>, Cst<U2>)>::of((Cst::new(), Cst::new()), 2.0);
+    /// let c: CpuTensor<f32, (Cst<U2>, Cst<U2>)> = a.matmul(&b);
+    /// assert_eq!(c.as_slice(), &[6.0; 4]);
+    /// ```
+    pub fn matmul<SR, SO>(&self, rhs: &Tensor<T, SR, B>) -> Tensor<T, SO, B>
+        where
+            T::Acc: Arith,
+            SR: Shape,
+            SO: MatrixShape,
+            S: MatMulShape<SR, Output = SO>,
+    {
+        expect(self.try_matmul(rhs))
     }
 
-    fn as_slice(&self) -> &[T] {
+    /// Like [`Tensor::matmul`], but returns an error instead of panicking.
+    pub fn try_matmul<SR, SO>(&self, rhs: &Tensor<T, SR, B>) -> Result<Tensor<T, SO, B>, DeeperError>
+        where
+            T::Acc: Arith,
+            SR: Shape,
+            SO: MatrixShape,
+            S: MatMulShape<SR, Output = SO>,
+    {
+        let shape = self.shape.try_matmul(rhs.shape)?;
+        let mut data = B::alloc(shape.size())?;
+        let o_dims = shape.dimensions();
+        let a_strides = matrix_strides(&self.shape, &o_dims);
+        let b_strides = matrix_strides(&rhs.shape, &o_dims);
+        let a_dims = self.shape.dimensions();
+
+        B::matmul::<T>(
+            &self.data,
+            &a_strides,
+            &rhs.data,
+            &b_strides,
+            &mut data,
+            &o_dims,
+            a_dims[a_dims.len() - 1],
+        )?;
+
+        Ok(Tensor { data, shape })
+    }
+
+    /// Like [`Tensor::matmul`], but returns an error instead of panicking if the shapes do not
+    /// match.
+    pub fn checked_matmul<SR, SO>(&self, rhs: &Tensor<T, SR, B>) -> Result<Tensor<T, SO, B>, ShapeError>
+        where
+            T::Acc: Arith,
+            SR: Shape,
+            SO: MatrixShape,
+            S: MatMulShape<SR, Output = SO>,
+    {
+        expect_shape(self.try_matmul(rhs))
+    }
+}
+
+impl<T: DType, D0: Dim, D1: Dim, B: Backend> Tensor<T, (D0, D1), B> {
+    /// Swaps the rows and columns of a matrix.
+    ///
+    /// ```
+    /// use deeper::prelude::*;
+    /// use typenum::{U2, U3};
+    ///
+    /// let a = CpuTensor::<f32, (Cst<U2>, Cst<U3>)>::from_vec(
+    ///     (Cst::new(), Cst::new()),
+    ///     vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0],
+    /// );
+    /// let b: CpuTensor<f32, (Cst<U3>, Cst<U2>)> = a.transpose();
+    /// assert_eq!(b.as_slice(), &[1.0, 4.0, 2.0, 5.0, 3.0, 6.0]);
+    /// ```
+    pub fn transpose(&self) -> Tensor<T, (D1, D0), B> {
+        expect(self.try_transpose())
+    }
+
+    /// Like [`Tensor::transpose`], but returns an error instead of panicking.
+    pub fn try_transpose(&self) -> Result<Tensor<T, (D1, D0), B>, DeeperError> {
+        self.try_permute::<(typenum::U1, typenum::U0)>()
+    }
+}
+
+/// Strides of a matrix operand for a product with output dimensions `o_dims`: the batch axes are
+/// broadcast, the last two axes keep the strides of the operand.
+fn matrix_strides<S: Shape, N: generic_array::ArrayLength<usize>>(
+    shape: &S,
+    o_dims: &generic_array::GenericArray<usize, N>,
+) -> generic_array::GenericArray<usize, N> {
+    let dims = shape.dimensions();
+    let strides = shape.strides();
+    let mut o_strides = broadcast_strides(dims.as_slice(), strides.as_slice(), o_dims);
+    let (rank, o_rank) = (strides.len(), o_strides.len());
+    o_strides[o_rank - 2] = strides[rank - 2];
+    o_strides[o_rank - 1] = strides[rank - 1];
+
+    o_strides
+}
+
+/// Unwraps the result of a fallible operation, the panicking API is built on this.
+pub(crate) fn expect<T>(result: Result<T, DeeperError>) -> T {
+    match result {
+        Ok(value) => value,
+        Err(err) => panic!("{}", err),
+    }
+}
+
+/// Checks that an output tensor of shape `out` can hold a result of shape `shape`, reporting the
+/// first axis that differs.
+fn check_out_shape<S: Shape>(out: S, shape: S) -> Result<(), ShapeError> {
+    let (out, shape) = (out.dimensions(), shape.dimensions());
+    match out.iter().zip(shape.iter()).position(|(o, s)| o != s) {
+        Some(axis) => Err(ShapeError::Broadcast { axis, lhs: out[axis], rhs: shape[axis] }),
+        None => Ok(()),
+    }
+}
+
+/// Narrows the result of a fallible operation to shape errors, panicking on any other error.
+fn expect_shape<T>(result: Result<T, DeeperError>) -> Result<T, ShapeError> {
+    match result {
+        Ok(value) => Ok(value),
+        Err(DeeperError::Shape(err)) => Err(err),
+        Err(err) => panic!("{}", err),
+    }
+}
+
+/// Collects a constant shaped tensor, panics if the iterator does not yield exactly as many
+/// elements as the shape holds.
+impl<T: DType, S: ConstShape, B: Backend> FromIterator<T> for Tensor<T, S, B> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        Self::from_shape_iter(S::default(), iter)
+    }
+}
+
+/// Collects a vector as long as the iterator.
+impl<T: DType, B: Backend> FromIterator<T> for Tensor<T, (Dyn,), B> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let data: Vec<T> = iter.into_iter().collect();
+        Self::from_vec((Dyn::new(data.len()),), data)
+    }
+}
+
+impl<T: DType, S: Shape> CpuTensor<T, S> {
+    pub fn as_slice(&self) -> &[T] {
         self.data.as_slice()
     }
+
+    pub fn as_mut_slice(&mut self) -> &mut [T] {
+        self.data.as_mut_slice()
+    }
 }
 
-impl<T, S: Shape> Display for CpuTensor<T, S>
+impl<T: DType, S: Shape> Display for CpuTensor<T, S>
     where T: Display
 {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
@@ -104,160 +618,365 @@ impl<T, S: Shape> Display for CpuTensor<T, S>
     }
 }
 
-impl<T: DeviceCopy, S: Shape> Into<CudaTensor<T, S>> for CpuTensor<T, S> {
-    fn into(self) -> CudaTensor<T, S> {
-        self.cuda()
+#[cfg(feature = "cuda")]
+impl<T: DType, S: Shape> From<CpuTensor<T, S>> for CudaTensor<T, S> {
+    fn from(tensor: CpuTensor<T, S>) -> Self {
+        tensor.cuda()
     }
 }
 
-struct CudaTensor<T: DeviceCopy, S: Shape> {
-    data: DeviceBuffer<T>,
-    shape: S,
+#[cfg(feature = "cuda")]
+impl<T: DType, S: Shape> From<CudaTensor<T, S>> for CpuTensor<T, S> {
+    fn from(tensor: CudaTensor<T, S>) -> Self {
+        tensor.cpu()
+    }
 }
 
-impl<T: DeviceCopy, S: Shape> CudaTensor<T, S> {
-    fn cpu(&self) -> CpuTensor<T, S> {
-        let size = self.shape.size();
-        let mut data = Vec::with_capacity(size);
-        unsafe { data.set_len(size); };
-        crate::STREAM.with(|stream| {
-            stream.synchronize().unwrap();
-            self.data.copy_to(&mut data).unwrap();
-
-            CpuTensor {
-                data,
-                shape: self.shape,
+macro_rules! impl_op {
+    ($op_ty:ident, $fn_id:ident, $try_fn_id:ident, $checked_fn_id:ident, $kernel:ty) => {
+        impl<
+            L: DType,
+            R: DType,
+            SL: Shape,
+            SR: Shape,
+            SO: Shape,
+            B: Backend,
+        > $op_ty<&Tensor<R, SR, B>> for &Tensor<L, SL, B>
+            where
+                SL: BroadcastShape<SR, Output = SO>,
+                $kernel: BinaryOp<L, R>,
+        {
+            type Output = Tensor<<$kernel as BinaryOp<L, R>>::Output, SO, B>;
+
+            fn $fn_id(self, rhs: &Tensor<R, SR, B>) -> Self::Output {
+                self.binary::<$kernel, R, SR, SO>(rhs)
             }
-        })
-    }
-}
+        }
 
-impl<T: DeviceCopy, S: Shape> Into<CpuTensor<T, S>> for CudaTensor<T, S> {
-    fn into(self) -> CpuTensor<T, S> {
-        self.cpu()
-    }
-}
+        /// Reuses the buffer of `self` if it has the shape of the result.
+        impl<
+            L: DType,
+            R: DType,
+            SL: Shape,
+            SR: Shape,
+            SO: Shape,
+            B: Backend,
+        > $op_ty<&Tensor<R, SR, B>> for Tensor<L, SL, B>
+            where
+                SL: BroadcastShape<SR, Output = SO>,
+                $kernel: BinaryOp<L, R, Output = L>,
+        {
+            type Output = Tensor<L, SO, B>;
 
-macro_rules! impl_cuda_op {
-    ($op_ty:ident, $fn_id:ident, $l_ty:ty, $r_ty:ty, $o_ty:ty, $cuda_op:expr) => {
+            fn $fn_id(self, rhs: &Tensor<R, SR, B>) -> Self::Output {
+                expect(self.try_binary_lhs::<$kernel, R, SR, SO>(rhs))
+            }
+        }
+
+        /// Reuses the buffer of `rhs` if it has the shape of the result.
         impl<
+            L: DType,
+            R: DType,
             SL: Shape,
             SR: Shape,
             SO: Shape,
-        > $op_ty<&CudaTensor<$l_ty, SR>> for &CudaTensor<$r_ty, SL>
+            B: Backend,
+        > $op_ty<Tensor<R, SR, B>> for &Tensor<L, SL, B>
             where
                 SL: BroadcastShape<SR, Output = SO>,
-                generic_array::GenericArray<usize, <SO as Shape>::Dims>: Copy,
+                $kernel: BinaryOp<L, R, Output = R>,
         {
-            type Output = CudaTensor<$o_ty, SO>;
-
-            fn $fn_id(self, rhs: &CudaTensor<$r_ty, SR>) -> Self::Output {
-                let shape = self.shape.broadcast(rhs.shape);
-                let size = shape.size();
-                let mut out_buffer = unsafe { DeviceBuffer::uninitialized(size) }.unwrap();
-                let o_strides = shape.strides();
-                let a_dims = self.shape.dimensions();
-                let b_dims = rhs.shape.dimensions();
-                let mut a_strides = o_strides.clone();
-                let mut b_strides = o_strides.clone();
-                a_strides.fill(1);
-                b_strides.fill(1);
-                for i in 0..o_strides.len() {
-                    if a_dims[i] == 1 && b_dims[i] > 1 {
-                        a_strides[i] = 0;
-                    }
-                    if b_dims[i] == 1 && a_dims[i] > 1 {
-                        b_strides[i] = 0;
+            type Output = Tensor<R, SO, B>;
+
+            fn $fn_id(self, rhs: Tensor<R, SR, B>) -> Self::Output {
+                expect(self.try_binary_rhs::<$kernel, R, SR, SO>(rhs))
+            }
+        }
+
+        /// Reuses the buffer of `self`, or else of `rhs`, if it has the shape of the result.
+        impl<
+            T: DType,
+            SL: Shape,
+            SR: Shape,
+            SO: Shape,
+            B: Backend,
+        > $op_ty<Tensor<T, SR, B>> for Tensor<T, SL, B>
+            where
+                SL: BroadcastShape<SR, Output = SO>,
+                $kernel: BinaryOp<T, T, Output = T>,
+        {
+            type Output = Tensor<T, SO, B>;
+
+            fn $fn_id(self, rhs: Tensor<T, SR, B>) -> Self::Output {
+                // `try_binary_rhs` allocates if `rhs` does not fit either, and reports shape errors.
+                match self.shape.try_broadcast(rhs.shape) {
+                    Ok(shape) if shape.dimensions().as_slice() == self.shape.dimensions().as_slice() => {
+                        expect(self.try_binary_lhs::<$kernel, T, SR, SO>(&rhs))
                     }
+                    _ => expect(self.try_binary_rhs::<$kernel, T, SR, SO>(rhs)),
                 }
+            }
+        }
 
-                let a_buffer = &self.data;
-                let b_buffer = &rhs.data;
-
-                crate::STREAM.with(|stream| {
-                    crate::MODULE.with(|module| {
-                        let func = module.get_function(format!(
-                            $cuda_op,
-                            o_strides.len()
-                        )).unwrap();
-                        let (_, block_size) = func.suggested_launch_configuration(
-                            0, 0.into()
-                        ).unwrap();
-                        let grid_size = (size as u32 + block_size - 1) / block_size;
-                        let a_strides = GenericArrayDeviceCopy::new(a_strides);
-                        let b_strides = GenericArrayDeviceCopy::new(b_strides);
-                        let o_strides = GenericArrayDeviceCopy::new(o_strides);
-
-                        unsafe {
-                            cust::launch!(
-                            func<<<grid_size, block_size, 0, stream>>>(
-                                a_buffer.as_device_ptr(),
-                                a_buffer.len(),
-                                a_strides,
-                                b_buffer.as_device_ptr(),
-                                b_buffer.len(),
-                                b_strides,
-                                out_buffer.as_device_ptr(),
-                                out_buffer.len(),
-                                o_strides,
-                            )
-                        ).unwrap();
-                        }
-
-                        CudaTensor {
-                            data: out_buffer,
-                            shape,
-                        }
-                    })
-                })
+        impl<L: DType, SL: Shape, B: Backend> Tensor<L, SL, B> {
+            /// Like the operator, but returns an error instead of panicking.
+            pub fn $try_fn_id<R: DType, SR: Shape, SO: Shape>(
+                &self,
+                rhs: &Tensor<R, SR, B>,
+            ) -> Result<Tensor<<$kernel as BinaryOp<L, R>>::Output, SO, B>, DeeperError>
+                where
+                    SL: BroadcastShape<SR, Output = SO>,
+                    $kernel: BinaryOp<L, R>,
+            {
+                self.try_binary::<$kernel, R, SR, SO>(rhs)
+            }
+
+            /// Like the operator, but returns an error instead of panicking if the shapes can not
+            /// be broadcast.
+            pub fn $checked_fn_id<R: DType, SR: Shape, SO: Shape>(
+                &self,
+                rhs: &Tensor<R, SR, B>,
+            ) -> Result<Tensor<<$kernel as BinaryOp<L, R>>::Output, SO, B>, ShapeError>
+                where
+                    SL: BroadcastShape<SR, Output = SO>,
+                    $kernel: BinaryOp<L, R>,
+            {
+                expect_shape(self.try_binary::<$kernel, R, SR, SO>(rhs))
             }
         }
     };
 }
 
+impl_op!(Add, add, try_add, checked_add, AddOp);
+impl_op!(Sub, sub, try_sub, checked_sub, SubOp);
+impl_op!(Mul, mul, try_mul, checked_mul, MulOp);
+impl_op!(Div, div, try_div, checked_div, DivOp);
+impl_op!(Rem, rem, try_rem, checked_rem, RemOp);
 
-impl_cuda_op!(Add, add, f32, f32, f32, "add_f32_{}d");
-impl_cuda_op!(Add, add, f64, f64, f64, "add_f64_{}d");
-impl_cuda_op!(Sub, sub, f32, f32, f32, "sub_f32_{}d");
-impl_cuda_op!(Sub, sub, f64, f64, f64, "sub_f64_{}d");
-impl_cuda_op!(Mul, mul, f32, f32, f32, "mul_f32_{}d");
-impl_cuda_op!(Mul, mul, f64, f64, f64, "mul_f64_{}d");
-impl_cuda_op!(Div, div, f32, f32, f32, "div_f32_{}d");
-impl_cuda_op!(Div, div, f64, f64, f64, "div_f64_{}d");
-
-impl_cuda_op!(BitAnd, bitand, bool, bool, bool, "and_bool_{}d");
-impl_cuda_op!(BitOr, bitor, bool, bool, bool, "or_bool_{}d");
-
-#[derive(Clone, Copy)]
-#[repr(transparent)]
-struct GenericArrayDeviceCopy<T, N: generic_array::ArrayLength<T>>
-    where
-        T: Copy,
-        generic_array::GenericArray<T, N>: Copy,
-{
-    data: generic_array::GenericArray<T, N>,
+impl_op!(BitAnd, bitand, try_bitand, checked_bitand, AndOp);
+impl_op!(BitOr, bitor, try_bitor, checked_bitor, OrOp);
+impl_op!(BitXor, bitxor, try_bitxor, checked_bitxor, XorOp);
+impl_op!(Shl, shl, try_shl, checked_shl, ShlOp);
+impl_op!(Shr, shr, try_shr, checked_shr, ShrOp);
+
+macro_rules! impl_assign_op {
+    (
+        $op_ty:ident,
+        $fn_id:ident,
+        $try_fn_id:ident,
+        $into_fn_id:ident,
+        $try_into_fn_id:ident,
+        $kernel:ty
+    ) => {
+        impl<L: DType, R: DType, SL: Shape, SR: Shape, B: Backend> $op_ty<&Tensor<R, SR, B>>
+            for Tensor<L, SL, B>
+            where
+                SL: BroadcastShape<SR, Output = SL>,
+                $kernel: BinaryOp<L, R, Output = L>,
+        {
+            fn $fn_id(&mut self, rhs: &Tensor<R, SR, B>) {
+                expect(self.$try_fn_id(rhs))
+            }
+        }
+
+        impl<L: DType, SL: Shape, B: Backend> Tensor<L, SL, B> {
+            /// Like the operator, but returns an error instead of panicking.
+            pub fn $try_fn_id<R: DType, SR: Shape>(
+                &mut self,
+                rhs: &Tensor<R, SR, B>,
+            ) -> Result<(), DeeperError>
+                where
+                    SL: BroadcastShape<SR, Output = SL>,
+                    $kernel: BinaryOp<L, R, Output = L>,
+            {
+                self.try_binary_assign::<$kernel, R, SR>(rhs)
+            }
+
+            /// Like the binary operator, but writes the result into `out` instead of allocating
+            /// it. `out` has to have the broadcast shape of the operands.
+            pub fn $into_fn_id<R: DType, SR: Shape, SO: Shape>(
+                &self,
+                rhs: &Tensor<R, SR, B>,
+                out: &mut Tensor<<$kernel as BinaryOp<L, R>>::Output, SO, B>,
+            )
+                where
+                    SL: BroadcastShape<SR, Output = SO>,
+                    $kernel: BinaryOp<L, R>,
+            {
+                expect(self.$try_into_fn_id(rhs, out))
+            }
+
+            /// Like the method without `try_`, but returns an error instead of panicking.
+            pub fn $try_into_fn_id<R: DType, SR: Shape, SO: Shape>(
+                &self,
+                rhs: &Tensor<R, SR, B>,
+                out: &mut Tensor<<$kernel as BinaryOp<L, R>>::Output, SO, B>,
+            ) -> Result<(), DeeperError>
+                where
+                    SL: BroadcastShape<SR, Output = SO>,
+                    $kernel: BinaryOp<L, R>,
+            {
+                self.try_binary_into::<$kernel, R, SR, SO>(rhs, out)
+            }
+        }
+    };
 }
-impl<T, N: generic_array::ArrayLength<T>> GenericArrayDeviceCopy<T, N>
-    where
-        T: Copy,
-        generic_array::GenericArray<T, N>: Copy,
-{
-    fn new(data: generic_array::GenericArray<T, N>) -> Self {
-        Self { data }
+
+impl_assign_op!(AddAssign, add_assign, try_add_assign, add_into, try_add_into, AddOp);
+impl_assign_op!(SubAssign, sub_assign, try_sub_assign, sub_into, try_sub_into, SubOp);
+impl_assign_op!(MulAssign, mul_assign, try_mul_assign, mul_into, try_mul_into, MulOp);
+impl_assign_op!(DivAssign, div_assign, try_div_assign, div_into, try_div_into, DivOp);
+impl_assign_op!(RemAssign, rem_assign, try_rem_assign, rem_into, try_rem_into, RemOp);
+
+impl_assign_op!(BitAndAssign, bitand_assign, try_bitand_assign, bitand_into, try_bitand_into, AndOp);
+impl_assign_op!(BitOrAssign, bitor_assign, try_bitor_assign, bitor_into, try_bitor_into, OrOp);
+impl_assign_op!(BitXorAssign, bitxor_assign, try_bitxor_assign, bitxor_into, try_bitxor_into, XorOp);
+impl_assign_op!(ShlAssign, shl_assign, try_shl_assign, shl_into, try_shl_into, ShlOp);
+impl_assign_op!(ShrAssign, shr_assign, try_shr_assign, shr_into, try_shr_into, ShrOp);
+
+macro_rules! impl_cmp {
+    ($fn_id:ident, $try_fn_id:ident, $checked_fn_id:ident, $kernel:ty, $doc:literal) => {
+        impl<T: DType, SL: Shape, B: Backend> Tensor<T, SL, B>
+            where
+                $kernel: BinaryOp<T, T, Output = bool>,
+        {
+            #[doc = $doc]
+            ///
+            /// The operands are broadcast like for the arithmetic operators.
+            pub fn $fn_id<SR: Shape, SO: Shape>(&self, rhs: &Tensor<T, SR, B>) -> Tensor<bool, SO, B>
+                where
+                    SL: BroadcastShape<SR, Output = SO>,
+            {
+                self.binary::<$kernel, T, SR, SO>(rhs)
+            }
+
+            /// Like the method without `try_`, but returns an error instead of panicking.
+            pub fn $try_fn_id<SR: Shape, SO: Shape>(
+                &self,
+                rhs: &Tensor<T, SR, B>,
+            ) -> Result<Tensor<bool, SO, B>, DeeperError>
+                where
+                    SL: BroadcastShape<SR, Output = SO>,
+            {
+                self.try_binary::<$kernel, T, SR, SO>(rhs)
+            }
+
+            /// Like the method without `checked_`, but returns an error instead of panicking if
+            /// the shapes can not be broadcast.
+            pub fn $checked_fn_id<SR: Shape, SO: Shape>(
+                &self,
+                rhs: &Tensor<T, SR, B>,
+            ) -> Result<Tensor<bool, SO, B>, ShapeError>
+                where
+                    SL: BroadcastShape<SR, Output = SO>,
+            {
+                expect_shape(self.try_binary::<$kernel, T, SR, SO>(rhs))
+            }
+        }
+    };
+}
+
+impl_cmp!(eq, try_eq, checked_eq, EqOp, "Elementwise `self == rhs`.");
+impl_cmp!(ne, try_ne, checked_ne, NeOp, "Elementwise `self != rhs`.");
+impl_cmp!(lt, try_lt, checked_lt, LtOp, "Elementwise `self < rhs`.");
+impl_cmp!(le, try_le, checked_le, LeOp, "Elementwise `self <= rhs`.");
+impl_cmp!(gt, try_gt, checked_gt, GtOp, "Elementwise `self > rhs`.");
+impl_cmp!(ge, try_ge, checked_ge, GeOp, "Elementwise `self >= rhs`.");
+
+impl<SC: Shape, B: Backend> Tensor<bool, SC, B> {
+    /// Selects the element of `a` where `self` is `true` and the element of `b` otherwise.
+    ///
+    /// All three operands are broadcast together like for the binary operators.
+    ///
+    /// ```
+    /// use deeper::prelude::*;
+    ///
+    /// let tensor = CpuTensor::from_vec((Dyn::new(4),), vec![-2.0, -1.0, 1.0, 2.0]);
+    /// let zero = CpuTensor::of((), 0.0);
+    /// let relu = tensor.gt(&zero).where_(&tensor, &zero);
+    /// assert_eq!(relu.as_slice(), &[0.0, 0.0, 1.0, 2.0]);
+    /// ```
+    pub fn where_<T, SA, SB, SCA, SO>(
+        &self,
+        a: &Tensor<T, SA, B>,
+        b: &Tensor<T, SB, B>,
+    ) -> Tensor<T, SO, B>
+        where
+            T: DType,
+            SA: Shape,
+            SB: Shape,
+            SCA: Shape,
+            SO: Shape,
+            SC: BroadcastShape<SA, Output = SCA>,
+            SCA: BroadcastShape<SB, Output = SO>,
+    {
+        expect(self.try_where(a, b))
+    }
+
+    /// Like [`Tensor::where_`], but returns an error instead of panicking.
+    pub fn try_where<T, SA, SB, SCA, SO>(
+        &self,
+        a: &Tensor<T, SA, B>,
+        b: &Tensor<T, SB, B>,
+    ) -> Result<Tensor<T, SO, B>, DeeperError>
+        where
+            T: DType,
+            SA: Shape,
+            SB: Shape,
+            SCA: Shape,
+            SO: Shape,
+            SC: BroadcastShape<SA, Output = SCA>,
+            SCA: BroadcastShape<SB, Output = SO>,
+    {
+        let shape = self.shape.try_broadcast(a.shape)?.try_broadcast(b.shape)?;
+        let mut data = B::alloc(shape.size())?;
+        let o_dims = shape.dimensions();
+        let strides = |dims: &[usize], strides: &[usize]| broadcast_strides(dims, strides, &o_dims);
+        let cond_strides = strides(self.shape.dimensions().as_slice(), self.shape.strides().as_slice());
+        let a_strides = strides(a.shape.dimensions().as_slice(), a.shape.strides().as_slice());
+        let b_strides = strides(b.shape.dimensions().as_slice(), b.shape.strides().as_slice());
+
+        B::where_::<T>(
+            &self.data,
+            &cond_strides,
+            &a.data,
+            &a_strides,
+            &b.data,
+            &b_strides,
+            &mut data,
+            &shape.strides(),
+        )?;
+
+        Ok(Tensor { data, shape })
+    }
+
+    /// Like [`Tensor::where_`], but returns an error instead of panicking if the shapes can not
+    /// be broadcast.
+    pub fn checked_where<T, SA, SB, SCA, SO>(
+        &self,
+        a: &Tensor<T, SA, B>,
+        b: &Tensor<T, SB, B>,
+    ) -> Result<Tensor<T, SO, B>, ShapeError>
+        where
+            T: DType,
+            SA: Shape,
+            SB: Shape,
+            SCA: Shape,
+            SO: Shape,
+            SC: BroadcastShape<SA, Output = SCA>,
+            SCA: BroadcastShape<SB, Output = SO>,
+    {
+        expect_shape(self.try_where(a, b))
     }
 }
-unsafe impl<T, N: generic_array::ArrayLength<T>> DeviceCopy for GenericArrayDeviceCopy<T, N>
-    where
-        T: Copy,
-        generic_array::GenericArray<T, N>: Copy,
-{}
 
 #[cfg(test)]
 mod tests {
-    use crate::shape::Cst;
+    use crate::shape::{Cst, Dyn};
     use super::*;
 
     #[test]
+    #[cfg(feature = "cuda")]
     fn test_add() {
         let cpu_tensor_a = CpuTensor::<f32, (Cst<typenum::U3>, Cst<typenum::U1>)>::one((Cst::new(), Cst::new()));
         let cpu_tensor_b = CpuTensor::<f32, (Cst<typenum::U1>, Cst<typenum::U3>)>::one((Cst::new(), Cst::new()));
@@ -270,6 +989,7 @@ mod tests {
     }
 
     #[test]
+    #[cfg(feature = "cuda")]
     fn test_mul() {
         let cpu_tensor_a = CpuTensor::<f32, (Cst<typenum::U3>, Cst<typenum::U1>)>::one((Cst::new(), Cst::new()));
         let cpu_tensor_b = CpuTensor::<f32, (Cst<typenum::U1>, Cst<typenum::U3>)>::of((Cst::new(), Cst::new()), 3.0);
@@ -282,6 +1002,7 @@ mod tests {
     }
 
     #[test]
+    #[cfg(feature = "cuda")]
     fn test_div() {
         let cpu_tensor_a = CpuTensor::<f32, (Cst<typenum::U3>, Cst<typenum::U1>)>::of((Cst::new(), Cst::new()), 4.0);
         let cpu_tensor_b = CpuTensor::<f32, (Cst<typenum::U1>, Cst<typenum::U3>)>::of((Cst::new(), Cst::new()), 2.0);
@@ -294,6 +1015,7 @@ mod tests {
     }
 
     #[test]
+    #[cfg(feature = "cuda")]
     fn test_sub() {
         let cpu_tensor_a = CpuTensor::<f32, (Cst<typenum::U3>, Cst<typenum::U1>)>::one((Cst::new(), Cst::new()));
         let cpu_tensor_b = CpuTensor::<f32, (Cst<typenum::U1>, Cst<typenum::U3>)>::of((Cst::new(), Cst::new()), 3.0);
@@ -304,4 +1026,543 @@ mod tests {
         let cpu_tensor_o = cpu_tensor_o.cpu();
         assert_eq!(cpu_tensor_o.as_slice(), &[-2.0, -2.0, -2.0, -2.0, -2.0, -2.0, -2.0, -2.0, -2.0]);
     }
-}
\ No newline at end of file
+
+    #[test]
+    #[cfg(feature = "cuda")]
+    fn test_matmul() {
+        let cpu_tensor_a = CpuTensor::<f32, (Cst<typenum::U2>, Cst<typenum::U3>)>::one((Cst::new(), Cst::new()));
+        let cpu_tensor_b = CpuTensor::<f32, (Cst<typenum::U3>, Cst<typenum::U2>)>::of((Cst::new(), Cst::new()), 2.0);
+        let cpu_tensor_a = cpu_tensor_a.cuda();
+        let cpu_tensor_b = cpu_tensor_b.cuda();
+
+        let cpu_tensor_o = cpu_tensor_a.matmul(&cpu_tensor_b);
+        let cpu_tensor_o = cpu_tensor_o.cpu();
+        assert_eq!(cpu_tensor_o.as_slice(), &[6.0, 6.0, 6.0, 6.0]);
+    }
+
+    #[test]
+    #[cfg(feature = "cuda")]
+    fn test_permute() {
+        let cpu_tensor_a = CpuTensor::from_fn((Cst::<typenum::U2>::new(), Cst::<typenum::U3>::new()), |idx| (idx[0] * 3 + idx[1]) as f32);
+        let cpu_tensor_a = cpu_tensor_a.cuda();
+
+        let cpu_tensor_o = cpu_tensor_a.transpose();
+        let cpu_tensor_o = cpu_tensor_o.cpu();
+        assert_eq!(cpu_tensor_o.as_slice(), &[0.0, 3.0, 1.0, 4.0, 2.0, 5.0]);
+    }
+
+    #[test]
+    #[cfg(feature = "cuda")]
+    fn test_empty() {
+        let cpu_tensor_a = CpuTensor::<f32, (Dyn, Cst<typenum::U3>)>::from_vec((Dyn::new(0), Cst::new()), vec![]);
+        let cpu_tensor_b = CpuTensor::<f32, (Cst<typenum::U3>, Cst<typenum::U2>)>::one((Cst::new(), Cst::new()));
+        let cpu_tensor_a = cpu_tensor_a.cuda();
+        let cpu_tensor_b = cpu_tensor_b.cuda();
+
+        assert!((&cpu_tensor_a + &cpu_tensor_a).cpu().as_slice().is_empty());
+        assert!(cpu_tensor_a.exp().cpu().as_slice().is_empty());
+        assert!(cpu_tensor_a.transpose().cpu().as_slice().is_empty());
+        assert!(cpu_tensor_a.matmul(&cpu_tensor_b).cpu().as_slice().is_empty());
+    }
+
+    #[test]
+    #[cfg(feature = "cuda")]
+    fn test_where() {
+        let tensor_a = CpuTensor::<f32, (Cst<typenum::U3>, Cst<typenum::U1>)>::from_vec((Cst::new(), Cst::new()), vec![1.0, 2.0, 3.0]);
+        let tensor_b = CpuTensor::<f32, (Cst<typenum::U1>, Cst<typenum::U3>)>::of((Cst::new(), Cst::new()), 2.0);
+        let tensor_a = tensor_a.cuda();
+        let tensor_b = tensor_b.cuda();
+
+        let tensor_o = tensor_a.lt(&tensor_b).where_(&tensor_a, &tensor_b);
+        let tensor_o = tensor_o.cpu();
+        assert_eq!(tensor_o.as_slice(), &[1.0, 1.0, 1.0, 2.0, 2.0, 2.0, 2.0, 2.0, 2.0]);
+    }
+
+    #[test]
+    #[cfg(feature = "cuda")]
+    fn test_add_assign() {
+        let tensor_a = CpuTensor::<f32, (Cst<typenum::U3>, Cst<typenum::U3>)>::one((Cst::new(), Cst::new()));
+        let tensor_b = CpuTensor::<f32, (Cst<typenum::U1>, Cst<typenum::U3>)>::from_vec((Cst::new(), Cst::new()), vec![1.0, 2.0, 3.0]);
+        let mut tensor_a = tensor_a.cuda();
+        let tensor_b = tensor_b.cuda();
+
+        tensor_a += &tensor_b;
+        let tensor_o = tensor_a.cpu();
+        assert_eq!(tensor_o.as_slice(), &[2.0, 3.0, 4.0, 2.0, 3.0, 4.0, 2.0, 3.0, 4.0]);
+    }
+
+    #[test]
+    #[cfg(feature = "cuda")]
+    fn test_owned() {
+        let tensor_a = CpuTensor::<f32, (Cst<typenum::U1>, Cst<typenum::U3>)>::from_vec((Cst::new(), Cst::new()), vec![1.0, 2.0, 3.0]);
+        let tensor_b = CpuTensor::<f32, (Cst<typenum::U2>, Cst<typenum::U3>)>::one((Cst::new(), Cst::new()));
+        let tensor_a = tensor_a.cuda();
+        let tensor_b = tensor_b.cuda();
+
+        let tensor_o = &tensor_a - tensor_b;
+        let tensor_o = (tensor_o + &tensor_a).cpu();
+        assert_eq!(tensor_o.as_slice(), &[1.0, 3.0, 5.0, 1.0, 3.0, 5.0]);
+    }
+
+    #[test]
+    #[cfg(feature = "cuda")]
+    fn test_int() {
+        let tensor_a = CpuTensor::from_vec((Dyn::new(2), Dyn::new(1)), vec![7i64, -7]).cuda();
+        let tensor_b = CpuTensor::from_vec((Dyn::new(3),), vec![1i64, 2, 3]).cuda();
+
+        assert_eq!((&tensor_a % &tensor_b).cpu().as_slice(), &[0, 1, 1, 0, -1, -1]);
+        assert_eq!((&tensor_a >> &tensor_b).cpu().as_slice(), &[3, 1, 0, -4, -2, -1]);
+        assert_eq!(tensor_a.le(&tensor_b).cpu().as_slice(), &[false, false, false, true, true, true]);
+
+        let tensor_c = CpuTensor::from_vec((Dyn::new(2), Dyn::new(3)), vec![3i32, -1, 4, -1, 5, -9]);
+        let tensor_d = CpuTensor::from_vec((Dyn::new(3), Dyn::new(1)), vec![i32::MAX, 1, 1]);
+        let expected = tensor_c.matmul(&tensor_d);
+        let (tensor_c, tensor_d) = (tensor_c.cuda(), tensor_d.cuda());
+        assert_eq!(tensor_c.matmul(&tensor_d).cpu().as_slice(), expected.as_slice());
+        assert_eq!(tensor_c.relu().cpu().as_slice(), &[3, 0, 4, 0, 5, 0]);
+        assert_eq!((!&tensor_c).cpu().as_slice(), &[-4, 0, -5, 0, -6, 8]);
+        assert_eq!((-&tensor_d).cpu().as_slice(), &[i32::MIN + 1, -1, -1]);
+        assert_eq!((-&CpuTensor::of((), i32::MIN).cuda()).cpu().as_slice(), &[i32::MIN]);
+        assert_eq!(tensor_c.argmax_axis::<typenum::U1>().cpu().as_slice(), &[2, 1]);
+        assert_eq!(tensor_c.topk(1).1.cpu().as_slice(), &[2, 1]);
+
+        let mask = tensor_c.gt(&CpuTensor::of((), 0).cuda());
+        assert_eq!(mask.argmin_axis::<typenum::U1>().cpu().as_slice(), &[1, 0]);
+        assert_eq!(mask.lt(&CpuTensor::of((), true).cuda()).cpu().as_slice(), &[false, true, false, true, false, true]);
+    }
+
+    #[test]
+    #[cfg(feature = "cuda")]
+    fn test_half() {
+        use half::bf16;
+
+        let tensor_a = CpuTensor::<bf16, (Dyn, Dyn)>::of((Dyn::new(2), Dyn::new(1000)), bf16::ONE).cuda();
+        let tensor_b = CpuTensor::<bf16, (Dyn, Dyn)>::of((Dyn::new(1000), Dyn::new(3)), bf16::from_f32(0.5)).cuda();
+
+        let tensor_o = tensor_a.matmul(&tensor_b);
+        assert_eq!(tensor_o.cpu().as_slice(), &[bf16::from_f32(500.0); 6]);
+        let tensor_o = (&tensor_a + &tensor_a).sum_axis::<typenum::U1>();
+        assert_eq!(tensor_o.cpu().as_slice(), &[bf16::from_f32(2000.0); 2]);
+
+        let tensor_c = CpuTensor::from_vec((Dyn::new(3),), vec![bf16::from_f32(-1.5), bf16::ZERO, bf16::ONE]).cuda();
+        assert_eq!(tensor_c.abs().cpu().as_slice(), &[bf16::from_f32(1.5), bf16::ZERO, bf16::ONE]);
+        assert_eq!((-&tensor_c).abs().cpu().as_slice(), tensor_c.abs().cpu().as_slice());
+    }
+
+    fn cpu_tensor<S: Shape>(shape: S, data: &[f32]) -> CpuTensor<f32, S> {
+        assert_eq!(shape.size(), data.len());
+        CpuTensor {
+            data: data.to_vec(),
+            shape,
+        }
+    }
+
+    #[test]
+    fn test_cpu_add() {
+        let tensor_a = cpu_tensor((Cst::<typenum::U3>::new(), Cst::<typenum::U1>::new()), &[1.0, 2.0, 3.0]);
+        let tensor_b = cpu_tensor((Cst::<typenum::U1>::new(), Cst::<typenum::U3>::new()), &[1.0, 2.0, 3.0]);
+
+        let tensor_o = &tensor_a + &tensor_b;
+        assert_eq!(tensor_o.as_slice(), &[
+            2.0, 3.0, 4.0,
+            3.0, 4.0, 5.0,
+            4.0, 5.0, 6.0,
+        ]);
+    }
+
+    #[test]
+    fn test_cpu_sub() {
+        let tensor_a = cpu_tensor((Cst::<typenum::U3>::new(), Cst::<typenum::U1>::new()), &[1.0, 2.0, 3.0]);
+        let tensor_b = cpu_tensor((Cst::<typenum::U1>::new(), Cst::<typenum::U2>::new()), &[1.0, 2.0]);
+
+        let tensor_o = &tensor_a - &tensor_b;
+        assert_eq!(tensor_o.as_slice(), &[0.0, -1.0, 1.0, 0.0, 2.0, 1.0]);
+    }
+
+    #[test]
+    fn test_cpu_mul() {
+        let tensor_a = cpu_tensor((Dyn::new(3),), &[1.0, 2.0, 3.0]);
+        let tensor_b = cpu_tensor((Dyn::new(3),), &[4.0, 5.0, 6.0]);
+
+        let tensor_o = &tensor_a * &tensor_b;
+        assert_eq!(tensor_o.as_slice(), &[4.0, 10.0, 18.0]);
+    }
+
+    #[test]
+    fn test_cpu_div() {
+        let tensor_a = cpu_tensor((Cst::<typenum::U2>::new(), Cst::<typenum::U1>::new()), &[4.0, 8.0]);
+        let tensor_b = cpu_tensor((Cst::<typenum::U1>::new(), Cst::<typenum::U2>::new()), &[1.0, 2.0]);
+
+        let tensor_o = &tensor_a / &tensor_b;
+        assert_eq!(tensor_o.as_slice(), &[4.0, 2.0, 8.0, 4.0]);
+    }
+
+    #[test]
+    fn test_cpu_strides() {
+        let tensor_a = cpu_tensor((Cst::<typenum::U2>::new(), Cst::<typenum::U3>::new()), &[1.0, 2.0, 3.0, 4.0, 5.0, 6.0]);
+        let tensor_b = cpu_tensor((Cst::<typenum::U1>::new(), Cst::<typenum::U3>::new()), &[10.0, 20.0, 30.0]);
+
+        let tensor_o = &tensor_a + &tensor_b;
+        assert_eq!(tensor_o.as_slice(), &[11.0, 22.0, 33.0, 14.0, 25.0, 36.0]);
+
+        let tensor_o = &tensor_a * &tensor_a;
+        assert_eq!(tensor_o.as_slice(), &[1.0, 4.0, 9.0, 16.0, 25.0, 36.0]);
+    }
+
+    #[test]
+    fn test_cpu_broadcast_rank() {
+        let tensor_a = cpu_tensor((Cst::<typenum::U2>::new(), Cst::<typenum::U1>::new()), &[1.0, 2.0]);
+        let tensor_b = cpu_tensor((Cst::<typenum::U3>::new(),), &[10.0, 20.0, 30.0]);
+        let tensor_o = &tensor_a + &tensor_b;
+        assert_eq!(tensor_o.as_slice(), &[11.0, 21.0, 31.0, 12.0, 22.0, 32.0]);
+
+        let tensor_s = cpu_tensor((), &[2.0]);
+        let tensor_o = &tensor_s * &tensor_b;
+        assert_eq!(tensor_o.as_slice(), &[20.0, 40.0, 60.0]);
+    }
+
+    #[test]
+    fn test_cpu_checked() {
+        let tensor_a = cpu_tensor((Dyn::new(2), Dyn::new(3)), &[1.0, 2.0, 3.0, 4.0, 5.0, 6.0]);
+        let tensor_b = cpu_tensor((Dyn::new(2),), &[1.0, 2.0]);
+        let tensor_c = cpu_tensor((Dyn::new(3),), &[1.0, 2.0, 3.0]);
+
+        assert_eq!(
+            tensor_a.checked_add(&tensor_b).err(),
+            Some(ShapeError::Broadcast { axis: 1, lhs: 3, rhs: 2 }),
+        );
+        let tensor_o = tensor_a.checked_sub(&tensor_c).unwrap();
+        assert_eq!(tensor_o.as_slice(), &[0.0, 0.0, 0.0, 3.0, 3.0, 3.0]);
+    }
+
+    #[test]
+    fn test_cpu_try() {
+        let tensor_a = cpu_tensor((Dyn::new(2),), &[1.0, 2.0]);
+        let tensor_b = cpu_tensor((Dyn::new(3),), &[1.0, 2.0, 3.0]);
+
+        assert_eq!(
+            tensor_a.try_mul(&tensor_b).err(),
+            Some(DeeperError::Shape(ShapeError::Broadcast { axis: 0, lhs: 2, rhs: 3 })),
+        );
+        let tensor_o = tensor_b.try_div(&tensor_b).unwrap();
+        assert_eq!(tensor_o.try_cpu().unwrap().as_slice(), &[1.0, 1.0, 1.0]);
+    }
+
+    #[test]
+    #[should_panic(expected = "can not broadcast axis 0 of size 3 with size 4")]
+    fn test_cpu_broadcast_mismatch() {
+        let tensor_a = cpu_tensor((Dyn::new(3),), &[1.0, 2.0, 3.0]);
+        let tensor_b = cpu_tensor((Dyn::new(4),), &[1.0, 2.0, 3.0, 4.0]);
+
+        let _ = &tensor_a + &tensor_b;
+    }
+
+    #[test]
+    fn test_cpu_reshape() {
+        let tensor_a = cpu_tensor((Cst::<typenum::U2>::new(), Cst::<typenum::U3>::new()), &[1.0, 2.0, 3.0, 4.0, 5.0, 6.0]);
+
+        let tensor_o = tensor_a.reshape::<(Cst<typenum::U3>, Cst<typenum::U1>, Cst<typenum::U2>)>();
+        assert_eq!(tensor_o.shape().dimensions().as_slice(), &[3, 1, 2]);
+        assert_eq!(tensor_o.as_slice(), &[1.0, 2.0, 3.0, 4.0, 5.0, 6.0]);
+
+        let tensor_o = tensor_o.reshape_to((Dyn::new(6),));
+        assert_eq!(tensor_o.as_slice(), &[1.0, 2.0, 3.0, 4.0, 5.0, 6.0]);
+        assert_eq!(
+            tensor_o.try_reshape_to((Dyn::new(4),)).err(),
+            Some(DeeperError::Shape(ShapeError::Size { expected: 4, actual: 6 })),
+        );
+    }
+
+    #[test]
+    fn test_cpu_permute() {
+        let tensor_a = CpuTensor::from_fn(
+            (Dyn::new(2), Cst::<typenum::U3>::new(), Dyn::new(4)),
+            |idx| (idx[0] * 100 + idx[1] * 10 + idx[2]) as f32,
+        );
+
+        let tensor_o = tensor_a.permute::<(typenum::U2, typenum::U0, typenum::U1)>();
+        assert_eq!(tensor_o.shape().dimensions().as_slice(), &[4, 2, 3]);
+        assert_eq!(tensor_o[(3, 1, 2)], 123.0);
+        assert_eq!(&tensor_o.as_slice()[..6], &[0.0, 10.0, 20.0, 100.0, 110.0, 120.0]);
+
+        let tensor_o = tensor_a.swap_axes::<typenum::U0, typenum::U2>();
+        assert_eq!(tensor_o.shape().dimensions().as_slice(), &[4, 3, 2]);
+        assert_eq!(tensor_o[(3, 1, 0)], 13.0);
+        assert_eq!(tensor_o[(2, 0, 1)], 102.0);
+
+        let tensor_b = cpu_tensor((Dyn::new(2), Dyn::new(3)), &[1.0, 2.0, 3.0, 4.0, 5.0, 6.0]);
+        let tensor_o = tensor_b.transpose();
+        assert_eq!(tensor_o.shape().dimensions().as_slice(), &[3, 2]);
+        assert_eq!(tensor_o.as_slice(), &[1.0, 4.0, 2.0, 5.0, 3.0, 6.0]);
+    }
+
+    #[test]
+    fn test_cpu_matmul() {
+        let tensor_a = cpu_tensor((Cst::<typenum::U2>::new(), Cst::<typenum::U3>::new()), &[1.0, 2.0, 3.0, 4.0, 5.0, 6.0]);
+        let tensor_b = cpu_tensor((Cst::<typenum::U3>::new(), Cst::<typenum::U2>::new()), &[1.0, 2.0, 3.0, 4.0, 5.0, 6.0]);
+
+        let tensor_o = tensor_a.matmul(&tensor_b);
+        assert_eq!(tensor_o.as_slice(), &[22.0, 28.0, 49.0, 64.0]);
+
+        // the batch axis of `c` is broadcast against the single matrix `b`
+        let tensor_c = cpu_tensor((Dyn::new(2), Dyn::new(1), Dyn::new(3)), &[1.0, 0.0, 0.0, 0.0, 0.0, 1.0]);
+        let tensor_o = tensor_c.matmul(&tensor_b);
+        assert_eq!(tensor_o.shape().dimensions().as_slice(), &[2, 1, 2]);
+        assert_eq!(tensor_o.as_slice(), &[1.0, 2.0, 5.0, 6.0]);
+    }
+
+    #[test]
+    fn test_cpu_matmul_blocked() {
+        // larger than one tile in every direction
+        let (m, k, n) = (70, 130, 65);
+        let tensor_a = CpuTensor::from_fn((Dyn::new(m), Dyn::new(k)), |idx| (idx[0] + idx[1]) as f64);
+        let tensor_b = CpuTensor::from_fn((Dyn::new(k), Dyn::new(n)), |idx| idx[0] as f64 - idx[1] as f64);
+
+        let tensor_o = tensor_a.matmul(&tensor_b);
+        for i in [0, 33, 64, 69] {
+            for j in [0, 31, 64] {
+                let expected: f64 = (0..k).map(|kk| ((i + kk) as f64) * (kk as f64 - j as f64)).sum();
+                assert_eq!(tensor_o.as_slice()[i * n + j], expected);
+            }
+        }
+    }
+
+    #[test]
+    fn test_cpu_matmul_mismatch() {
+        let tensor_a = cpu_tensor((Dyn::new(2), Dyn::new(3)), &[1.0, 2.0, 3.0, 4.0, 5.0, 6.0]);
+        let tensor_b = cpu_tensor((Dyn::new(2), Dyn::new(3)), &[1.0, 2.0, 3.0, 4.0, 5.0, 6.0]);
+
+        assert_eq!(
+            tensor_a.checked_matmul(&tensor_b).err(),
+            Some(ShapeError::MatMul { lhs: 3, rhs: 2 }),
+        );
+    }
+
+    #[test]
+    fn test_from_vec() {
+        let tensor = CpuTensor::from_vec((Dyn::new(2), Cst::<typenum::U2>::new()), vec![1.0, 2.0, 3.0, 4.0]);
+        assert_eq!(tensor.as_slice(), &[1.0, 2.0, 3.0, 4.0]);
+        assert_eq!(
+            CpuTensor::try_from_vec((Dyn::new(3),), vec![1.0, 2.0]).err(),
+            Some(DeeperError::Shape(ShapeError::Size { expected: 3, actual: 2 })),
+        );
+    }
+
+    #[test]
+    fn test_from_fn() {
+        let tensor = CpuTensor::from_fn((Dyn::new(2), Dyn::new(1), Dyn::new(3)), |idx| {
+            (idx[0] * 100 + idx[1] * 10 + idx[2]) as f64
+        });
+        assert_eq!(tensor.as_slice(), &[0.0, 1.0, 2.0, 100.0, 101.0, 102.0]);
+
+        let tensor = CpuTensor::from_fn((), |_| 7.0);
+        assert_eq!(tensor.as_slice(), &[7.0]);
+    }
+
+    #[test]
+    fn test_from_iter() {
+        let tensor: CpuTensor<f32, (Cst<typenum::U2>, Cst<typenum::U2>)> = (0..4).map(|i| i as f32).collect();
+        assert_eq!(tensor.as_slice(), &[0.0, 1.0, 2.0, 3.0]);
+
+        let tensor: CpuTensor<f32, (Dyn,)> = (0..5).map(|i| i as f32).collect();
+        assert_eq!(tensor.shape().dimensions().as_slice(), &[5]);
+
+        assert_eq!(
+            CpuTensor::try_from_shape_iter((Dyn::new(2),), [1.0, 2.0, 3.0]).err(),
+            Some(DeeperError::Shape(ShapeError::Size { expected: 2, actual: 3 })),
+        );
+        assert_eq!(
+            CpuTensor::try_from_shape_iter((Dyn::new(2),), (0..7).map(|i| i as f32)).err(),
+            Some(DeeperError::Shape(ShapeError::Size { expected: 2, actual: 7 })),
+        );
+        assert_eq!(
+            CpuTensor::try_from_shape_iter((Dyn::new(2),), [1.0f32]).err(),
+            Some(DeeperError::Shape(ShapeError::Size { expected: 2, actual: 1 })),
+        );
+    }
+
+    #[test]
+    fn test_tensor_literal() {
+        let tensor = crate::tensor![[1.0, 2.0], [3.0, 4.0], [5.0, 6.0]];
+        let _: &CpuTensor<f64, (Cst<typenum::U3>, Cst<typenum::U2>)> = &tensor;
+        assert_eq!(tensor.as_slice(), &[1.0, 2.0, 3.0, 4.0, 5.0, 6.0]);
+
+        let tensor = crate::tensor![[[true], [false]]];
+        assert_eq!(tensor.shape().dimensions().as_slice(), &[1, 2, 1]);
+        assert_eq!(tensor.as_slice(), &[true, false]);
+    }
+
+    #[test]
+    fn test_cpu_bool() {
+        let tensor_a = CpuTensor::<bool, (Cst<typenum::U2>, Cst<typenum::U1>)> {
+            data: vec![true, false],
+            shape: (Cst::new(), Cst::new()),
+        };
+        let tensor_b = CpuTensor::<bool, (Cst<typenum::U1>, Cst<typenum::U2>)> {
+            data: vec![true, false],
+            shape: (Cst::new(), Cst::new()),
+        };
+
+        assert_eq!((&tensor_a & &tensor_b).as_slice(), &[true, false, false, false]);
+        assert_eq!((&tensor_a | &tensor_b).as_slice(), &[true, true, true, false]);
+    }
+
+    #[test]
+    fn test_cpu_half() {
+        use half::f16;
+
+        let tensor_a = CpuTensor::<f16, (Dyn, Dyn)>::of((Dyn::new(2), Dyn::new(4096)), f16::ONE);
+        let tensor_b = CpuTensor::<f16, (Dyn, Dyn)>::of((Dyn::new(4096), Dyn::new(1)), f16::ONE);
+        assert_eq!(tensor_a.matmul(&tensor_b).as_slice(), &[f16::from_f32(4096.0); 2]);
+
+        let tensor_c = CpuTensor::from_vec((Dyn::new(2),), vec![f16::from_f32(1.5), f16::from_f32(-2.0)]);
+        let tensor_o = &(&tensor_c * &tensor_c) - &CpuTensor::of((), f16::ONE);
+        assert_eq!(tensor_o.as_slice(), &[f16::from_f32(1.25), f16::from_f32(3.0)]);
+        assert_eq!(tensor_c.lt(&tensor_o).as_slice(), &[false, true]);
+        assert_eq!((f16::from_f32(2.0) * &tensor_c).relu().as_slice(), &[f16::from_f32(3.0), f16::ZERO]);
+        assert_eq!(tensor_c.abs().as_slice(), &[f16::from_f32(1.5), f16::from_f32(2.0)]);
+    }
+
+    #[test]
+    fn test_cpu_int() {
+        let tensor_a = CpuTensor::from_vec((Dyn::new(2), Dyn::new(1)), vec![7i32, -7]);
+        let tensor_b = CpuTensor::from_vec((Dyn::new(3),), vec![1i32, 2, 3]);
+
+        assert_eq!((&tensor_a / &tensor_b).as_slice(), &[7, 3, 2, -7, -3, -2]);
+        assert_eq!((&tensor_a % &tensor_b).as_slice(), &[0, 1, 1, 0, -1, -1]);
+        assert_eq!((&tensor_a << &tensor_b).as_slice(), &[14, 28, 56, -14, -28, -56]);
+        assert_eq!((&tensor_a >> &tensor_b).as_slice(), &[3, 1, 0, -4, -2, -1]);
+        assert_eq!((&tensor_a ^ &tensor_b).as_slice(), &[6, 5, 4, -8, -5, -6]);
+        assert_eq!(tensor_a.gt(&tensor_b).as_slice(), &[true, true, true, false, false, false]);
+
+        let mut tensor_c = CpuTensor::from_vec((Dyn::new(3),), vec![0xf0u8, 0x0f, 0xff]);
+        tensor_c &= &CpuTensor::from_vec((), vec![0x3cu8]);
+        assert_eq!(tensor_c.as_slice(), &[0x30, 0x0c, 0x3c]);
+        assert_eq!((&tensor_c | &CpuTensor::from_vec((), vec![1u8])).as_slice(), &[0x31, 0x0d, 0x3d]);
+        assert_eq!((2u8 * &tensor_c).as_slice(), &[0x60, 0x18, 0x78]);
+
+        let tensor_d = CpuTensor::from_vec((Dyn::new(2), Dyn::new(3)), vec![3i32, -1, 4, -1, 5, -9]);
+        let tensor_e = CpuTensor::from_vec((Dyn::new(3), Dyn::new(1)), vec![i32::MAX, 1, 1]);
+        assert_eq!(tensor_d.matmul(&tensor_e).as_slice(), &[i32::MIN, i32::MAX - 2]);
+        assert_eq!(tensor_d.relu().as_slice(), &[3, 0, 4, 0, 5, 0]);
+        assert_eq!((!&tensor_d).as_slice(), &[-4, 0, -5, 0, -6, 8]);
+        assert_eq!(tensor_d.argmax_axis::<typenum::U1>().as_slice(), &[2, 1]);
+        assert_eq!(tensor_d.topk(2).0.as_slice(), &[4, 3, 5, -1]);
+    }
+
+    #[test]
+    fn test_cpu_int_wrapping() {
+        let tensor_a = CpuTensor::from_vec((Dyn::new(3),), vec![0xffu8, 0x80, 3]);
+        assert_eq!((2u8 * &tensor_a).as_slice(), &[0xfe, 0x00, 6]);
+        assert_eq!((&tensor_a + 1).as_slice(), &[0x00, 0x81, 4]);
+        assert_eq!((0 - &tensor_a).as_slice(), &[0x01, 0x80, 0xfd]);
+
+        let tensor_b = CpuTensor::from_vec((Dyn::new(4),), vec![i32::MIN, i32::MAX, 7, -7]);
+        let tensor_c = CpuTensor::from_vec((Dyn::new(4),), vec![-1i32, 1, 0, 0]);
+        assert_eq!((&tensor_b / &tensor_c).as_slice(), &[i32::MIN, i32::MAX, 0, 0]);
+        assert_eq!((&tensor_b % &tensor_c).as_slice(), &[0, 0, 7, -7]);
+        assert_eq!((&tensor_b + &tensor_c).as_slice(), &[i32::MAX, i32::MIN, 7, -7]);
+        assert_eq!((&tensor_b / 0).as_slice(), &[0; 4]);
+        assert_eq!((1 / &tensor_c).as_slice(), &[-1, 1, 0, 0]);
+        assert_eq!(tensor_b.abs().as_slice(), &[i32::MIN, i32::MAX, 7, 7]);
+        assert_eq!((-&tensor_b).as_slice(), &[i32::MIN, -i32::MAX, -7, 7]);
+
+        let shift = CpuTensor::from_vec((), vec![33i32]);
+        assert_eq!((&tensor_b << &shift).as_slice(), &[0, -2, 14, -14]);
+        assert_eq!((&tensor_b >> &shift).as_slice(), &[i32::MIN >> 1, i32::MAX >> 1, 3, -4]);
+    }
+
+    #[test]
+    fn test_cpu_assign() {
+        let mut tensor_a = cpu_tensor((Dyn::new(2), Dyn::new(3)), &[1.0, 2.0, 3.0, 4.0, 5.0, 6.0]);
+        let tensor_b = cpu_tensor((Dyn::new(3),), &[1.0, 2.0, 3.0]);
+
+        tensor_a += &tensor_b;
+        assert_eq!(tensor_a.as_slice(), &[2.0, 4.0, 6.0, 5.0, 7.0, 9.0]);
+        tensor_a *= &cpu_tensor((), &[2.0]);
+        assert_eq!(tensor_a.as_slice(), &[4.0, 8.0, 12.0, 10.0, 14.0, 18.0]);
+        tensor_a /= &cpu_tensor((Dyn::new(2), Dyn::new(1)), &[2.0, 1.0]);
+        assert_eq!(tensor_a.as_slice(), &[2.0, 4.0, 6.0, 10.0, 14.0, 18.0]);
+
+        // the left hand side would have to grow to hold the result
+        let mut tensor_c = cpu_tensor((Dyn::new(1),), &[1.0]);
+        assert_eq!(
+            tensor_c.try_sub_assign(&tensor_b).err(),
+            Some(DeeperError::Shape(ShapeError::Broadcast { axis: 0, lhs: 1, rhs: 3 })),
+        );
+    }
+
+    #[test]
+    fn test_cpu_owned() {
+        let tensor_a = cpu_tensor((Dyn::new(2), Dyn::new(1)), &[1.0, 2.0]);
+        let tensor_b = cpu_tensor((Dyn::new(3),), &[1.0, 2.0, 3.0]);
+        let tensor_c = cpu_tensor((Dyn::new(2), Dyn::new(3)), &[1.0; 6]);
+
+        // the sum is allocated, every later result reuses its buffer
+        let tensor_o = (&tensor_a + &tensor_b) * cpu_tensor((), &[2.0]) - &tensor_c;
+        let ptr = tensor_o.as_slice().as_ptr();
+        assert_eq!(tensor_o.as_slice(), &[3.0, 5.0, 7.0, 5.0, 7.0, 9.0]);
+
+        let tensor_o = &tensor_c - tensor_o;
+        assert_eq!(tensor_o.as_slice().as_ptr(), ptr);
+        assert_eq!(tensor_o.as_slice(), &[-2.0, -4.0, -6.0, -4.0, -6.0, -8.0]);
+
+        let tensor_o = tensor_a / tensor_o;
+        assert_eq!(tensor_o.as_slice().as_ptr(), ptr);
+        assert_eq!(tensor_o.as_slice(), &[-0.5, -0.25, 1.0 / -6.0, -0.5, 2.0 / -6.0, -0.25]);
+    }
+
+    #[test]
+    fn test_cpu_into() {
+        let tensor_a = cpu_tensor((Dyn::new(2), Dyn::new(1)), &[1.0, 2.0]);
+        let tensor_b = cpu_tensor((Dyn::new(3),), &[1.0, 2.0, 3.0]);
+        let mut tensor_o = cpu_tensor((Dyn::new(2), Dyn::new(3)), &[0.0; 6]);
+
+        tensor_a.mul_into(&tensor_b, &mut tensor_o);
+        assert_eq!(tensor_o.as_slice(), &[1.0, 2.0, 3.0, 2.0, 4.0, 6.0]);
+
+        let mut tensor_o = cpu_tensor((Dyn::new(2), Dyn::new(2)), &[0.0; 4]);
+        assert_eq!(
+            tensor_a.try_add_into(&tensor_b, &mut tensor_o).err(),
+            Some(DeeperError::Shape(ShapeError::Broadcast { axis: 1, lhs: 2, rhs: 3 })),
+        );
+    }
+
+    #[test]
+    fn test_cpu_where() {
+        let cond = CpuTensor::from_vec((Dyn::new(2), Cst::<typenum::U1>::new()), vec![true, false]);
+        let tensor_a = cpu_tensor((Dyn::new(3),), &[1.0, 2.0, 3.0]);
+        let tensor_b = cpu_tensor((), &[0.0]);
+
+        let tensor_o = cond.where_(&tensor_a, &tensor_b);
+        let _: &CpuTensor<f32, (Dyn, Dyn)> = &tensor_o;
+        assert_eq!(tensor_o.as_slice(), &[1.0, 2.0, 3.0, 0.0, 0.0, 0.0]);
+
+        let tensor_c = cpu_tensor((Dyn::new(2),), &[1.0, 2.0]);
+        assert_eq!(
+            cond.checked_where(&tensor_a, &tensor_c).err(),
+            Some(ShapeError::Broadcast { axis: 1, lhs: 3, rhs: 2 }),
+        );
+    }
+
+    #[test]
+    fn test_cpu_compare() {
+        let tensor_a = cpu_tensor((Dyn::new(2), Dyn::new(1)), &[1.0, 2.0]);
+        let tensor_b = cpu_tensor((Dyn::new(3),), &[1.0, 2.0, 3.0]);
+
+        let tensor_o = tensor_a.lt(&tensor_b);
+        let _: &CpuTensor<bool, (Dyn, Dyn)> = &tensor_o;
+        assert_eq!(tensor_o.as_slice(), &[false, true, true, false, false, true]);
+        assert_eq!(tensor_a.eq(&tensor_b).as_slice(), &[true, false, false, false, true, false]);
+        assert_eq!(tensor_a.ne(&tensor_b).as_slice(), &[false, true, true, true, false, true]);
+        assert_eq!(tensor_a.le(&tensor_b).as_slice(), &[true, true, true, false, true, true]);
+        assert_eq!(tensor_a.gt(&tensor_b).as_slice(), &[false, false, false, true, false, false]);
+        assert_eq!(tensor_a.ge(&tensor_b).as_slice(), &[true, false, false, true, true, false]);
+
+        let tensor_c = cpu_tensor((Dyn::new(2),), &[1.0, 2.0]);
+        assert_eq!(
+            tensor_b.checked_eq(&tensor_c).err(),
+            Some(ShapeError::Broadcast { axis: 0, lhs: 3, rhs: 2 }),
+        );
+    }
+}
@@ -0,0 +1,291 @@
+use std::ops::{Add, Div, Mul, Neg, Not, Sub};
+use half::{bf16, f16};
+use crate::backend::{Backend, DType};
+use crate::backend::ops::{
+    AbsOp, AddScalarOp, Cast, CastOp, CosOp, DivScalarOp, ExpOp, GeluOp, LnOp, MulScalarOp, NegOp, NotOp, PowfOp,
+    RDivScalarOp, RSubScalarOp, ReluOp, RsqrtOp, SigmoidOp, SinOp, SqrtOp, SubScalarOp, TanhOp,
+    UnaryOp,
+};
+use crate::error::DeeperError;
+use crate::shape::Shape;
+use crate::tensor::{expect, Tensor};
+
+impl<T: DType, S: Shape, B: Backend> Tensor<T, S, B> {
+    /// Applies `op` to every element, the shape is unchanged.
+    fn try_unary<Op: UnaryOp<T>>(&self, op: Op) -> Result<Tensor<Op::Output, S, B>, DeeperError> {
+        let mut data = B::alloc(self.shape.size())?;
+        B::unary(&op, &self.data, &mut data)?;
+
+        Ok(Tensor { data, shape: self.shape })
+    }
+
+    /// Raises every element to the power of `exponent`.
+    ///
+    /// ```
+    /// use deeper::prelude::*;
+    ///
+    /// let tensor = CpuTensor::from_vec((Dyn::new(3),), vec![1.0f32, 4.0, 9.0]);
+    /// assert_eq!(tensor.powf(0.5).as_slice(), &[1.0, 2.0, 3.0]);
+    /// ```
+    pub fn powf(&self, exponent: T) -> Tensor<T, S, B>
+        where
+            PowfOp<T>: UnaryOp<T, Output = T>,
+    {
+        expect(self.try_powf(exponent))
+    }
+
+    /// Like [`Tensor::powf`], but returns an error instead of panicking.
+    pub fn try_powf(&self, exponent: T) -> Result<Tensor<T, S, B>, DeeperError>
+        where
+            PowfOp<T>: UnaryOp<T, Output = T>,
+    {
+        self.try_unary(PowfOp(exponent))
+    }
+
+    /// Converts every element to `U`, the shape is unchanged. See [`Cast`] for the rounding and
+    /// saturation rules.
+    ///
+    /// ```
+    /// use deeper::prelude::*;
+    ///
+    /// let tensor = CpuTensor::from_vec((Dyn::new(3),), vec![-1.5f32, 2.7, 300.0]);
+    /// assert_eq!(tensor.cast::<u8>().as_slice(), &[0, 2, 255]);
+    /// ```
+    pub fn cast<U: DType>(&self) -> Tensor<U, S, B>
+        where
+            T: Cast<U>,
+    {
+        expect(self.try_cast())
+    }
+
+    /// Like [`Tensor::cast`], but returns an error instead of panicking.
+    pub fn try_cast<U: DType>(&self) -> Result<Tensor<U, S, B>, DeeperError>
+        where
+            T: Cast<U>,
+    {
+        self.try_unary(CastOp(std::marker::PhantomData))
+    }
+}
+
+macro_rules! impl_unary {
+    ($kernel:ident, $fn_id:ident, $try_fn_id:ident, $doc:literal) => {
+        impl<T: DType, S: Shape, B: Backend> Tensor<T, S, B>
+            where
+                $kernel: UnaryOp<T>,
+        {
+            #[doc = $doc]
+            pub fn $fn_id(&self) -> Tensor<<$kernel as UnaryOp<T>>::Output, S, B> {
+                expect(self.$try_fn_id())
+            }
+
+            /// Like the method without `try_`, but returns an error instead of panicking.
+            pub fn $try_fn_id(
+                &self,
+            ) -> Result<Tensor<<$kernel as UnaryOp<T>>::Output, S, B>, DeeperError> {
+                self.try_unary($kernel)
+            }
+        }
+    };
+}
+
+impl_unary!(ExpOp, exp, try_exp, "Elementwise `e^x`.");
+impl_unary!(LnOp, ln, try_ln, "Elementwise natural logarithm.");
+impl_unary!(SqrtOp, sqrt, try_sqrt, "Elementwise square root.");
+impl_unary!(RsqrtOp, rsqrt, try_rsqrt, "Elementwise reciprocal of the square root.");
+impl_unary!(SinOp, sin, try_sin, "Elementwise sine.");
+impl_unary!(CosOp, cos, try_cos, "Elementwise cosine.");
+impl_unary!(TanhOp, tanh, try_tanh, "Elementwise hyperbolic tangent.");
+impl_unary!(SigmoidOp, sigmoid, try_sigmoid, "Elementwise logistic function `1 / (1 + e^-x)`.");
+impl_unary!(ReluOp, relu, try_relu, "Elementwise `max(x, 0)`.");
+impl_unary!(GeluOp, gelu, try_gelu, "Elementwise GELU, using the tanh approximation.");
+impl_unary!(AbsOp, abs, try_abs, "Elementwise absolute value.");
+impl_unary!(NegOp, neg, try_neg, "Elementwise negation, also available as the `-` operator.");
+
+impl<T: DType, S: Shape, B: Backend> Neg for &Tensor<T, S, B>
+    where
+        NegOp: UnaryOp<T>,
+{
+    type Output = Tensor<<NegOp as UnaryOp<T>>::Output, S, B>;
+
+    fn neg(self) -> Self::Output {
+        expect(self.try_neg())
+    }
+}
+
+/// Logical negation of `bool` and bitwise negation of integer elements.
+impl<T: DType, S: Shape, B: Backend> Not for &Tensor<T, S, B>
+    where
+        NotOp: UnaryOp<T>,
+{
+    type Output = Tensor<<NotOp as UnaryOp<T>>::Output, S, B>;
+
+    fn not(self) -> Self::Output {
+        expect(self.try_unary(NotOp))
+    }
+}
+
+macro_rules! impl_scalar_op {
+    ($op_ty:ident, $fn_id:ident, $try_fn_id:ident, $kernel:ident) => {
+        /// Combines every element with the scalar `rhs`, like broadcasting against a `()` shaped
+        /// tensor but without allocating one.
+        impl<T: DType, S: Shape, B: Backend> $op_ty<T> for &Tensor<T, S, B>
+            where
+                $kernel<T>: UnaryOp<T, Output = T>,
+        {
+            type Output = Tensor<T, S, B>;
+
+            fn $fn_id(self, rhs: T) -> Self::Output {
+                expect(self.$try_fn_id(rhs))
+            }
+        }
+
+        impl<T: DType, S: Shape, B: Backend> Tensor<T, S, B>
+            where
+                $kernel<T>: UnaryOp<T, Output = T>,
+        {
+            /// Like the operator with a scalar `rhs`, but returns an error instead of panicking.
+            pub fn $try_fn_id(&self, rhs: T) -> Result<Tensor<T, S, B>, DeeperError> {
+                self.try_unary($kernel(rhs))
+            }
+        }
+    };
+}
+
+impl_scalar_op!(Add, add, try_add_scalar, AddScalarOp);
+impl_scalar_op!(Sub, sub, try_sub_scalar, SubScalarOp);
+impl_scalar_op!(Mul, mul, try_mul_scalar, MulScalarOp);
+impl_scalar_op!(Div, div, try_div_scalar, DivScalarOp);
+
+/// Scalars on the left hand side, the impls have to name the element type.
+macro_rules! impl_scalar_lhs {
+    ($($ty:ty),*) => {
+        $(
+            impl<S: Shape, B: Backend> Add<&Tensor<$ty, S, B>> for $ty {
+                type Output = Tensor<$ty, S, B>;
+
+                fn add(self, rhs: &Tensor<$ty, S, B>) -> Self::Output {
+                    expect(rhs.try_unary(AddScalarOp(self)))
+                }
+            }
+
+            impl<S: Shape, B: Backend> Sub<&Tensor<$ty, S, B>> for $ty {
+                type Output = Tensor<$ty, S, B>;
+
+                fn sub(self, rhs: &Tensor<$ty, S, B>) -> Self::Output {
+                    expect(rhs.try_unary(RSubScalarOp(self)))
+                }
+            }
+
+            impl<S: Shape, B: Backend> Mul<&Tensor<$ty, S, B>> for $ty {
+                type Output = Tensor<$ty, S, B>;
+
+                fn mul(self, rhs: &Tensor<$ty, S, B>) -> Self::Output {
+                    expect(rhs.try_unary(MulScalarOp(self)))
+                }
+            }
+
+            impl<S: Shape, B: Backend> Div<&Tensor<$ty, S, B>> for $ty {
+                type Output = Tensor<$ty, S, B>;
+
+                fn div(self, rhs: &Tensor<$ty, S, B>) -> Self::Output {
+                    expect(rhs.try_unary(RDivScalarOp(self)))
+                }
+            }
+        )*
+    };
+}
+
+impl_scalar_lhs!(f32, f64, f16, bf16, i8, i16, i32, i64, u8, u32, u64);
+
+#[cfg(test)]
+mod tests {
+    use crate::shape::{Cst, Dyn};
+    use crate::tensor::CpuTensor;
+
+    fn assert_close(actual: &[f32], expected: &[f32]) {
+        assert_eq!(actual.len(), expected.len());
+        for (a, e) in actual.iter().zip(expected) {
+            assert!((a - e).abs() < 1e-5, "{actual:?} != {expected:?}");
+        }
+    }
+
+    #[test]
+    fn test_unary() {
+        let tensor = CpuTensor::from_vec((Dyn::new(2), Cst::<typenum::U2>::new()), vec![-1.0f32, 0.0, 1.0, 4.0]);
+
+        let tensor_o = -&tensor;
+        let _: &CpuTensor<f32, (Dyn, Cst<typenum::U2>)> = &tensor_o;
+        assert_eq!(tensor_o.as_slice(), &[1.0, -0.0, -1.0, -4.0]);
+        assert_eq!(tensor.abs().as_slice(), &[1.0, 0.0, 1.0, 4.0]);
+        assert_eq!(tensor.relu().as_slice(), &[0.0, 0.0, 1.0, 4.0]);
+        assert_close(tensor.abs().sqrt().as_slice(), &[1.0, 0.0, 1.0, 2.0]);
+        assert_close(tensor.exp().ln().as_slice(), &[-1.0, 0.0, 1.0, 4.0]);
+        assert_close(tensor.sigmoid().as_slice(), &[0.268941, 0.5, 0.731059, 0.982014]);
+        assert_close(tensor.tanh().as_slice(), &[-0.761594, 0.0, 0.761594, 0.999329]);
+        assert_close(tensor.gelu().as_slice(), &[-0.158808, 0.0, 0.841192, 3.99993]);
+        assert_close(tensor.powf(2.0).as_slice(), &[1.0, 0.0, 1.0, 16.0]);
+        assert_close(tensor.sin().as_slice(), &[-0.841471, 0.0, 0.841471, -0.756802]);
+        assert_close(tensor.cos().as_slice(), &[0.540302, 1.0, 0.540302, -0.653644]);
+        assert_eq!(tensor.rsqrt().as_slice()[3], 0.5);
+
+        let mask = tensor.gt(&CpuTensor::of((), 0.0));
+        assert_eq!((!&mask).as_slice(), &[true, true, false, false]);
+    }
+
+    #[test]
+    fn test_scalar() {
+        let tensor = CpuTensor::from_vec((Dyn::new(3),), vec![1.0f32, 2.0, 4.0]);
+
+        assert_eq!((&tensor + 2.0).as_slice(), &[3.0, 4.0, 6.0]);
+        assert_eq!((&tensor - 1.0).as_slice(), &[0.0, 1.0, 3.0]);
+        assert_eq!((&tensor * 0.5).as_slice(), &[0.5, 1.0, 2.0]);
+        assert_eq!((&tensor / 2.0).as_slice(), &[0.5, 1.0, 2.0]);
+        assert_eq!((1.0 + &tensor).as_slice(), &[2.0, 3.0, 5.0]);
+        assert_eq!((1.0 - &tensor).as_slice(), &[0.0, -1.0, -3.0]);
+        assert_eq!((2.0 * &tensor).as_slice(), &[2.0, 4.0, 8.0]);
+        assert_eq!((4.0 / &tensor).as_slice(), &[4.0, 2.0, 1.0]);
+        assert_eq!(tensor.try_add_scalar(1.0).unwrap().as_slice(), &[2.0, 3.0, 5.0]);
+    }
+
+    #[test]
+    fn test_cast() {
+        let tensor = CpuTensor::from_vec((Dyn::new(2), Cst::<typenum::U3>::new()), vec![-2.5f32, -0.5, 0.0, 1.7, 1e10, f32::NAN]);
+
+        let tensor_o = tensor.cast::<i32>();
+        let _: &CpuTensor<i32, (Dyn, Cst<typenum::U3>)> = &tensor_o;
+        assert_eq!(tensor_o.as_slice(), &[-2, 0, 0, 1, i32::MAX, 0]);
+        assert_eq!(tensor.cast::<u8>().as_slice(), &[0, 0, 0, 1, 255, 0]);
+        assert_eq!(tensor.cast::<bool>().as_slice(), &[true, true, false, true, true, true]);
+        assert_eq!(tensor.cast::<f64>().as_slice()[3], 1.7f32 as f64);
+        assert_eq!(tensor_o.cast::<i8>().as_slice(), &[-2, 0, 0, 1, i8::MAX, 0]);
+        assert_eq!(tensor_o.cast::<u32>().as_slice(), &[0, 0, 0, 1, i32::MAX as u32, 0]);
+        assert_eq!(tensor.cast::<bool>().cast::<f32>().as_slice(), &[1.0, 1.0, 0.0, 1.0, 1.0, 1.0]);
+        assert_eq!(tensor.cast::<half::f16>().cast::<f32>().as_slice()[..3], [-2.5, -0.5, 0.0]);
+        assert_eq!(tensor.cast::<half::bf16>().cast::<i64>().as_slice()[..4], [-2, 0, 0, 1]);
+
+        // Just above the halfway point between two `bf16` values, rounding to `f64` first would
+        // land on the tie and round down.
+        let tensor = CpuTensor::from_vec((Dyn::new(2),), vec![(1i64 << 62) + (1 << 54) + 1, -(1 << 62) - (1 << 54) - 1]);
+        assert_eq!(tensor.cast::<half::bf16>().cast::<f64>().as_slice(), &[(1u64 << 62) as f64 + (1u64 << 55) as f64, -((1u64 << 62) as f64 + (1u64 << 55) as f64)]);
+        let tensor = CpuTensor::from_vec((Dyn::new(1),), vec![1.0 + 2f64.powi(-8) + 2f64.powi(-40)]);
+        assert_eq!(tensor.cast::<half::bf16>().cast::<f32>().as_slice(), &[1.0 + 2f32.powi(-7)]);
+    }
+
+    #[test]
+    #[cfg(feature = "cuda")]
+    fn test_unary_cuda() {
+        let tensor = CpuTensor::from_fn((Dyn::new(3), Dyn::new(500)), |idx| idx[1] as f64 - 250.0);
+        let expected = tensor.relu();
+        let tensor = tensor.cuda();
+
+        assert_eq!(tensor.relu().cpu().as_slice(), expected.as_slice());
+        assert_eq!((-&tensor).abs().cpu().as_slice(), tensor.abs().cpu().as_slice());
+        assert_eq!(tensor.powf(2.0).cpu().as_slice()[0], 62500.0);
+        assert_eq!((2.0 - &tensor).cpu().as_slice()[0], 252.0);
+        assert_eq!((&tensor * 2.0).cpu().as_slice()[0], -500.0);
+
+        let expected = expected.cast::<i32>().cast::<u8>();
+        assert_eq!(tensor.relu().cast::<i32>().cast::<u8>().cpu().as_slice(), expected.as_slice());
+        assert!(!tensor.cast::<bool>().cpu().as_slice()[250]);
+    }
+}
@@ -0,0 +1,642 @@
+use std::ops::{Index, IndexMut};
+use generic_array::GenericArray;
+use crate::backend::{Backend, Cpu, DType};
+use crate::error::DeeperError;
+use crate::index::TensorIndex;
+use crate::shape::{
+    broadcast_strides, Axis, AxisRange, BroadcastShape, Dim, PermuteShape, ReplaceAxis, Shape,
+    ShapeError, SliceShape, SwapAxes,
+};
+use crate::tensor::{expect, Tensor};
+
+/// Where the elements of a view are found in the storage of its tensor.
+#[derive(Clone, Debug)]
+struct Layout<S: Shape> {
+    offset: usize,
+    strides: GenericArray<usize, S::Dims>,
+    shape: S,
+}
+
+impl<S: Shape> Layout<S> {
+    fn contiguous(shape: S) -> Self {
+        Layout { offset: 0, strides: shape.strides(), shape }
+    }
+
+    /// The layout with axis `i` taken from axis `axes[i]`.
+    fn permute_axes<SO: Shape>(&self, shape: SO, axes: &[usize]) -> Layout<SO> {
+        let mut strides = GenericArray::<usize, SO::Dims>::default();
+        for (stride, &axis) in strides.iter_mut().zip(axes) {
+            *stride = self.strides[axis];
+        }
+
+        Layout { offset: self.offset, strides, shape }
+    }
+
+    fn permute<P>(&self) -> Layout<S::Output>
+        where
+            S: PermuteShape<P>,
+    {
+        self.permute_axes(self.shape.permute(), &S::axes())
+    }
+
+    fn swap_axes<A0: typenum::Unsigned, A1: typenum::Unsigned>(&self) -> Layout<S::Output>
+        where
+            S: SwapAxes<A0, A1>,
+    {
+        let mut axes = self.shape.dimensions();
+        for (i, axis) in axes.iter_mut().enumerate() {
+            *axis = i;
+        }
+        axes.swap(A0::USIZE, A1::USIZE);
+
+        self.permute_axes(self.shape.swap_axes(), &axes)
+    }
+
+    /// The layout of `shape` starting at the element at `starts`, with the same strides.
+    fn select<SO: Shape>(&self, shape: SO, starts: &[usize]) -> Layout<SO> {
+        let mut offset = self.offset;
+        let mut strides = GenericArray::<usize, SO::Dims>::default();
+        for i in 0..strides.len() {
+            offset += starts[i] * self.strides[i];
+            strides[i] = self.strides[i];
+        }
+
+        Layout { offset, strides, shape }
+    }
+
+    fn slice<R>(&self, ranges: R) -> Result<Layout<S::Output>, ShapeError>
+        where
+            S: SliceShape<R>,
+    {
+        let (starts, shape) = self.shape.try_slice(ranges)?;
+
+        Ok(self.select(shape, &starts))
+    }
+
+    fn narrow<A, R>(&self, range: R) -> Result<Layout<Narrowed<S, A, R>>, ShapeError>
+        where
+            A: typenum::Unsigned,
+            S: Axis<A> + ReplaceAxis<A, R::Output>,
+            R: AxisRange<<S as Axis<A>>::Output>,
+    {
+        let (start, dim) = range
+            .select(self.shape.axis())
+            .map_err(|e| e.at_axis(A::USIZE))?;
+        let mut starts = self.shape.dimensions();
+        for (i, s) in starts.iter_mut().enumerate() {
+            *s = if i == A::USIZE { start } else { 0 };
+        }
+
+        Ok(self.select(self.shape.replace_axis(dim), &starts))
+    }
+
+    /// Position of the element at `index` in the storage, `None` if it is out of bounds.
+    fn position(&self, index: &GenericArray<usize, S::Dims>) -> Option<usize> {
+        let dims = self.shape.dimensions();
+        let mut position = self.offset;
+        for i in 0..dims.len() {
+            if index[i] >= dims[i] {
+                return None;
+            }
+            position += index[i] * self.strides[i];
+        }
+
+        Some(position)
+    }
+
+    fn out_of_bounds(&self, index: &GenericArray<usize, S::Dims>) -> ! {
+        panic!(
+            "index {:?} is out of bounds for dimensions {:?}",
+            index.as_slice(),
+            self.shape.dimensions().as_slice(),
+        )
+    }
+}
+
+/// The shape `S` with the axis `A` narrowed by the range `R`.
+pub type Narrowed<S, A, R> = <S as ReplaceAxis<A, <R as AxisRange<<S as Axis<A>>::Output>>::Output>>::Output;
+
+/// A borrowed, possibly strided view of the elements of a [`Tensor`].
+///
+/// Permuting the axes or broadcasting only changes the offset and strides, the elements are not
+/// copied until [`TensorView::contiguous`] is called. Broadcast axes have a stride of 0.
+///
+/// ```
+/// use deeper::prelude::*;
+///
+/// let tensor = CpuTensor::from_vec((Dyn::new(2), Dyn::new(3)), vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0]);
+/// let view = tensor.view().transpose();
+/// assert_eq!(view.strides().as_slice(), &[1, 3]);
+/// assert_eq!(view[(2, 1)], 6.0);
+/// assert_eq!(view.contiguous().as_slice(), &[1.0, 4.0, 2.0, 5.0, 3.0, 6.0]);
+/// ```
+pub struct TensorView<'a, T: DType, S: Shape, B: Backend> {
+    data: &'a B::Storage<T>,
+    layout: Layout<S>,
+}
+
+/// A mutably borrowed, possibly strided view of the elements of a [`Tensor`].
+pub struct TensorViewMut<'a, T: DType, S: Shape, B: Backend> {
+    data: &'a mut B::Storage<T>,
+    layout: Layout<S>,
+}
+
+impl<T: DType, S: Shape, B: Backend> Tensor<T, S, B> {
+    /// A view of all elements.
+    pub fn view(&self) -> TensorView<'_, T, S, B> {
+        TensorView { data: &self.data, layout: Layout::contiguous(self.shape) }
+    }
+
+    /// A mutable view of all elements.
+    pub fn view_mut(&mut self) -> TensorViewMut<'_, T, S, B> {
+        TensorViewMut { data: &mut self.data, layout: Layout::contiguous(self.shape) }
+    }
+
+    /// A view of the elements selected by `ranges`, one [`AxisRange`] per axis.
+    ///
+    /// `..` keeps an axis, a [`CstRange`](crate::CstRange) gives a constant axis and the `usize`
+    /// ranges give a [`Dyn`](crate::Dyn) axis.
+    ///
+    /// ```
+    /// use deeper::prelude::*;
+    /// use typenum::{U0, U2, U3, U4};
+    ///
+    /// let tensor = CpuTensor::from_fn((Cst::<U3>::new(), Cst::<U4>::new()), |idx| (idx[0] * 4 + idx[1]) as f32);
+    /// let view: TensorView<f32, (Cst<U2>, Dyn), Cpu> = tensor.slice((CstRange::<U0, U2>::new(), 1..3));
+    /// assert_eq!(view.contiguous().as_slice(), &[1.0, 2.0, 5.0, 6.0]);
+    /// ```
+    pub fn slice<R>(&self, ranges: R) -> TensorView<'_, T, S::Output, B>
+        where
+            S: SliceShape<R>,
+    {
+        self.view().slice(ranges)
+    }
+
+    /// Like [`Tensor::slice`], but returns an error instead of panicking.
+    pub fn try_slice<R>(&self, ranges: R) -> Result<TensorView<'_, T, S::Output, B>, DeeperError>
+        where
+            S: SliceShape<R>,
+    {
+        self.view().try_slice(ranges)
+    }
+
+    /// A view of the elements selected by `range` along the axis `A`, given as a `typenum`
+    /// index.
+    ///
+    /// ```
+    /// use deeper::prelude::*;
+    /// use typenum::{U1, U2, U3};
+    ///
+    /// let tensor = CpuTensor::from_fn((Dyn::new(2), Cst::<U3>::new()), |idx| (idx[0] * 3 + idx[1]) as f32);
+    /// let view = tensor.narrow::<U1, _>(CstRange::<U1, U3>::new());
+    /// assert_eq!(view.contiguous().as_slice(), &[1.0, 2.0, 4.0, 5.0]);
+    /// ```
+    pub fn narrow<A, R>(&self, range: R) -> TensorView<'_, T, Narrowed<S, A, R>, B>
+        where
+            A: typenum::Unsigned,
+            S: Axis<A> + ReplaceAxis<A, R::Output>,
+            R: AxisRange<<S as Axis<A>>::Output>,
+    {
+        self.view().narrow::<A, R>(range)
+    }
+
+    /// Like [`Tensor::narrow`], but returns an error instead of panicking.
+    #[allow(clippy::type_complexity)]
+    pub fn try_narrow<A, R>(
+        &self,
+        range: R,
+    ) -> Result<TensorView<'_, T, Narrowed<S, A, R>, B>, DeeperError>
+        where
+            A: typenum::Unsigned,
+            S: Axis<A> + ReplaceAxis<A, R::Output>,
+            R: AxisRange<<S as Axis<A>>::Output>,
+    {
+        self.view().try_narrow::<A, R>(range)
+    }
+}
+
+impl<T: DType, S: Shape, B: Backend> Clone for TensorView<'_, T, S, B> {
+    fn clone(&self) -> Self {
+        TensorView { data: self.data, layout: self.layout.clone() }
+    }
+}
+
+impl<'a, T: DType, S: Shape, B: Backend> TensorView<'a, T, S, B> {
+    pub fn shape(&self) -> S {
+        self.layout.shape
+    }
+
+    /// Distance in the storage between neighbouring elements along every axis.
+    pub fn strides(&self) -> GenericArray<usize, S::Dims> {
+        self.layout.strides.clone()
+    }
+
+    /// Position of the first element in the storage.
+    pub fn offset(&self) -> usize {
+        self.layout.offset
+    }
+
+    /// Whether the elements are stored densely in row-major order.
+    pub fn is_contiguous(&self) -> bool {
+        self.layout.strides == self.layout.shape.strides()
+    }
+
+    /// Reorders the axes like [`Tensor::permute`], without copying.
+    pub fn permute<P>(&self) -> TensorView<'a, T, <S as PermuteShape<P>>::Output, B>
+        where
+            S: PermuteShape<P>,
+    {
+        TensorView { data: self.data, layout: self.layout.permute::<P>() }
+    }
+
+    /// Swaps two axes like [`Tensor::swap_axes`], without copying.
+    pub fn swap_axes<A0, A1>(&self) -> TensorView<'a, T, <S as SwapAxes<A0, A1>>::Output, B>
+        where
+            A0: typenum::Unsigned,
+            A1: typenum::Unsigned,
+            S: SwapAxes<A0, A1>,
+    {
+        TensorView { data: self.data, layout: self.layout.swap_axes::<A0, A1>() }
+    }
+
+    /// Selects part of the view like [`Tensor::slice`], without copying.
+    pub fn slice<R>(&self, ranges: R) -> TensorView<'a, T, S::Output, B>
+        where
+            S: SliceShape<R>,
+    {
+        expect(self.try_slice(ranges))
+    }
+
+    /// Like [`TensorView::slice`], but returns an error instead of panicking.
+    pub fn try_slice<R>(&self, ranges: R) -> Result<TensorView<'a, T, S::Output, B>, DeeperError>
+        where
+            S: SliceShape<R>,
+    {
+        Ok(TensorView { data: self.data, layout: self.layout.slice(ranges)? })
+    }
+
+    /// Selects part of an axis like [`Tensor::narrow`], without copying.
+    pub fn narrow<A, R>(&self, range: R) -> TensorView<'a, T, Narrowed<S, A, R>, B>
+        where
+            A: typenum::Unsigned,
+            S: Axis<A> + ReplaceAxis<A, R::Output>,
+            R: AxisRange<<S as Axis<A>>::Output>,
+    {
+        expect(self.try_narrow::<A, R>(range))
+    }
+
+    /// Like [`TensorView::narrow`], but returns an error instead of panicking.
+    #[allow(clippy::type_complexity)]
+    pub fn try_narrow<A, R>(
+        &self,
+        range: R,
+    ) -> Result<TensorView<'a, T, Narrowed<S, A, R>, B>, DeeperError>
+        where
+            A: typenum::Unsigned,
+            S: Axis<A> + ReplaceAxis<A, R::Output>,
+            R: AxisRange<<S as Axis<A>>::Output>,
+    {
+        Ok(TensorView { data: self.data, layout: self.layout.narrow::<A, R>(range)? })
+    }
+
+    /// Repeats the elements along the axes which are missing or of size 1 to fill `shape`,
+    /// without copying.
+    ///
+    /// ```
+    /// use deeper::prelude::*;
+    ///
+    /// let tensor = CpuTensor::from_vec((Dyn::new(3),), vec![1.0, 2.0, 3.0]);
+    /// let view = tensor.view().broadcast_to((Dyn::new(2), Dyn::new(3)));
+    /// assert_eq!(view.strides().as_slice(), &[0, 1]);
+    /// assert_eq!(view.contiguous().as_slice(), &[1.0, 2.0, 3.0, 1.0, 2.0, 3.0]);
+    /// ```
+    pub fn broadcast_to<SO: Shape>(&self, shape: SO) -> TensorView<'a, T, SO, B>
+        where
+            S: BroadcastShape<SO, Output = SO>,
+    {
+        expect(self.try_broadcast_to(shape))
+    }
+
+    /// Like [`TensorView::broadcast_to`], but returns an error instead of panicking.
+    pub fn try_broadcast_to<SO: Shape>(
+        &self,
+        shape: SO,
+    ) -> Result<TensorView<'a, T, SO, B>, DeeperError>
+        where
+            S: BroadcastShape<SO, Output = SO>,
+    {
+        // The broadcast shape can be larger than `shape` along an axis of size 1 in `shape`.
+        let o_dims = shape.dimensions();
+        let b_dims = self.layout.shape.try_broadcast(shape)?.dimensions();
+        if let Some(axis) = (0..o_dims.len()).find(|&axis| b_dims[axis] != o_dims[axis]) {
+            return Err(ShapeError::Broadcast {
+                axis,
+                lhs: b_dims[axis],
+                rhs: o_dims[axis],
+            }.into());
+        }
+        let strides = broadcast_strides(
+            self.layout.shape.dimensions().as_slice(),
+            self.layout.strides.as_slice(),
+            &o_dims,
+        );
+
+        Ok(TensorView {
+            data: self.data,
+            layout: Layout { offset: self.layout.offset, strides, shape },
+        })
+    }
+
+    /// Copies the elements into a new, contiguous tensor.
+    pub fn contiguous(&self) -> Tensor<T, S, B> {
+        expect(self.try_contiguous())
+    }
+
+    /// Like [`TensorView::contiguous`], but returns an error instead of panicking.
+    pub fn try_contiguous(&self) -> Result<Tensor<T, S, B>, DeeperError> {
+        let shape = self.layout.shape;
+        let mut data = B::alloc(shape.size())?;
+        B::permute::<T>(
+            self.data,
+            self.layout.offset,
+            &self.layout.strides,
+            &mut data,
+            &shape.strides(),
+        )?;
+
+        Ok(Tensor { data, shape })
+    }
+}
+
+impl<'a, T: DType, D0: Dim, D1: Dim, B: Backend> TensorView<'a, T, (D0, D1), B> {
+    /// Swaps the rows and columns of a matrix, without copying.
+    pub fn transpose(&self) -> TensorView<'a, T, (D1, D0), B> {
+        self.permute::<(typenum::U1, typenum::U0)>()
+    }
+}
+
+impl<'a, T: DType, S: Shape, B: Backend> TensorViewMut<'a, T, S, B> {
+    pub fn shape(&self) -> S {
+        self.layout.shape
+    }
+
+    /// Distance in the storage between neighbouring elements along every axis.
+    pub fn strides(&self) -> GenericArray<usize, S::Dims> {
+        self.layout.strides.clone()
+    }
+
+    /// Position of the first element in the storage.
+    pub fn offset(&self) -> usize {
+        self.layout.offset
+    }
+
+    /// An immutable view of the same elements.
+    pub fn as_view(&self) -> TensorView<'_, T, S, B> {
+        TensorView { data: self.data, layout: self.layout.clone() }
+    }
+
+    /// Reorders the axes like [`Tensor::permute`], without copying.
+    pub fn permute<P>(self) -> TensorViewMut<'a, T, <S as PermuteShape<P>>::Output, B>
+        where
+            S: PermuteShape<P>,
+    {
+        TensorViewMut { layout: self.layout.permute::<P>(), data: self.data }
+    }
+
+    /// Swaps two axes like [`Tensor::swap_axes`], without copying.
+    pub fn swap_axes<A0, A1>(self) -> TensorViewMut<'a, T, <S as SwapAxes<A0, A1>>::Output, B>
+        where
+            A0: typenum::Unsigned,
+            A1: typenum::Unsigned,
+            S: SwapAxes<A0, A1>,
+    {
+        TensorViewMut { layout: self.layout.swap_axes::<A0, A1>(), data: self.data }
+    }
+
+    /// Selects part of the view like [`Tensor::slice`], without copying.
+    pub fn slice<R>(self, ranges: R) -> TensorViewMut<'a, T, S::Output, B>
+        where
+            S: SliceShape<R>,
+    {
+        expect(self.try_slice(ranges))
+    }
+
+    /// Like [`TensorViewMut::slice`], but returns an error instead of panicking.
+    pub fn try_slice<R>(self, ranges: R) -> Result<TensorViewMut<'a, T, S::Output, B>, DeeperError>
+        where
+            S: SliceShape<R>,
+    {
+        Ok(TensorViewMut { layout: self.layout.slice(ranges)?, data: self.data })
+    }
+
+    /// Selects part of an axis like [`Tensor::narrow`], without copying.
+    pub fn narrow<A, R>(self, range: R) -> TensorViewMut<'a, T, Narrowed<S, A, R>, B>
+        where
+            A: typenum::Unsigned,
+            S: Axis<A> + ReplaceAxis<A, R::Output>,
+            R: AxisRange<<S as Axis<A>>::Output>,
+    {
+        expect(self.try_narrow::<A, R>(range))
+    }
+
+    /// Like [`TensorViewMut::narrow`], but returns an error instead of panicking.
+    #[allow(clippy::type_complexity)]
+    pub fn try_narrow<A, R>(
+        self,
+        range: R,
+    ) -> Result<TensorViewMut<'a, T, Narrowed<S, A, R>, B>, DeeperError>
+        where
+            A: typenum::Unsigned,
+            S: Axis<A> + ReplaceAxis<A, R::Output>,
+            R: AxisRange<<S as Axis<A>>::Output>,
+    {
+        Ok(TensorViewMut { layout: self.layout.narrow::<A, R>(range)?, data: self.data })
+    }
+
+    /// Copies the elements into a new, contiguous tensor.
+    pub fn contiguous(&self) -> Tensor<T, S, B> {
+        self.as_view().contiguous()
+    }
+
+    /// Like [`TensorViewMut::contiguous`], but returns an error instead of panicking.
+    pub fn try_contiguous(&self) -> Result<Tensor<T, S, B>, DeeperError> {
+        self.as_view().try_contiguous()
+    }
+}
+
+impl<'a, T: DType, D0: Dim, D1: Dim, B: Backend> TensorViewMut<'a, T, (D0, D1), B> {
+    /// Swaps the rows and columns of a matrix, without copying.
+    pub fn transpose(self) -> TensorViewMut<'a, T, (D1, D0), B> {
+        self.permute::<(typenum::U1, typenum::U0)>()
+    }
+}
+
+impl<T: DType, S: Shape> TensorView<'_, T, S, Cpu> {
+    /// The element at `index`, `None` if it is out of bounds.
+    pub fn get(&self, index: impl TensorIndex<S::Dims>) -> Option<&T> {
+        self.data.get(self.layout.position(&index.into_index())?)
+    }
+}
+
+impl<T: DType, S: Shape> TensorViewMut<'_, T, S, Cpu> {
+    /// The element at `index`, `None` if it is out of bounds.
+    pub fn get(&self, index: impl TensorIndex<S::Dims>) -> Option<&T> {
+        self.data.get(self.layout.position(&index.into_index())?)
+    }
+
+    pub fn get_mut(&mut self, index: impl TensorIndex<S::Dims>) -> Option<&mut T> {
+        self.data.get_mut(self.layout.position(&index.into_index())?)
+    }
+}
+
+impl<T: DType, S: Shape, I: TensorIndex<S::Dims>> Index<I> for TensorView<'_, T, S, Cpu> {
+    type Output = T;
+
+    fn index(&self, index: I) -> &T {
+        let index = index.into_index();
+        match self.layout.position(&index) {
+            Some(position) => &self.data[position],
+            None => self.layout.out_of_bounds(&index),
+        }
+    }
+}
+
+impl<T: DType, S: Shape, I: TensorIndex<S::Dims>> Index<I> for TensorViewMut<'_, T, S, Cpu> {
+    type Output = T;
+
+    fn index(&self, index: I) -> &T {
+        let index = index.into_index();
+        match self.layout.position(&index) {
+            Some(position) => &self.data[position],
+            None => self.layout.out_of_bounds(&index),
+        }
+    }
+}
+
+impl<T: DType, S: Shape, I: TensorIndex<S::Dims>> IndexMut<I> for TensorViewMut<'_, T, S, Cpu> {
+    fn index_mut(&mut self, index: I) -> &mut T {
+        let index = index.into_index();
+        match self.layout.position(&index) {
+            Some(position) => &mut self.data[position],
+            None => self.layout.out_of_bounds(&index),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::backend::Cpu;
+    use crate::shape::{Cst, CstRange, Dyn, Shape, ShapeError};
+    use crate::tensor::CpuTensor;
+    use crate::view::TensorView;
+    use crate::DeeperError;
+
+    #[test]
+    fn test_view_permute() {
+        let tensor = CpuTensor::from_fn(
+            (Dyn::new(2), Cst::<typenum::U3>::new(), Dyn::new(4)),
+            |idx| (idx[0] * 100 + idx[1] * 10 + idx[2]) as f32,
+        );
+
+        let view = tensor.view().permute::<(typenum::U2, typenum::U0, typenum::U1)>();
+        assert_eq!(view.shape().dimensions().as_slice(), &[4, 2, 3]);
+        assert_eq!(view.strides().as_slice(), &[1, 12, 4]);
+        assert!(!view.is_contiguous());
+        assert_eq!(view[(3, 1, 2)], 123.0);
+        assert_eq!(view.get((4, 0, 0)), None);
+
+        let view = view.swap_axes::<typenum::U0, typenum::U2>();
+        assert_eq!(view.shape().dimensions().as_slice(), &[3, 2, 4]);
+        assert_eq!(view[(2, 1, 3)], 123.0);
+        assert_eq!(view.contiguous().as_slice(), tensor.swap_axes::<typenum::U0, typenum::U1>().as_slice());
+    }
+
+    #[test]
+    fn test_view_broadcast() {
+        let tensor = CpuTensor::from_vec((Cst::<typenum::U2>::new(), Cst::<typenum::U1>::new()), vec![1.0, 2.0]);
+
+        let view = tensor.view().broadcast_to((Dyn::new(3), Cst::<typenum::U2>::new(), Dyn::new(2)));
+        assert_eq!(view.strides().as_slice(), &[0, 1, 0]);
+        assert_eq!(view.contiguous().as_slice(), &[1.0, 1.0, 2.0, 2.0, 1.0, 1.0, 2.0, 2.0, 1.0, 1.0, 2.0, 2.0]);
+
+        let tensor = CpuTensor::from_vec((Dyn::new(3),), vec![1.0, 2.0, 3.0]);
+        assert_eq!(
+            tensor.view().try_broadcast_to((Dyn::new(1),)).err(),
+            Some(DeeperError::Shape(ShapeError::Broadcast { axis: 0, lhs: 3, rhs: 1 })),
+        );
+    }
+
+    #[test]
+    fn test_view_mut() {
+        let mut tensor = CpuTensor::from_vec((Dyn::new(2), Dyn::new(3)), vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0]);
+
+        let mut view = tensor.view_mut().transpose();
+        view[(2, 0)] = 30.0;
+        *view.get_mut((0, 1)).unwrap() = 40.0;
+        assert_eq!(view.as_view().contiguous().as_slice(), &[1.0, 40.0, 2.0, 5.0, 30.0, 6.0]);
+        assert_eq!(tensor.as_slice(), &[1.0, 2.0, 30.0, 40.0, 5.0, 6.0]);
+    }
+
+    #[test]
+    fn test_slice() {
+        let tensor = CpuTensor::from_fn(
+            (Dyn::new(3), Cst::<typenum::U4>::new(), Cst::<typenum::U2>::new()),
+            |idx| (idx[0] * 100 + idx[1] * 10 + idx[2]) as f32,
+        );
+
+        let view = tensor.slice((1.., CstRange::<typenum::U1, typenum::U3>::new(), ..));
+        let _: &TensorView<f32, (Dyn, Cst<typenum::U2>, Cst<typenum::U2>), Cpu> = &view;
+        assert_eq!(view.offset(), 10);
+        assert_eq!(view[(1, 1, 0)], 220.0);
+        assert_eq!(view.contiguous().as_slice(), &[110.0, 111.0, 120.0, 121.0, 210.0, 211.0, 220.0, 221.0]);
+
+        let view = view.narrow::<typenum::U0, _>(..=0).narrow::<typenum::U2, _>(1..2);
+        assert_eq!(view.shape().dimensions().as_slice(), &[1, 2, 1]);
+        assert_eq!(view.contiguous().as_slice(), &[111.0, 121.0]);
+
+        assert_eq!(
+            tensor.try_slice((.., 2..5, ..)).err(),
+            Some(DeeperError::Shape(ShapeError::Range { axis: 1, start: 2, end: 5, size: 4 })),
+        );
+        assert_eq!(
+            tensor.try_narrow::<typenum::U0, _>(CstRange::<typenum::U2, typenum::U4>::new()).err(),
+            Some(DeeperError::Shape(ShapeError::Range { axis: 0, start: 2, end: 4, size: 3 })),
+        );
+        assert_eq!(
+            tensor.try_slice((.., 0..=usize::MAX, ..)).err(),
+            Some(DeeperError::Shape(ShapeError::Range { axis: 1, start: 0, end: usize::MAX, size: 4 })),
+        );
+        assert_eq!(
+            tensor.try_narrow::<typenum::U2, _>(..=usize::MAX).err(),
+            Some(DeeperError::Shape(ShapeError::Range { axis: 2, start: 0, end: usize::MAX, size: 2 })),
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "cuda")]
+    fn test_slice_cuda() {
+        let tensor = CpuTensor::from_fn((Dyn::new(3), Dyn::new(3)), |idx| (idx[0] * 3 + idx[1]) as f32);
+        let tensor = tensor.cuda();
+
+        let tensor_o = tensor.slice((1.., ..2)).transpose().contiguous();
+        assert_eq!(tensor_o.cpu().as_slice(), &[3.0, 6.0, 4.0, 7.0]);
+    }
+
+    #[test]
+    fn test_slice_mut() {
+        let mut tensor = CpuTensor::<f32, _>::zero((Dyn::new(3), Dyn::new(3)));
+
+        let mut view = tensor.view_mut().slice((1..3, ..2));
+        view[(0, 1)] = 1.0;
+        view[(1, 0)] = 2.0;
+        assert_eq!(tensor.as_slice(), &[0.0, 0.0, 0.0, 0.0, 1.0, 0.0, 2.0, 0.0, 0.0]);
+    }
+
+    #[test]
+    #[should_panic(expected = "index [0, 2] is out of bounds for dimensions [3, 2]")]
+    fn test_view_out_of_bounds() {
+        let tensor = CpuTensor::from_vec((Dyn::new(2), Dyn::new(3)), vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0]);
+
+        let _ = tensor.view().transpose()[(0, 2)];
+    }
+}